@@ -14,6 +14,7 @@ use serde::Deserialize;
 use serde_derive_state::DeserializeState;
 use serde_state::DeserializeState;
 use strum::{EnumIter, IntoEnumIterator};
+use world::block::custom::CustomBlockId;
 use world::resource::Location;
 use world::{Block, BlockId, Direction};
 
@@ -24,6 +25,8 @@ use crate::{texture, Packs};
 #[derive(Debug)]
 pub struct Registry {
     models: Box<EnumMap<BlockId, Model>>,
+    /// The models of the custom blocks, whose blockstates are in their own namespace.
+    custom_models: FxHashMap<CustomBlockId, Model>,
 }
 
 impl Registry {
@@ -33,31 +36,22 @@ impl Registry {
         let mut bakery = Bakery::new(packs, &arena, texture_uploader);
         let mut registry = Registry {
             models: Box::default(),
+            custom_models: FxHashMap::default(),
         };
 
         for block_id in BlockId::iter() {
             let block_name = block_id.to_location();
             let path = format!("assets/minecraft/blockstates/{block_name}.json");
-            let (pack_index, mut file) = bakery
-                .packs
-                .find_file(0, &path)
-                .with_context(|| format!("failed to load `{path}`"))?;
-            let buffer = file.read_to_arena(bakery.arena)?;
-            drop(file);
-
-            let mut deserializer = serde_json::Deserializer::from_slice(buffer);
-
-            bakery.block_id = block_id;
-            bakery.pack_index = pack_index;
-            let selectors = Selectors::deserialize_state(&mut bakery, &mut deserializer)
-                .with_context(|| {
-                    format!(
-                        "failed to load `{}`",
-                        bakery.packs.inner[pack_index].path.join(path).display(),
-                    )
-                })?;
 
-            registry.models[block_id] = Model::new(selectors);
+            registry.models[block_id] = bakery.load_block_model(Block::from_id(block_id), &path)?;
+        }
+
+        for custom_id in CustomBlockId::iter() {
+            let (namespace, block_name) = Location::new(custom_id.location())?.as_parts();
+            let path = format!("assets/{namespace}/blockstates/{block_name}.json");
+            let model = bakery.load_block_model(Block::from_custom(custom_id), &path)?;
+
+            registry.custom_models.insert(custom_id, model);
         }
 
         Ok(registry)
@@ -66,7 +60,10 @@ impl Registry {
     /// Returns the faces that should be rendered for a block and its state.
     #[must_use]
     pub fn faces_of(&self, block: Block) -> Cow<'_, Faces> {
-        let model = &self.models[block.id()];
+        let model = match block.custom_id() {
+            Some(custom_id) => &self.custom_models[&custom_id],
+            None => &self.models[block.id()],
+        };
 
         match &model.selectors {
             Selectors::Variants(selectors) => {
@@ -325,8 +322,8 @@ pub(super) struct Bakery<'arena> {
     /// The resource packs that are being processed.
     packs: Packs,
     packs2: Packs,
-    /// The block whose model is currently being baked.
-    pub(super) block_id: BlockId,
+    /// The block whose model is currently being baked, in its default state.
+    pub(super) block: Block,
     /// The pack which is currently being processed.
     pack_index: usize,
     /// Arena into which all JSON files are allocated.
@@ -360,7 +357,7 @@ impl<'arena> Bakery<'arena> {
             packs,
             packs2,
             arena,
-            block_id: BlockId::Air,
+            block: Block::from_id(BlockId::Air),
             pack_index: 0,
             // The largest block file in the original pack is block/nether_portal.png at 14 KiB.
             scratch: vec![0; 64 * 1024],
@@ -378,6 +375,29 @@ impl<'arena> Bakery<'arena> {
         }
     }
 
+    /// Loads the blockstates file of the given block at the given path and bakes its model.
+    fn load_block_model(&mut self, block: Block, path: &str) -> Result<Model> {
+        let (pack_index, mut file) = self
+            .packs
+            .find_file(0, path)
+            .with_context(|| format!("failed to load `{path}`"))?;
+        let buffer = file.read_to_arena(self.arena)?;
+        drop(file);
+
+        let mut deserializer = serde_json::Deserializer::from_slice(buffer);
+
+        self.block = block;
+        self.pack_index = pack_index;
+        let selectors = Selectors::deserialize_state(self, &mut deserializer).with_context(|| {
+            format!(
+                "failed to load `{}`",
+                self.packs.inner[pack_index].path.join(path).display(),
+            )
+        })?;
+
+        Ok(Model::new(selectors))
+    }
+
     /// Loads a model file from the current pack.
     fn load_model(&mut self, props: &ModelProperties<'arena>) -> Result<Faces> {
         let location = &props.model;
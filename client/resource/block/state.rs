@@ -59,8 +59,7 @@ impl<'de: 'arena, 'arena> Visitor<'de> for VariantPredicateSeed<'_, 'arena> {
             return Ok(VariantPredicate::default());
         }
 
-        let block_id = self.state.block_id;
-        let state_definition = block_id.state_definition();
+        let state_definition = self.state.block.state_definition();
         let property_name_seed = PropertyNameSeed { state_definition };
 
         let mut block_state_mask = 0;
@@ -134,8 +133,7 @@ impl<'de: 'arena, 'arena> Visitor<'de> for PartPredicateVisitor<'_, 'arena> {
     where
         A: MapAccess<'de>,
     {
-        let block_id = self.state.block_id;
-        let state_definition = block_id.state_definition();
+        let state_definition = self.state.block.state_definition();
         let property_name_seed = PropertyNameSeed { state_definition };
         let last_property_definition = state_definition.properties.last().unwrap();
         let bits = last_property_definition.offset + last_property_definition.id.bits() - u16::BITS;
@@ -150,6 +148,7 @@ impl<'de: 'arena, 'arena> Visitor<'de> for PartPredicateVisitor<'_, 'arena> {
                 match map.next_key_seed(FieldSeed(property_name_seed))? {
                     Some(Field::OR) => {
                         return map.next_value_seed(OrSeed {
+                            block: self.state.block,
                             property_name_seed,
                             bitfield,
                             scratch: &mut scratch,
@@ -172,7 +171,7 @@ impl<'de: 'arena, 'arena> Visitor<'de> for PartPredicateVisitor<'_, 'arena> {
             })?;
         }
 
-        generate_combinations(&mut bitfield, &scratch);
+        mark_matching_states(&mut bitfield, self.state.block, &mut scratch);
 
         Ok(PartPredicate {
             bitfield: Some(bitfield),
@@ -180,39 +179,39 @@ impl<'de: 'arena, 'arena> Visitor<'de> for PartPredicateVisitor<'_, 'arena> {
     }
 }
 
-fn generate_combinations(
+/// Sets the bits of the block's states whose property values are all among those in `scratch`,
+/// where properties without any values match every value, then clears `scratch` for the next
+/// condition.
+fn mark_matching_states(
     bitfield: &mut [u64],
-    scratch: &[ArrayVec<u32, MAX_PROPERTY_VALUES>; MAX_PROPERTIES],
+    block: Block,
+    scratch: &mut [ArrayVec<u32, MAX_PROPERTY_VALUES>; MAX_PROPERTIES],
 ) {
-    let mut current_indices = [0; MAX_PROPERTIES];
+    let properties = block.state_definition().properties;
+    let states: Box<dyn Iterator<Item = Block>> = match block.custom_id() {
+        Some(custom_id) => Box::new(custom_id.states()),
+        None => Box::new(block.id().states()),
+    };
 
-    loop {
-        let mut bits = 0;
+    for state in states {
+        let bits = state.as_u32() >> u16::BITS;
+        let matches = scratch.iter().zip(properties).all(|(values, definition)| {
+            let mask = ((1 << definition.id.bits()) - 1) << (definition.offset - u16::BITS);
 
-        for (properties, &current_index) in scratch.iter().zip(&current_indices) {
-            bits |= properties.get(current_index).unwrap_or(&0);
-        }
-
-        let word_index = bits / u64::BITS;
-        let bit_index = bits % u64::BITS;
-
-        bitfield[word_index as usize] |= 1 << bit_index;
+            values.is_empty() || values.contains(&(bits & mask))
+        });
 
-        for (i, (properties, current_index)) in scratch.iter().zip(&mut current_indices).enumerate()
-        {
-            *current_index += 1;
-
-            if *current_index < properties.len() {
-                break;
-            }
+        if matches {
+            let word_index = bits / u64::BITS;
+            let bit_index = bits % u64::BITS;
 
-            *current_index = 0;
-
-            if i == MAX_PROPERTIES - 1 {
-                return;
-            }
+            bitfield[word_index as usize] |= 1 << bit_index;
         }
     }
+
+    for values in scratch {
+        values.clear();
+    }
 }
 
 enum Field {
@@ -295,6 +294,7 @@ impl<'de> Visitor<'de> for PropertyNameSeed {
 }
 
 struct OrSeed<'seed> {
+    block: Block,
     property_name_seed: PropertyNameSeed,
     bitfield: Box<[u64]>,
     scratch: &'seed mut [ArrayVec<u32, MAX_PROPERTY_VALUES>; MAX_PROPERTIES],
@@ -326,7 +326,7 @@ impl<'de> Visitor<'de> for OrSeed<'_> {
             property_name_seed: self.property_name_seed,
             scratch: self.scratch,
         })? {
-            generate_combinations(&mut self.bitfield, self.scratch);
+            mark_matching_states(&mut self.bitfield, self.block, self.scratch);
         }
 
         Ok(PartPredicate {
@@ -1,10 +1,20 @@
+//! Behavior properties of blocks, which mirror vanilla's `BlockBehaviour.Properties`. The
+//! properties of a block are returned by [`BlockId::properties`] and [`Block::behavior`].
+//!
+//! [`BlockId::properties`]: crate::BlockId::properties
+//! [`Block::behavior`]: crate::Block::behavior
+
 use crate::material::{Color, Material};
 use crate::sound;
 
+/// The behavior properties of a block. Every field mirrors the vanilla field of the same name,
+/// except where noted.
 #[allow(clippy::struct_excessive_bools)] // This is, in fact, not a state machine.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Properties {
     pub material: Material,
+    /// The color of the block on maps. Vanilla computes this per block state for a few blocks,
+    /// like logs and beds, of which this is the color of the default state.
     pub material_color: Color,
     pub has_collision: bool,
     pub requires_correct_tool_for_drops: bool,
@@ -17,6 +27,9 @@ pub struct Properties {
     pub jump_factor: f32,
     pub can_occlude: bool,
     pub dynamic_shape: bool,
+    /// Whether the block has a loot table. Vanilla stores the loot table's resource location
+    /// instead.
+    pub has_drops: bool,
     pub is_air: bool,
 }
 
@@ -36,6 +49,7 @@ impl Properties {
             jump_factor: 1.0,
             can_occlude: true,
             dynamic_shape: false,
+            has_drops: true,
             is_air: false,
         }
     }
@@ -48,6 +62,7 @@ impl Properties {
 
     const fn no_collision(mut self) -> Self {
         self.has_collision = false;
+        self.can_occlude = false;
 
         self
     }
@@ -84,7 +99,9 @@ impl Properties {
         self
     }
 
-    const fn no_drops(self) -> Self {
+    const fn no_drops(mut self) -> Self {
+        self.has_drops = false;
+
         self
     }
 
@@ -0,0 +1,213 @@
+//! Serde support for blocks and block IDs. They serialize to the string form used by commands,
+//! like `minecraft:oak_stairs[facing=east,half=top,shape=straight,waterlogged=false]`, and
+//! deserialize from either that or the compound form used by the palettes of chunk sections, like
+//! `{"Name": "minecraft:oak_stairs", "Properties": {"facing": "east"}}`. Property values in the
+//! compound form may also be booleans or integers, as they are often written in JSON.
+//!
+//! Use `#[serde(with = "world_block::compound")]` to serialize a field to the compound form
+//! instead.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::{resource, Block, BlockId};
+
+/// A value that has a compound form, which is either a [`Block`] or a [`BlockId`].
+pub trait Compound: Sized {
+    /// Serializes the value in the compound form.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the serializer fails.
+    fn serialize_compound<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+impl Compound for Block {
+    fn serialize_compound<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Properties(Block);
+
+        impl Serialize for Properties {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(
+                    self.0
+                        .properties()
+                        .map(|(name, property)| (name, property.to_string())),
+                )
+            }
+        }
+
+        let has_properties = !self.state_definition().properties.is_empty();
+        let mut map = serializer.serialize_map(Some(1 + usize::from(has_properties)))?;
+        map.serialize_entry("Name", &self.name())?;
+
+        if has_properties {
+            map.serialize_entry("Properties", &Properties(*self))?;
+        }
+
+        map.end()
+    }
+}
+
+impl Compound for BlockId {
+    fn serialize_compound<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("Name", &format!("minecraft:{}", self.to_location()))?;
+        map.end()
+    }
+}
+
+/// Serializes the block or block ID in the compound form.
+///
+/// # Errors
+///
+/// Returns `Err` if the serializer fails.
+pub fn serialize<T: Compound, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize_compound(serializer)
+}
+
+/// Deserializes the block or block ID from either the compound or the string form.
+///
+/// # Errors
+///
+/// Returns `Err` if the input isn't a valid block or block ID.
+pub fn deserialize<'de, T: Compound + Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::deserialize(deserializer)
+}
+
+impl Serialize for Block {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BlockVisitor;
+
+        impl<'de> Visitor<'de> for BlockVisitor {
+            type Value = Block;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a block state string or a compound with a `Name`")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                s.parse().map_err(E::custom)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                let (name, properties) = read_compound(map)?;
+
+                Block::from_parts(
+                    &name,
+                    properties
+                        .iter()
+                        .map(|(name, PropertyValue(value))| (name.as_str(), value.as_str())),
+                )
+                .map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(BlockVisitor)
+    }
+}
+
+impl Serialize for BlockId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("minecraft:{}", self.to_location()))
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BlockIdVisitor;
+
+        impl<'de> Visitor<'de> for BlockIdVisitor {
+            type Value = BlockId;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a block ID or a compound with a `Name`")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                resource::Location::new(s)
+                    .map_err(anyhow::Error::from)
+                    .and_then(BlockId::try_from)
+                    .map_err(E::custom)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                let (name, properties) = read_compound(map)?;
+
+                if !properties.is_empty() {
+                    return Err(de::Error::custom("expected a block ID without properties"));
+                }
+
+                self.visit_str(&name)
+            }
+        }
+
+        deserializer.deserialize_any(BlockIdVisitor)
+    }
+}
+
+/// Reads the name and the property values of a block in the compound form.
+fn read_compound<'de, A: MapAccess<'de>>(
+    mut map: A,
+) -> Result<(String, BTreeMap<String, PropertyValue>), A::Error> {
+    let mut name = None;
+    let mut properties = None;
+
+    while let Some(key) = map.next_key::<String>()? {
+        match key.as_str() {
+            "Name" if name.is_none() => name = Some(map.next_value()?),
+            "Properties" if properties.is_none() => properties = Some(map.next_value()?),
+            "Name" | "Properties" => return Err(de::Error::custom(format!("duplicate `{key}`"))),
+            _ => return Err(de::Error::unknown_field(&key, &["Name", "Properties"])),
+        }
+    }
+
+    let name = name.ok_or_else(|| de::Error::missing_field("Name"))?;
+
+    Ok((name, properties.unwrap_or_default()))
+}
+
+/// The value of a property in the compound form, which may be a string, a boolean or an integer.
+struct PropertyValue(String);
+
+impl<'de> Deserialize<'de> for PropertyValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PropertyValueVisitor;
+
+        impl Visitor<'_> for PropertyValueVisitor {
+            type Value = PropertyValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string, a boolean or an integer")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                Ok(PropertyValue(s.to_owned()))
+            }
+
+            fn visit_bool<E: de::Error>(self, b: bool) -> Result<Self::Value, E> {
+                Ok(PropertyValue(b.to_string()))
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Self::Value, E> {
+                Ok(PropertyValue(n.to_string()))
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Self::Value, E> {
+                Ok(PropertyValue(n.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(PropertyValueVisitor)
+    }
+}
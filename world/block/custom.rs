@@ -0,0 +1,294 @@
+//! Blocks registered at runtime, like the blocks of data packs or mods. Custom blocks get numeric
+//! IDs above [`BlockId::MAX`] and global state IDs following vanilla's, in the order they are
+//! registered.
+//!
+//! Each custom block extends a vanilla base block, which it behaves like in the world: it has the
+//! properties of its base block, stored the same way, followed by its own. [`Block::id`] returns
+//! the base block of custom blocks, so that the logic of the base block applies to them, while
+//! their name, states and behavior properties are their own.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, OnceLock, RwLock};
+
+use anyhow::{bail, ensure, Result};
+
+use crate::{behavior, registry, resource, shape, state, BlockId};
+
+/// A custom block to be registered with [`register`].
+pub struct CustomBlock {
+    location: String,
+    base: BlockId,
+    properties: Vec<(String, state::Property)>,
+    behavior: behavior::Properties,
+}
+
+impl CustomBlock {
+    /// Creates a custom block with the given resource location, which behaves like the given
+    /// vanilla block and has its behavior properties.
+    #[must_use]
+    pub fn new(location: &str, base: BlockId) -> Self {
+        CustomBlock {
+            location: location.to_owned(),
+            base,
+            properties: Vec::new(),
+            behavior: base.properties().clone(),
+        }
+    }
+
+    /// Adds a state property with the given name to the block, which defaults to the given value.
+    #[must_use]
+    pub fn property(mut self, name: &str, default: impl Into<state::Property>) -> Self {
+        self.properties.push((name.to_owned(), default.into()));
+
+        self
+    }
+
+    /// Changes the behavior properties of the block, which start out as those of its base block.
+    #[must_use]
+    pub fn behavior(mut self, f: impl FnOnce(&mut behavior::Properties)) -> Self {
+        f(&mut self.behavior);
+
+        self
+    }
+}
+
+/// Registers the given custom block, returning its ID. Blocks are usually registered at startup,
+/// before any world is loaded.
+///
+/// # Errors
+///
+/// Returns `Err` if the resource location is invalid, in the `minecraft` namespace or already
+/// registered, if a property is named like another property of the block, if the properties of
+/// the block take up more than 16 bits, or if there are no numeric IDs left.
+pub fn register(block: CustomBlock) -> Result<CustomBlockId> {
+    let CustomBlock {
+        location,
+        base,
+        properties,
+        behavior,
+    } = block;
+    let (namespace, path) = resource::Location::new(&location)?.as_parts();
+
+    ensure!(
+        namespace != "minecraft",
+        "custom block `{location}` can't be in the `minecraft` namespace",
+    );
+
+    let base_definition = base.state_definition();
+    let mut added_properties = Vec::with_capacity(properties.len());
+    let mut offset = base_definition
+        .properties
+        .last()
+        .map_or(16, |definition| definition.offset + definition.id.bits());
+    let mut default_state = base.default_state();
+
+    for (name, default) in properties {
+        ensure!(
+            !base_definition.property_names.contains(&name.as_str())
+                && !added_properties.iter().any(|(n, _, _)| *n == name),
+            "custom block `{location}` has more than one property named `{name}`",
+        );
+
+        let id = default.id();
+
+        if offset + id.bits() > 32 {
+            bail!("the properties of custom block `{location}` take up more than 16 bits");
+        }
+
+        default_state |= default.to_bits() << offset;
+        added_properties.push((name, id, offset));
+        offset += id.bits();
+    }
+
+    let location = format!("{namespace}:{path}");
+    let mut registry = REGISTRY.write().unwrap();
+
+    ensure!(
+        !registry.by_location.contains_key(location.as_str()),
+        "custom block `{location}` is already registered",
+    );
+
+    let Some(numeric_id) = u16::try_from(registry.entries.len())
+        .ok()
+        .and_then(|index| (BlockId::MAX + 1).checked_add(index))
+    else {
+        bail!("there are no numeric IDs left for custom block `{location}`");
+    };
+
+    // The registration can't fail anymore, so the names are leaked only now.
+    let location = &*Box::leak(location.into_boxed_str());
+    let mut property_names = base_definition.property_names.to_vec();
+    let mut property_definitions = base_definition.properties.to_vec();
+
+    for (name, id, offset) in added_properties {
+        let name = &*Box::leak(name.into_boxed_str());
+        property_names.push(name);
+        property_definitions.push(state::PropertyDefinition { name, id, offset });
+    }
+
+    let state_definition = state::Definition {
+        property_names: Box::leak(property_names.into_boxed_slice()),
+        properties: Box::leak(property_definitions.into_boxed_slice()),
+    };
+    let state_count = state_definition
+        .properties
+        .iter()
+        .map(|definition| definition.id.value_count())
+        .product();
+    let base_state_id = registry
+        .entries
+        .last()
+        .map_or(registry::STATE_COUNT, |entry| {
+            entry.base_state_id + entry.state_count
+        });
+
+    let entry = &*Box::leak(Box::new(Entry {
+        location,
+        base,
+        state_definition,
+        default_state,
+        behavior,
+        base_state_id,
+        state_count,
+        shapes: OnceLock::new(),
+    }));
+
+    // The lock is held while setting the entry, so the slot can't have been set before.
+    let _ = ENTRIES[registry.entries.len()].set(entry);
+    registry.entries.push(entry);
+    registry
+        .by_location
+        .insert(location, CustomBlockId(numeric_id));
+
+    Ok(CustomBlockId(numeric_id))
+}
+
+/// The numeric ID of a registered custom block, which is above [`BlockId::MAX`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CustomBlockId(u16);
+
+impl CustomBlockId {
+    /// Returns the ID of the custom block with the given resource location, like
+    /// `example:ruby_block`, if it's registered.
+    #[must_use]
+    pub fn from_location(location: &str) -> Option<Self> {
+        REGISTRY.read().unwrap().by_location.get(location).copied()
+    }
+
+    /// Returns the ID of the custom block with the given numeric ID, if it's registered.
+    #[must_use]
+    pub fn from_numeric(id: u16) -> Option<Self> {
+        let index = usize::from(id.checked_sub(BlockId::MAX + 1)?);
+
+        ENTRIES[index].get().map(|_| CustomBlockId(id))
+    }
+
+    /// Returns the numeric ID of the custom block.
+    #[must_use]
+    pub const fn to_numeric(self) -> u16 {
+        self.0
+    }
+
+    /// Returns an iterator over the IDs of all registered custom blocks, in the order they were
+    /// registered.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let count = REGISTRY.read().unwrap().entries.len() as u16;
+
+        (BlockId::MAX + 1..BlockId::MAX + 1 + count).map(CustomBlockId)
+    }
+
+    /// Returns the resource location of the custom block, including its namespace.
+    #[must_use]
+    pub fn location(self) -> &'static str {
+        self.entry().location
+    }
+
+    /// Returns the vanilla block the custom block behaves like.
+    #[must_use]
+    pub fn base(self) -> BlockId {
+        self.entry().base
+    }
+
+    /// Returns the definition of the state properties of the custom block, which starts with
+    /// those of its base block.
+    #[must_use]
+    pub fn state_definition(self) -> &'static state::Definition {
+        &self.entry().state_definition
+    }
+
+    /// Returns the behavior properties of the custom block.
+    #[must_use]
+    pub fn properties(self) -> &'static behavior::Properties {
+        &self.entry().behavior
+    }
+
+    /// Returns the number of valid states of the custom block.
+    #[must_use]
+    pub fn state_count(self) -> u32 {
+        self.entry().state_count
+    }
+
+    /// Returns the global state ID of the first state of the custom block.
+    pub(crate) fn base_state_id(self) -> u32 {
+        self.entry().base_state_id
+    }
+
+    /// Returns the bits of the properties of the custom block in its default state.
+    pub(crate) fn default_state(self) -> u32 {
+        self.entry().default_state
+    }
+
+    /// Returns the shapes of the states of the custom block, in the order of their global state
+    /// IDs, which are computed by [`shape`] the first time they're needed.
+    pub(crate) fn shapes(self) -> &'static OnceLock<Box<[shape::Shapes]>> {
+        &self.entry().shapes
+    }
+
+    fn entry(self) -> &'static Entry {
+        ENTRIES[usize::from(self.0 - BlockId::MAX - 1)]
+            .get()
+            .expect("custom block IDs are only created for registered blocks")
+    }
+}
+
+/// Returns the custom block whose states include the given global state ID, if any.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn from_state_id(state_id: u32) -> Option<CustomBlockId> {
+    let registry = REGISTRY.read().unwrap();
+    let index = registry
+        .entries
+        .partition_point(|entry| entry.base_state_id + entry.state_count <= state_id);
+    let entry = registry.entries.get(index)?;
+
+    (entry.base_state_id <= state_id).then(|| CustomBlockId(BlockId::MAX + 1 + index as u16))
+}
+
+/// A registered custom block. Entries are leaked so that they can be handed out without holding
+/// the lock on the registry, as blocks are never unregistered.
+struct Entry {
+    location: &'static str,
+    base: BlockId,
+    state_definition: state::Definition,
+    default_state: u32,
+    behavior: behavior::Properties,
+    base_state_id: u32,
+    state_count: u32,
+    shapes: OnceLock<Box<[shape::Shapes]>>,
+}
+
+#[derive(Default)]
+struct Registry {
+    entries: Vec<&'static Entry>,
+    by_location: HashMap<&'static str, CustomBlockId>,
+}
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(RwLock::default);
+
+/// The entries of the registry indexed by their numeric ID minus [`BlockId::MAX`] + 1, which
+/// blocks look up without taking the lock. Slots are only ever set once, while the registry is
+/// locked for writing.
+static ENTRIES: [OnceLock<&'static Entry>; ENTRY_COUNT] = [const { OnceLock::new() }; ENTRY_COUNT];
+
+/// The number of numeric IDs available to custom blocks.
+const ENTRY_COUNT: usize = (u16::MAX - BlockId::MAX) as usize;
@@ -0,0 +1,98 @@
+//! How likely blocks are to catch fire and to burn away, following vanilla's flammability table.
+
+use crate::tag::vanilla;
+use crate::{state, Block, BlockId};
+
+/// The odds of a block catching fire and burning away. Both are 0 for blocks that don't burn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Flammability {
+    /// How likely fire is to spread to an empty space next to the block, from 0 to 100.
+    pub encouragement: u8,
+    /// How likely the block is to burn away when fire is next to it, from 0 to 100.
+    pub flammability: u8,
+}
+
+impl Flammability {
+    /// The flammability of blocks that don't burn.
+    pub const NONE: Self = Self::new(0, 0);
+
+    const PLANKS: Self = Self::new(5, 20);
+    const LOG: Self = Self::new(5, 5);
+    const LEAVES: Self = Self::new(30, 60);
+    const PLANT: Self = Self::new(60, 100);
+
+    const fn new(encouragement: u8, flammability: u8) -> Self {
+        Self {
+            encouragement,
+            flammability,
+        }
+    }
+}
+
+impl Block {
+    /// Returns the odds of the block catching fire and burning away. Waterlogged blocks never
+    /// burn, and neither do nether woods, even though their material is wood.
+    #[must_use]
+    pub fn flammability(self) -> Flammability {
+        use BlockId::*;
+
+        if self
+            .get::<state::Waterlogged>()
+            .is_some_and(|waterlogged| waterlogged.0)
+        {
+            return Flammability::NONE;
+        }
+
+        let id = self.id();
+
+        match id {
+            OakPlanks | SprucePlanks | BirchPlanks | JunglePlanks | AcaciaPlanks
+            | DarkOakPlanks | OakSlab | SpruceSlab | BirchSlab | JungleSlab | AcaciaSlab
+            | DarkOakSlab | OakFenceGate | SpruceFenceGate | BirchFenceGate | JungleFenceGate
+            | AcaciaFenceGate | DarkOakFenceGate | OakFence | SpruceFence | BirchFence
+            | JungleFence | AcaciaFence | DarkOakFence | OakStairs | SpruceStairs | BirchStairs
+            | JungleStairs | AcaciaStairs | DarkOakStairs | Composter | Beehive => {
+                Flammability::PLANKS
+            }
+            OakLog | SpruceLog | BirchLog | JungleLog | AcaciaLog | DarkOakLog | StrippedOakLog
+            | StrippedSpruceLog | StrippedBirchLog | StrippedJungleLog | StrippedAcaciaLog
+            | StrippedDarkOakLog | StrippedOakWood | StrippedSpruceWood | StrippedBirchWood
+            | StrippedJungleWood | StrippedAcaciaWood | StrippedDarkOakWood | OakWood
+            | SpruceWood | BirchWood | JungleWood | AcaciaWood | DarkOakWood | CoalBlock => {
+                Flammability::LOG
+            }
+            OakLeaves
+            | SpruceLeaves
+            | BirchLeaves
+            | JungleLeaves
+            | AcaciaLeaves
+            | DarkOakLeaves
+            | AzaleaLeaves
+            | FloweringAzaleaLeaves
+            | DriedKelpBlock
+            | Azalea
+            | FloweringAzalea
+            | HangingRoots => Flammability::LEAVES,
+            Bookshelf | Lectern | BeeNest => Flammability::new(30, 20),
+            Tnt | Vine | GlowLichen => Flammability::new(15, 100),
+            Grass | Fern | DeadBush | Sunflower | Lilac | RoseBush | Peony | TallGrass
+            | LargeFern | Dandelion | Poppy | BlueOrchid | Allium | AzureBluet | RedTulip
+            | OrangeTulip | WhiteTulip | PinkTulip | OxeyeDaisy | Cornflower | LilyOfTheValley
+            | WitherRose | SweetBerryBush | SporeBlossom | BigDripleaf | BigDripleafStem
+            | SmallDripleaf => Flammability::PLANT,
+            HayBlock => Flammability::new(60, 20),
+            Target => Flammability::new(15, 20),
+            Bamboo | Scaffolding => Flammability::new(60, 60),
+            CaveVines | CaveVinesPlant => Flammability::new(15, 60),
+            _ if id.is_in(vanilla("wool")) => Flammability::LEAVES,
+            _ if id.is_in(vanilla("carpets")) => Flammability::new(60, 20),
+            _ => Flammability::NONE,
+        }
+    }
+
+    /// Returns `true` if fire can spread to the block, which also keeps fire next to it alive.
+    #[must_use]
+    pub fn can_burn(self) -> bool {
+        self.flammability().encouragement > 0
+    }
+}
@@ -0,0 +1,169 @@
+//! Fluid states, which describe the water or lava contained in a block, be it a fluid block or a
+//! waterlogged one.
+
+use crate::state::{self, Integer};
+use crate::{blocks, Block, BlockId};
+
+impl Block {
+    /// Returns the state of the fluid contained in the block.
+    #[must_use]
+    pub fn fluid_state(self) -> FluidState {
+        match self.id() {
+            BlockId::Water => FluidState::from_level(Fluid::Water, self.level()),
+            BlockId::Lava => FluidState::from_level(Fluid::Lava, self.level()),
+            // These blocks are always underwater.
+            BlockId::BubbleColumn
+            | BlockId::Kelp
+            | BlockId::KelpPlant
+            | BlockId::Seagrass
+            | BlockId::TallSeagrass => FluidState::source(Fluid::Water),
+            _ if self.get::<state::Waterlogged>() == Some(state::Waterlogged(true)) => {
+                FluidState::source(Fluid::Water)
+            }
+            _ => FluidState::EMPTY,
+        }
+    }
+
+    fn level(self) -> u32 {
+        self.get::<state::Level>().map_or(0, |state::Level(level)| level.get())
+    }
+}
+
+/// A kind of fluid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Fluid {
+    #[default]
+    Empty,
+    Water,
+    Lava,
+}
+
+/// The state of a fluid, which is either a source or flowing. The amount of fluid goes from 1 to
+/// 8, 8 being the amount of a source or of falling fluid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FluidState {
+    fluid: Fluid,
+    amount: u8,
+    is_source: bool,
+    is_falling: bool,
+}
+
+impl FluidState {
+    /// The state of blocks without any fluid.
+    pub const EMPTY: Self = FluidState {
+        fluid: Fluid::Empty,
+        amount: 0,
+        is_source: false,
+        is_falling: false,
+    };
+
+    /// Creates a source of the given fluid.
+    #[must_use]
+    pub const fn source(fluid: Fluid) -> Self {
+        if let Fluid::Empty = fluid {
+            return FluidState::EMPTY;
+        }
+
+        FluidState {
+            fluid,
+            amount: 8,
+            is_source: true,
+            is_falling: false,
+        }
+    }
+
+    /// Creates a flowing fluid with the given amount, which is clamped between 1 and 8.
+    #[must_use]
+    pub const fn flowing(fluid: Fluid, amount: u8, is_falling: bool) -> Self {
+        if let Fluid::Empty = fluid {
+            return FluidState::EMPTY;
+        }
+
+        FluidState {
+            fluid,
+            amount: if amount < 1 {
+                1
+            } else if amount > 8 {
+                8
+            } else {
+                amount
+            },
+            is_source: false,
+            is_falling,
+        }
+    }
+
+    /// Creates the fluid state of a fluid block with the given `level` property. Level 0 is a
+    /// source, levels 1 to 7 are flowing with decreasing amounts and levels of 8 and above are
+    /// falling.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn from_level(fluid: Fluid, level: u32) -> Self {
+        match level {
+            0 => FluidState::source(fluid),
+            1..=7 => FluidState::flowing(fluid, 8 - level as u8, false),
+            _ => FluidState::flowing(fluid, 8, true),
+        }
+    }
+
+    /// Returns the kind of fluid.
+    #[must_use]
+    pub const fn fluid(self) -> Fluid {
+        self.fluid
+    }
+
+    /// Returns the amount of fluid, from 0 for empty states to 8 for sources and falling fluid.
+    #[must_use]
+    pub const fn amount(self) -> u8 {
+        self.amount
+    }
+
+    /// Returns `true` if the state holds no fluid.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        matches!(self.fluid, Fluid::Empty)
+    }
+
+    /// Returns `true` if the fluid is a source.
+    #[must_use]
+    pub const fn is_source(self) -> bool {
+        self.is_source
+    }
+
+    /// Returns `true` if the fluid is flowing down from above.
+    #[must_use]
+    pub const fn is_falling(self) -> bool {
+        self.is_falling
+    }
+
+    /// Returns the height of the fluid surface relative to the bottom of the block, ignoring the
+    /// fluid above it.
+    #[must_use]
+    pub fn own_height(self) -> f32 {
+        f32::from(self.amount) / 9.0
+    }
+
+    /// Returns the `level` property of the fluid block holding this state.
+    #[must_use]
+    pub const fn level(self) -> u32 {
+        if self.is_source {
+            0
+        } else if self.is_falling {
+            8
+        } else {
+            8 - self.amount as u32
+        }
+    }
+
+    /// Returns the fluid block holding this state, or air for empty states.
+    #[must_use]
+    pub fn to_block(self) -> Block {
+        let level = state::Level(Integer::new(self.level()).expect("level should be in range"));
+
+        match self.fluid {
+            Fluid::Empty => Block::from_id(BlockId::Air),
+            Fluid::Water => blocks::Water { level }.into(),
+            Fluid::Lava => blocks::Lava { level }.into(),
+        }
+    }
+}
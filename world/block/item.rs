@@ -0,0 +1,3634 @@
+//! Items, which are what blocks become when held in inventories and containers.
+
+use std::{hash, mem, str};
+
+use anyhow::{anyhow, Result};
+use enum_map::{Enum, EnumArray};
+use quartz_nbt::{NbtCompound, NbtList};
+
+use crate::macros::items;
+use crate::{resource, BlockId};
+
+impl BlockId {
+    /// Returns the item of the block, if it has any. This is the item that places the block, for
+    /// example [`ItemId::Torch`] for both [`BlockId::Torch`] and [`BlockId::WallTorch`], or
+    /// [`ItemId::WheatSeeds`] for [`BlockId::Wheat`].
+    #[must_use]
+    pub const fn item(self) -> Option<ItemId> {
+        const LUT: [Option<ItemId>; BlockId::COUNT] = {
+            let mut lut = [None; BlockId::COUNT];
+            let mut id = 0;
+
+            while id <= ItemId::MAX {
+                // SAFETY: The ID is in bounds of the enum discriminants.
+                let item = unsafe { ItemId::from_numeric_unchecked(id) };
+                let properties = item.properties();
+
+                if let Some(block) = properties.block {
+                    lut[block as usize] = Some(item);
+                }
+
+                if let Some(block) = properties.wall_block {
+                    lut[block as usize] = Some(item);
+                }
+
+                id += 1;
+            }
+
+            lut
+        };
+
+        LUT[self as usize]
+    }
+}
+
+/// The rarity of an item, which determines the color of its name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+}
+
+/// The kind of a tool, along with its tier if it has any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Tool {
+    Sword(Tier),
+    Shovel(Tier),
+    Pickaxe(Tier),
+    Axe(Tier),
+    Hoe(Tier),
+    Shears,
+}
+
+/// The material a tool is made of, which determines which blocks it can harvest and how fast it
+/// mines them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Tier {
+    Wood,
+    Stone,
+    Iron,
+    Diamond,
+    Gold,
+    Netherite,
+}
+
+impl Tier {
+    /// Returns the mining level of the tier. Blocks in the `needs_stone_tool`, `needs_iron_tool`
+    /// and `needs_diamond_tool` tags require a level of at least 1, 2 and 3 respectively.
+    #[must_use]
+    pub const fn level(self) -> u8 {
+        match self {
+            Tier::Wood | Tier::Gold => 0,
+            Tier::Stone => 1,
+            Tier::Iron => 2,
+            Tier::Diamond => 3,
+            Tier::Netherite => 4,
+        }
+    }
+
+    /// Returns the speed multiplier of the tier, applied when mining blocks the tool is meant for.
+    #[must_use]
+    pub const fn speed(self) -> f32 {
+        match self {
+            Tier::Wood => 2.0,
+            Tier::Stone => 4.0,
+            Tier::Iron => 6.0,
+            Tier::Diamond => 8.0,
+            Tier::Gold => 12.0,
+            Tier::Netherite => 9.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Properties {
+    max_stack_size: u8,
+    rarity: Rarity,
+    block: Option<BlockId>,
+    wall_block: Option<BlockId>,
+    tool: Option<Tool>,
+}
+
+impl ItemId {
+    /// Converts the given numeric ID to an `ItemId`.
+    #[must_use]
+    pub const fn from_numeric(id: u16) -> Option<Self> {
+        if id <= Self::MAX {
+            // SAFETY: We checked that the ID is in bounds of the enum discriminants.
+            Some(unsafe { ItemId::from_numeric_unchecked(id) })
+        } else {
+            None
+        }
+    }
+
+    /// Converts the given numeric ID to an `ItemId` without checking that the value is within
+    /// bounds.
+    ///
+    /// # Safety
+    ///
+    /// The given `id` must not exceed [`ItemId::MAX`].
+    #[must_use]
+    pub const unsafe fn from_numeric_unchecked(id: u16) -> Self {
+        debug_assert!(id <= Self::MAX);
+
+        mem::transmute::<u16, ItemId>(id)
+    }
+
+    /// Converts the `ItemId` to a numeric ID.
+    #[must_use]
+    pub const fn to_numeric(self) -> u16 {
+        self as u16
+    }
+
+    /// Returns the maximum number of items in a stack of this item.
+    #[must_use]
+    pub const fn max_stack_size(self) -> u8 {
+        self.properties().max_stack_size
+    }
+
+    /// Returns the rarity of the item, before taking enchantments into account.
+    #[must_use]
+    pub const fn rarity(self) -> Rarity {
+        self.properties().rarity
+    }
+
+    /// Returns the block placed by the item, if any. For items that place a different block when
+    /// used on the side of a block, like torches, this is the standing variant.
+    #[must_use]
+    pub const fn block(self) -> Option<BlockId> {
+        self.properties().block
+    }
+
+    /// Returns the tool the item is, if any.
+    #[must_use]
+    pub const fn tool(self) -> Option<Tool> {
+        self.properties().tool
+    }
+
+    /// Returns an iterator over all item IDs.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        (0..=Self::MAX).map(|id| {
+            // SAFETY: The ID is in bounds of the enum discriminants.
+            unsafe { ItemId::from_numeric_unchecked(id) }
+        })
+    }
+}
+
+impl Enum for ItemId {
+    const LENGTH: usize = Self::COUNT;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_usize(id: usize) -> Self {
+        if id < Self::COUNT {
+            // SAFETY: We checked that the ID is in bounds of the enum discriminants.
+            unsafe { ItemId::from_numeric_unchecked(id as u16) }
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn into_usize(self) -> usize {
+        usize::from(self.to_numeric())
+    }
+}
+
+impl<V> EnumArray<V> for ItemId {
+    type Array = [V; Self::COUNT];
+}
+
+impl PartialEq for ItemId {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_numeric() == other.to_numeric()
+    }
+}
+
+impl Eq for ItemId {}
+
+impl hash::Hash for ItemId {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.to_numeric().hash(state);
+    }
+}
+
+impl TryFrom<&resource::Location> for ItemId {
+    type Error = anyhow::Error;
+
+    fn try_from(location: &resource::Location) -> Result<Self, Self::Error> {
+        match location.as_parts() {
+            ("minecraft", path) => path.parse(),
+            (namespace, _) => Err(anyhow!("unknown namespace `{namespace}` in `{location}`")),
+        }
+    }
+}
+
+impl str::FromStr for ItemId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ItemId::from_location(s).ok_or_else(|| anyhow!("`{s}` does not match any item ID"))
+    }
+}
+
+/// A stack of items, as found in inventories and containers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemStack {
+    pub id: ItemId,
+    pub count: u8,
+    /// Additional data of the stack, like its enchantments or custom name.
+    pub nbt: Option<NbtCompound>,
+}
+
+impl ItemStack {
+    /// Creates a new stack of the given item without any NBT.
+    #[must_use]
+    pub const fn new(id: ItemId, count: u8) -> Self {
+        ItemStack {
+            id,
+            count,
+            nbt: None,
+        }
+    }
+
+    /// Returns `true` if the stack holds no items, which is the case for air and for stacks with a
+    /// count of zero.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.id == ItemId::Air || self.count == 0
+    }
+
+    /// Returns `true` if the stack has any enchantments. Stored enchantments, like those of
+    /// enchanted books, do not count.
+    #[must_use]
+    pub fn is_enchanted(&self) -> bool {
+        self.nbt
+            .as_ref()
+            .and_then(|nbt| nbt.get::<_, &NbtList>("Enchantments").ok())
+            .is_some_and(|enchantments| !enchantments.is_empty())
+    }
+
+    /// Returns the level of the given enchantment on the stack, or 0 if it doesn't have it.
+    /// Stored enchantments, like those of enchanted books, do not count.
+    #[must_use]
+    pub fn enchantment_level(&self, enchantment: &resource::Location) -> u8 {
+        let Some(enchantments) = self
+            .nbt
+            .as_ref()
+            .and_then(|nbt| nbt.get::<_, &NbtList>("Enchantments").ok())
+        else {
+            return 0;
+        };
+
+        enchantments
+            .iter()
+            .filter_map(|tag| <&NbtCompound>::try_from(tag).ok())
+            .find(|tag| {
+                tag.get::<_, &str>("id")
+                    .ok()
+                    .and_then(|id| resource::Location::new(id).ok())
+                    .is_some_and(|id| id == enchantment)
+            })
+            .and_then(|tag| tag.get::<_, i16>("lvl").ok())
+            .map_or(0, |level| u8::try_from(level.clamp(0, 255)).unwrap())
+    }
+
+    /// Returns the rarity of the stack, which is raised by one level if it is enchanted.
+    #[must_use]
+    pub fn rarity(&self) -> Rarity {
+        match self.id.rarity() {
+            Rarity::Common | Rarity::Uncommon if self.is_enchanted() => Rarity::Rare,
+            Rarity::Rare if self.is_enchanted() => Rarity::Epic,
+            rarity => rarity,
+        }
+    }
+
+    /// Parses the `Items` list of a container block entity, returning each stack along with the
+    /// slot it is in. Like in vanilla, stacks with a count of zero or less are kept as empty
+    /// stacks.
+    pub fn parse_items(items: &NbtList) -> Result<Vec<(u8, ItemStack)>> {
+        items
+            .iter()
+            .map(|tag| {
+                let tag = <&NbtCompound>::try_from(tag)?;
+                let slot = tag.get::<_, i8>("Slot")?.cast_unsigned();
+
+                Ok((slot, ItemStack::try_from(tag)?))
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<&NbtCompound> for ItemStack {
+    type Error = anyhow::Error;
+
+    fn try_from(tag: &NbtCompound) -> Result<Self, Self::Error> {
+        let location = resource::Location::new(tag.get::<_, &str>("id")?)?;
+        let id = ItemId::try_from(location)?;
+        // Vanilla keeps negative counts and treats such stacks as empty, like those with a count
+        // of zero. We store them as zero, which `is_empty` treats the same.
+        let count = u8::try_from(tag.get::<_, i8>("Count")?).unwrap_or(0);
+        let nbt = tag.get::<_, &NbtCompound>("tag").ok().cloned();
+
+        Ok(ItemStack { id, count, nbt })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+items! {
+    // Air is at the very top so that it has a numeric ID of 0, like in vanilla.
+    #[block]
+    struct Air;
+
+    #[max_stack_size(1)]
+    struct AcaciaBoat;
+
+    #[block]
+    struct AcaciaButton;
+
+    #[block]
+    struct AcaciaDoor;
+
+    #[block]
+    struct AcaciaFence;
+
+    #[block]
+    struct AcaciaFenceGate;
+
+    #[block]
+    struct AcaciaLeaves;
+
+    #[block]
+    struct AcaciaLog;
+
+    #[block]
+    struct AcaciaPlanks;
+
+    #[block]
+    struct AcaciaPressurePlate;
+
+    #[block]
+    struct AcaciaSapling;
+
+    #[block]
+    #[wall_block(AcaciaWallSign)]
+    #[max_stack_size(16)]
+    struct AcaciaSign;
+
+    #[block]
+    struct AcaciaSlab;
+
+    #[block]
+    struct AcaciaStairs;
+
+    #[block]
+    struct AcaciaTrapdoor;
+
+    #[block]
+    struct AcaciaWood;
+
+    #[block]
+    struct ActivatorRail;
+
+    #[block]
+    struct Allium;
+
+    #[block]
+    struct AmethystBlock;
+
+    #[block]
+    struct AmethystCluster;
+
+    struct AmethystShard;
+
+    #[block]
+    struct AncientDebris;
+
+    #[block]
+    struct Andesite;
+
+    #[block]
+    struct AndesiteSlab;
+
+    #[block]
+    struct AndesiteStairs;
+
+    #[block]
+    struct AndesiteWall;
+
+    #[block]
+    struct Anvil;
+
+    struct Apple;
+
+    #[max_stack_size(16)]
+    struct ArmorStand;
+
+    struct Arrow;
+
+    #[max_stack_size(1)]
+    struct AxolotlBucket;
+
+    struct AxolotlSpawnEgg;
+
+    #[block]
+    struct Azalea;
+
+    #[block]
+    struct AzaleaLeaves;
+
+    #[block]
+    struct AzureBluet;
+
+    struct BakedPotato;
+
+    #[block]
+    struct Bamboo;
+
+    #[block]
+    struct Barrel;
+
+    #[block]
+    struct Barrier;
+
+    #[block]
+    struct Basalt;
+
+    struct BatSpawnEgg;
+
+    #[block]
+    #[rarity(Rare)]
+    struct Beacon;
+
+    #[block]
+    struct Bedrock;
+
+    #[block]
+    struct BeeNest;
+
+    struct BeeSpawnEgg;
+
+    struct Beef;
+
+    #[block]
+    struct Beehive;
+
+    struct Beetroot;
+
+    #[block(Beetroots)]
+    struct BeetrootSeeds;
+
+    #[max_stack_size(1)]
+    struct BeetrootSoup;
+
+    #[block]
+    struct Bell;
+
+    #[block]
+    struct BigDripleaf;
+
+    #[max_stack_size(1)]
+    struct BirchBoat;
+
+    #[block]
+    struct BirchButton;
+
+    #[block]
+    struct BirchDoor;
+
+    #[block]
+    struct BirchFence;
+
+    #[block]
+    struct BirchFenceGate;
+
+    #[block]
+    struct BirchLeaves;
+
+    #[block]
+    struct BirchLog;
+
+    #[block]
+    struct BirchPlanks;
+
+    #[block]
+    struct BirchPressurePlate;
+
+    #[block]
+    struct BirchSapling;
+
+    #[block]
+    #[wall_block(BirchWallSign)]
+    #[max_stack_size(16)]
+    struct BirchSign;
+
+    #[block]
+    struct BirchSlab;
+
+    #[block]
+    struct BirchStairs;
+
+    #[block]
+    struct BirchTrapdoor;
+
+    #[block]
+    struct BirchWood;
+
+    #[block]
+    #[wall_block(BlackWallBanner)]
+    #[max_stack_size(16)]
+    struct BlackBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct BlackBed;
+
+    #[block]
+    struct BlackCandle;
+
+    #[block]
+    struct BlackCarpet;
+
+    #[block]
+    struct BlackConcrete;
+
+    #[block]
+    struct BlackConcretePowder;
+
+    struct BlackDye;
+
+    #[block]
+    struct BlackGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct BlackShulkerBox;
+
+    #[block]
+    struct BlackStainedGlass;
+
+    #[block]
+    struct BlackStainedGlassPane;
+
+    #[block]
+    struct BlackTerracotta;
+
+    #[block]
+    struct BlackWool;
+
+    #[block]
+    struct Blackstone;
+
+    #[block]
+    struct BlackstoneSlab;
+
+    #[block]
+    struct BlackstoneStairs;
+
+    #[block]
+    struct BlackstoneWall;
+
+    #[block]
+    struct BlastFurnace;
+
+    struct BlazePowder;
+
+    struct BlazeRod;
+
+    struct BlazeSpawnEgg;
+
+    #[block]
+    #[wall_block(BlueWallBanner)]
+    #[max_stack_size(16)]
+    struct BlueBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct BlueBed;
+
+    #[block]
+    struct BlueCandle;
+
+    #[block]
+    struct BlueCarpet;
+
+    #[block]
+    struct BlueConcrete;
+
+    #[block]
+    struct BlueConcretePowder;
+
+    struct BlueDye;
+
+    #[block]
+    struct BlueGlazedTerracotta;
+
+    #[block]
+    struct BlueIce;
+
+    #[block]
+    struct BlueOrchid;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct BlueShulkerBox;
+
+    #[block]
+    struct BlueStainedGlass;
+
+    #[block]
+    struct BlueStainedGlassPane;
+
+    #[block]
+    struct BlueTerracotta;
+
+    #[block]
+    struct BlueWool;
+
+    struct Bone;
+
+    #[block]
+    struct BoneBlock;
+
+    struct BoneMeal;
+
+    struct Book;
+
+    #[block]
+    struct Bookshelf;
+
+    #[max_stack_size(1)]
+    struct Bow;
+
+    struct Bowl;
+
+    #[block]
+    struct BrainCoral;
+
+    #[block]
+    struct BrainCoralBlock;
+
+    #[block]
+    #[wall_block(BrainCoralWallFan)]
+    struct BrainCoralFan;
+
+    struct Bread;
+
+    #[block]
+    struct BrewingStand;
+
+    struct Brick;
+
+    #[block]
+    struct BrickSlab;
+
+    #[block]
+    struct BrickStairs;
+
+    #[block]
+    struct BrickWall;
+
+    #[block]
+    struct Bricks;
+
+    #[block]
+    #[wall_block(BrownWallBanner)]
+    #[max_stack_size(16)]
+    struct BrownBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct BrownBed;
+
+    #[block]
+    struct BrownCandle;
+
+    #[block]
+    struct BrownCarpet;
+
+    #[block]
+    struct BrownConcrete;
+
+    #[block]
+    struct BrownConcretePowder;
+
+    struct BrownDye;
+
+    #[block]
+    struct BrownGlazedTerracotta;
+
+    #[block]
+    struct BrownMushroom;
+
+    #[block]
+    struct BrownMushroomBlock;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct BrownShulkerBox;
+
+    #[block]
+    struct BrownStainedGlass;
+
+    #[block]
+    struct BrownStainedGlassPane;
+
+    #[block]
+    struct BrownTerracotta;
+
+    #[block]
+    struct BrownWool;
+
+    #[block]
+    struct BubbleCoral;
+
+    #[block]
+    struct BubbleCoralBlock;
+
+    #[block]
+    #[wall_block(BubbleCoralWallFan)]
+    struct BubbleCoralFan;
+
+    #[max_stack_size(16)]
+    struct Bucket;
+
+    #[block]
+    struct BuddingAmethyst;
+
+    #[max_stack_size(1)]
+    struct Bundle;
+
+    #[block]
+    struct Cactus;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct Cake;
+
+    #[block]
+    struct Calcite;
+
+    #[block]
+    struct Campfire;
+
+    #[block]
+    struct Candle;
+
+    #[block(Carrots)]
+    struct Carrot;
+
+    #[max_stack_size(1)]
+    struct CarrotOnAStick;
+
+    #[block]
+    struct CartographyTable;
+
+    #[block]
+    struct CarvedPumpkin;
+
+    struct CatSpawnEgg;
+
+    #[block]
+    struct Cauldron;
+
+    struct CaveSpiderSpawnEgg;
+
+    #[block]
+    struct Chain;
+
+    #[block]
+    #[rarity(Epic)]
+    struct ChainCommandBlock;
+
+    #[max_stack_size(1)]
+    struct ChainmailBoots;
+
+    #[max_stack_size(1)]
+    struct ChainmailChestplate;
+
+    #[max_stack_size(1)]
+    struct ChainmailHelmet;
+
+    #[max_stack_size(1)]
+    struct ChainmailLeggings;
+
+    struct Charcoal;
+
+    #[block]
+    struct Chest;
+
+    #[max_stack_size(1)]
+    struct ChestMinecart;
+
+    struct Chicken;
+
+    struct ChickenSpawnEgg;
+
+    #[block]
+    struct ChippedAnvil;
+
+    #[block]
+    struct ChiseledDeepslate;
+
+    #[block]
+    struct ChiseledNetherBricks;
+
+    #[block]
+    struct ChiseledPolishedBlackstone;
+
+    #[block]
+    struct ChiseledQuartzBlock;
+
+    #[block]
+    struct ChiseledRedSandstone;
+
+    #[block]
+    struct ChiseledSandstone;
+
+    #[block]
+    struct ChiseledStoneBricks;
+
+    #[block]
+    struct ChorusFlower;
+
+    struct ChorusFruit;
+
+    #[block]
+    struct ChorusPlant;
+
+    #[block]
+    struct Clay;
+
+    struct ClayBall;
+
+    struct Clock;
+
+    struct Coal;
+
+    #[block]
+    struct CoalBlock;
+
+    #[block]
+    struct CoalOre;
+
+    #[block]
+    struct CoarseDirt;
+
+    #[block]
+    struct CobbledDeepslate;
+
+    #[block]
+    struct CobbledDeepslateSlab;
+
+    #[block]
+    struct CobbledDeepslateStairs;
+
+    #[block]
+    struct CobbledDeepslateWall;
+
+    #[block]
+    struct Cobblestone;
+
+    #[block]
+    struct CobblestoneSlab;
+
+    #[block]
+    struct CobblestoneStairs;
+
+    #[block]
+    struct CobblestoneWall;
+
+    #[block]
+    struct Cobweb;
+
+    #[block(Cocoa)]
+    struct CocoaBeans;
+
+    struct Cod;
+
+    #[max_stack_size(1)]
+    struct CodBucket;
+
+    struct CodSpawnEgg;
+
+    #[block]
+    #[rarity(Epic)]
+    struct CommandBlock;
+
+    #[max_stack_size(1)]
+    #[rarity(Epic)]
+    struct CommandBlockMinecart;
+
+    #[block]
+    struct Comparator;
+
+    struct Compass;
+
+    #[block]
+    struct Composter;
+
+    #[block]
+    #[rarity(Rare)]
+    struct Conduit;
+
+    struct CookedBeef;
+
+    struct CookedChicken;
+
+    struct CookedCod;
+
+    struct CookedMutton;
+
+    struct CookedPorkchop;
+
+    struct CookedRabbit;
+
+    struct CookedSalmon;
+
+    struct Cookie;
+
+    #[block]
+    struct CopperBlock;
+
+    struct CopperIngot;
+
+    #[block]
+    struct CopperOre;
+
+    #[block]
+    struct Cornflower;
+
+    struct CowSpawnEgg;
+
+    #[block]
+    struct CrackedDeepslateBricks;
+
+    #[block]
+    struct CrackedDeepslateTiles;
+
+    #[block]
+    struct CrackedNetherBricks;
+
+    #[block]
+    struct CrackedPolishedBlackstoneBricks;
+
+    #[block]
+    struct CrackedStoneBricks;
+
+    #[block]
+    struct CraftingTable;
+
+    #[max_stack_size(1)]
+    #[rarity(Uncommon)]
+    struct CreeperBannerPattern;
+
+    #[block]
+    #[wall_block(CreeperWallHead)]
+    #[rarity(Uncommon)]
+    struct CreeperHead;
+
+    struct CreeperSpawnEgg;
+
+    #[block]
+    struct CrimsonButton;
+
+    #[block]
+    struct CrimsonDoor;
+
+    #[block]
+    struct CrimsonFence;
+
+    #[block]
+    struct CrimsonFenceGate;
+
+    #[block]
+    struct CrimsonFungus;
+
+    #[block]
+    struct CrimsonHyphae;
+
+    #[block]
+    struct CrimsonNylium;
+
+    #[block]
+    struct CrimsonPlanks;
+
+    #[block]
+    struct CrimsonPressurePlate;
+
+    #[block]
+    struct CrimsonRoots;
+
+    #[block]
+    #[wall_block(CrimsonWallSign)]
+    #[max_stack_size(16)]
+    struct CrimsonSign;
+
+    #[block]
+    struct CrimsonSlab;
+
+    #[block]
+    struct CrimsonStairs;
+
+    #[block]
+    struct CrimsonStem;
+
+    #[block]
+    struct CrimsonTrapdoor;
+
+    #[max_stack_size(1)]
+    struct Crossbow;
+
+    #[block]
+    struct CryingObsidian;
+
+    #[block]
+    struct CutCopper;
+
+    #[block]
+    struct CutCopperSlab;
+
+    #[block]
+    struct CutCopperStairs;
+
+    #[block]
+    struct CutRedSandstone;
+
+    #[block]
+    struct CutRedSandstoneSlab;
+
+    #[block]
+    struct CutSandstone;
+
+    #[block]
+    struct CutSandstoneSlab;
+
+    #[block]
+    #[wall_block(CyanWallBanner)]
+    #[max_stack_size(16)]
+    struct CyanBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct CyanBed;
+
+    #[block]
+    struct CyanCandle;
+
+    #[block]
+    struct CyanCarpet;
+
+    #[block]
+    struct CyanConcrete;
+
+    #[block]
+    struct CyanConcretePowder;
+
+    struct CyanDye;
+
+    #[block]
+    struct CyanGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct CyanShulkerBox;
+
+    #[block]
+    struct CyanStainedGlass;
+
+    #[block]
+    struct CyanStainedGlassPane;
+
+    #[block]
+    struct CyanTerracotta;
+
+    #[block]
+    struct CyanWool;
+
+    #[block]
+    struct DamagedAnvil;
+
+    #[block]
+    struct Dandelion;
+
+    #[max_stack_size(1)]
+    struct DarkOakBoat;
+
+    #[block]
+    struct DarkOakButton;
+
+    #[block]
+    struct DarkOakDoor;
+
+    #[block]
+    struct DarkOakFence;
+
+    #[block]
+    struct DarkOakFenceGate;
+
+    #[block]
+    struct DarkOakLeaves;
+
+    #[block]
+    struct DarkOakLog;
+
+    #[block]
+    struct DarkOakPlanks;
+
+    #[block]
+    struct DarkOakPressurePlate;
+
+    #[block]
+    struct DarkOakSapling;
+
+    #[block]
+    #[wall_block(DarkOakWallSign)]
+    #[max_stack_size(16)]
+    struct DarkOakSign;
+
+    #[block]
+    struct DarkOakSlab;
+
+    #[block]
+    struct DarkOakStairs;
+
+    #[block]
+    struct DarkOakTrapdoor;
+
+    #[block]
+    struct DarkOakWood;
+
+    #[block]
+    struct DarkPrismarine;
+
+    #[block]
+    struct DarkPrismarineSlab;
+
+    #[block]
+    struct DarkPrismarineStairs;
+
+    #[block]
+    struct DaylightDetector;
+
+    #[block]
+    struct DeadBrainCoral;
+
+    #[block]
+    struct DeadBrainCoralBlock;
+
+    #[block]
+    #[wall_block(DeadBrainCoralWallFan)]
+    struct DeadBrainCoralFan;
+
+    #[block]
+    struct DeadBubbleCoral;
+
+    #[block]
+    struct DeadBubbleCoralBlock;
+
+    #[block]
+    #[wall_block(DeadBubbleCoralWallFan)]
+    struct DeadBubbleCoralFan;
+
+    #[block]
+    struct DeadBush;
+
+    #[block]
+    struct DeadFireCoral;
+
+    #[block]
+    struct DeadFireCoralBlock;
+
+    #[block]
+    #[wall_block(DeadFireCoralWallFan)]
+    struct DeadFireCoralFan;
+
+    #[block]
+    struct DeadHornCoral;
+
+    #[block]
+    struct DeadHornCoralBlock;
+
+    #[block]
+    #[wall_block(DeadHornCoralWallFan)]
+    struct DeadHornCoralFan;
+
+    #[block]
+    struct DeadTubeCoral;
+
+    #[block]
+    struct DeadTubeCoralBlock;
+
+    #[block]
+    #[wall_block(DeadTubeCoralWallFan)]
+    struct DeadTubeCoralFan;
+
+    #[max_stack_size(1)]
+    #[rarity(Epic)]
+    struct DebugStick;
+
+    #[block]
+    struct Deepslate;
+
+    #[block]
+    struct DeepslateBrickSlab;
+
+    #[block]
+    struct DeepslateBrickStairs;
+
+    #[block]
+    struct DeepslateBrickWall;
+
+    #[block]
+    struct DeepslateBricks;
+
+    #[block]
+    struct DeepslateCoalOre;
+
+    #[block]
+    struct DeepslateCopperOre;
+
+    #[block]
+    struct DeepslateDiamondOre;
+
+    #[block]
+    struct DeepslateEmeraldOre;
+
+    #[block]
+    struct DeepslateGoldOre;
+
+    #[block]
+    struct DeepslateIronOre;
+
+    #[block]
+    struct DeepslateLapisOre;
+
+    #[block]
+    struct DeepslateRedstoneOre;
+
+    #[block]
+    struct DeepslateTileSlab;
+
+    #[block]
+    struct DeepslateTileStairs;
+
+    #[block]
+    struct DeepslateTileWall;
+
+    #[block]
+    struct DeepslateTiles;
+
+    #[block]
+    struct DetectorRail;
+
+    struct Diamond;
+
+    #[max_stack_size(1)]
+    #[tool(Axe(Tier::Diamond))]
+    struct DiamondAxe;
+
+    #[block]
+    struct DiamondBlock;
+
+    #[max_stack_size(1)]
+    struct DiamondBoots;
+
+    #[max_stack_size(1)]
+    struct DiamondChestplate;
+
+    #[max_stack_size(1)]
+    struct DiamondHelmet;
+
+    #[max_stack_size(1)]
+    #[tool(Hoe(Tier::Diamond))]
+    struct DiamondHoe;
+
+    #[max_stack_size(1)]
+    struct DiamondHorseArmor;
+
+    #[max_stack_size(1)]
+    struct DiamondLeggings;
+
+    #[block]
+    struct DiamondOre;
+
+    #[max_stack_size(1)]
+    #[tool(Pickaxe(Tier::Diamond))]
+    struct DiamondPickaxe;
+
+    #[max_stack_size(1)]
+    #[tool(Shovel(Tier::Diamond))]
+    struct DiamondShovel;
+
+    #[max_stack_size(1)]
+    #[tool(Sword(Tier::Diamond))]
+    struct DiamondSword;
+
+    #[block]
+    struct Diorite;
+
+    #[block]
+    struct DioriteSlab;
+
+    #[block]
+    struct DioriteStairs;
+
+    #[block]
+    struct DioriteWall;
+
+    #[block]
+    struct Dirt;
+
+    #[block]
+    struct DirtPath;
+
+    #[block]
+    struct Dispenser;
+
+    struct DolphinSpawnEgg;
+
+    struct DonkeySpawnEgg;
+
+    #[rarity(Uncommon)]
+    struct DragonBreath;
+
+    #[block]
+    #[rarity(Epic)]
+    struct DragonEgg;
+
+    #[block]
+    #[wall_block(DragonWallHead)]
+    #[rarity(Epic)]
+    struct DragonHead;
+
+    struct DriedKelp;
+
+    #[block]
+    struct DriedKelpBlock;
+
+    #[block]
+    struct DripstoneBlock;
+
+    #[block]
+    struct Dropper;
+
+    struct DrownedSpawnEgg;
+
+    #[max_stack_size(16)]
+    struct Egg;
+
+    struct ElderGuardianSpawnEgg;
+
+    #[max_stack_size(1)]
+    #[rarity(Uncommon)]
+    struct Elytra;
+
+    struct Emerald;
+
+    #[block]
+    struct EmeraldBlock;
+
+    #[block]
+    struct EmeraldOre;
+
+    #[max_stack_size(1)]
+    #[rarity(Uncommon)]
+    struct EnchantedBook;
+
+    #[rarity(Epic)]
+    struct EnchantedGoldenApple;
+
+    #[block]
+    struct EnchantingTable;
+
+    #[rarity(Rare)]
+    struct EndCrystal;
+
+    #[block]
+    struct EndPortalFrame;
+
+    #[block]
+    struct EndRod;
+
+    #[block]
+    struct EndStone;
+
+    #[block]
+    struct EndStoneBrickSlab;
+
+    #[block]
+    struct EndStoneBrickStairs;
+
+    #[block]
+    struct EndStoneBrickWall;
+
+    #[block]
+    struct EndStoneBricks;
+
+    #[block]
+    struct EnderChest;
+
+    struct EnderEye;
+
+    #[max_stack_size(16)]
+    struct EnderPearl;
+
+    struct EndermanSpawnEgg;
+
+    struct EndermiteSpawnEgg;
+
+    struct EvokerSpawnEgg;
+
+    #[rarity(Uncommon)]
+    struct ExperienceBottle;
+
+    #[block]
+    struct ExposedCopper;
+
+    #[block]
+    struct ExposedCutCopper;
+
+    #[block]
+    struct ExposedCutCopperSlab;
+
+    #[block]
+    struct ExposedCutCopperStairs;
+
+    #[block]
+    struct Farmland;
+
+    struct Feather;
+
+    struct FermentedSpiderEye;
+
+    #[block]
+    struct Fern;
+
+    struct FilledMap;
+
+    struct FireCharge;
+
+    #[block]
+    struct FireCoral;
+
+    #[block]
+    struct FireCoralBlock;
+
+    #[block]
+    #[wall_block(FireCoralWallFan)]
+    struct FireCoralFan;
+
+    struct FireworkRocket;
+
+    struct FireworkStar;
+
+    #[max_stack_size(1)]
+    struct FishingRod;
+
+    #[block]
+    struct FletchingTable;
+
+    struct Flint;
+
+    #[max_stack_size(1)]
+    struct FlintAndSteel;
+
+    #[max_stack_size(1)]
+    struct FlowerBannerPattern;
+
+    #[block]
+    struct FlowerPot;
+
+    #[block]
+    struct FloweringAzalea;
+
+    #[block]
+    struct FloweringAzaleaLeaves;
+
+    struct FoxSpawnEgg;
+
+    #[block]
+    struct Furnace;
+
+    #[max_stack_size(1)]
+    struct FurnaceMinecart;
+
+    struct GhastSpawnEgg;
+
+    struct GhastTear;
+
+    #[block]
+    struct GildedBlackstone;
+
+    #[block]
+    struct Glass;
+
+    struct GlassBottle;
+
+    #[block]
+    struct GlassPane;
+
+    struct GlisteringMelonSlice;
+
+    #[max_stack_size(1)]
+    struct GlobeBannerPattern;
+
+    #[block(CaveVines)]
+    struct GlowBerries;
+
+    struct GlowInkSac;
+
+    struct GlowItemFrame;
+
+    #[block]
+    struct GlowLichen;
+
+    struct GlowSquidSpawnEgg;
+
+    #[block]
+    struct Glowstone;
+
+    struct GlowstoneDust;
+
+    struct GoatSpawnEgg;
+
+    #[block]
+    struct GoldBlock;
+
+    struct GoldIngot;
+
+    struct GoldNugget;
+
+    #[block]
+    struct GoldOre;
+
+    #[rarity(Rare)]
+    struct GoldenApple;
+
+    #[max_stack_size(1)]
+    #[tool(Axe(Tier::Gold))]
+    struct GoldenAxe;
+
+    #[max_stack_size(1)]
+    struct GoldenBoots;
+
+    struct GoldenCarrot;
+
+    #[max_stack_size(1)]
+    struct GoldenChestplate;
+
+    #[max_stack_size(1)]
+    struct GoldenHelmet;
+
+    #[max_stack_size(1)]
+    #[tool(Hoe(Tier::Gold))]
+    struct GoldenHoe;
+
+    #[max_stack_size(1)]
+    struct GoldenHorseArmor;
+
+    #[max_stack_size(1)]
+    struct GoldenLeggings;
+
+    #[max_stack_size(1)]
+    #[tool(Pickaxe(Tier::Gold))]
+    struct GoldenPickaxe;
+
+    #[max_stack_size(1)]
+    #[tool(Shovel(Tier::Gold))]
+    struct GoldenShovel;
+
+    #[max_stack_size(1)]
+    #[tool(Sword(Tier::Gold))]
+    struct GoldenSword;
+
+    #[block]
+    struct Granite;
+
+    #[block]
+    struct GraniteSlab;
+
+    #[block]
+    struct GraniteStairs;
+
+    #[block]
+    struct GraniteWall;
+
+    #[block]
+    struct Grass;
+
+    #[block]
+    struct GrassBlock;
+
+    #[block]
+    struct Gravel;
+
+    #[block]
+    #[wall_block(GrayWallBanner)]
+    #[max_stack_size(16)]
+    struct GrayBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct GrayBed;
+
+    #[block]
+    struct GrayCandle;
+
+    #[block]
+    struct GrayCarpet;
+
+    #[block]
+    struct GrayConcrete;
+
+    #[block]
+    struct GrayConcretePowder;
+
+    struct GrayDye;
+
+    #[block]
+    struct GrayGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct GrayShulkerBox;
+
+    #[block]
+    struct GrayStainedGlass;
+
+    #[block]
+    struct GrayStainedGlassPane;
+
+    #[block]
+    struct GrayTerracotta;
+
+    #[block]
+    struct GrayWool;
+
+    #[block]
+    #[wall_block(GreenWallBanner)]
+    #[max_stack_size(16)]
+    struct GreenBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct GreenBed;
+
+    #[block]
+    struct GreenCandle;
+
+    #[block]
+    struct GreenCarpet;
+
+    #[block]
+    struct GreenConcrete;
+
+    #[block]
+    struct GreenConcretePowder;
+
+    struct GreenDye;
+
+    #[block]
+    struct GreenGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct GreenShulkerBox;
+
+    #[block]
+    struct GreenStainedGlass;
+
+    #[block]
+    struct GreenStainedGlassPane;
+
+    #[block]
+    struct GreenTerracotta;
+
+    #[block]
+    struct GreenWool;
+
+    #[block]
+    struct Grindstone;
+
+    struct GuardianSpawnEgg;
+
+    struct Gunpowder;
+
+    #[block]
+    struct HangingRoots;
+
+    #[block]
+    struct HayBlock;
+
+    #[rarity(Uncommon)]
+    struct HeartOfTheSea;
+
+    #[block]
+    struct HeavyWeightedPressurePlate;
+
+    struct HoglinSpawnEgg;
+
+    #[block]
+    struct HoneyBlock;
+
+    #[max_stack_size(16)]
+    struct HoneyBottle;
+
+    struct Honeycomb;
+
+    #[block]
+    struct HoneycombBlock;
+
+    #[block]
+    struct Hopper;
+
+    #[max_stack_size(1)]
+    struct HopperMinecart;
+
+    #[block]
+    struct HornCoral;
+
+    #[block]
+    struct HornCoralBlock;
+
+    #[block]
+    #[wall_block(HornCoralWallFan)]
+    struct HornCoralFan;
+
+    struct HorseSpawnEgg;
+
+    struct HuskSpawnEgg;
+
+    #[block]
+    struct Ice;
+
+    #[block]
+    struct InfestedChiseledStoneBricks;
+
+    #[block]
+    struct InfestedCobblestone;
+
+    #[block]
+    struct InfestedCrackedStoneBricks;
+
+    #[block]
+    struct InfestedDeepslate;
+
+    #[block]
+    struct InfestedMossyStoneBricks;
+
+    #[block]
+    struct InfestedStone;
+
+    #[block]
+    struct InfestedStoneBricks;
+
+    struct InkSac;
+
+    #[max_stack_size(1)]
+    #[tool(Axe(Tier::Iron))]
+    struct IronAxe;
+
+    #[block]
+    struct IronBars;
+
+    #[block]
+    struct IronBlock;
+
+    #[max_stack_size(1)]
+    struct IronBoots;
+
+    #[max_stack_size(1)]
+    struct IronChestplate;
+
+    #[block]
+    struct IronDoor;
+
+    #[max_stack_size(1)]
+    struct IronHelmet;
+
+    #[max_stack_size(1)]
+    #[tool(Hoe(Tier::Iron))]
+    struct IronHoe;
+
+    #[max_stack_size(1)]
+    struct IronHorseArmor;
+
+    struct IronIngot;
+
+    #[max_stack_size(1)]
+    struct IronLeggings;
+
+    struct IronNugget;
+
+    #[block]
+    struct IronOre;
+
+    #[max_stack_size(1)]
+    #[tool(Pickaxe(Tier::Iron))]
+    struct IronPickaxe;
+
+    #[max_stack_size(1)]
+    #[tool(Shovel(Tier::Iron))]
+    struct IronShovel;
+
+    #[max_stack_size(1)]
+    #[tool(Sword(Tier::Iron))]
+    struct IronSword;
+
+    #[block]
+    struct IronTrapdoor;
+
+    struct ItemFrame;
+
+    #[block]
+    struct JackOLantern;
+
+    #[block]
+    #[rarity(Epic)]
+    struct Jigsaw;
+
+    #[block]
+    struct Jukebox;
+
+    #[max_stack_size(1)]
+    struct JungleBoat;
+
+    #[block]
+    struct JungleButton;
+
+    #[block]
+    struct JungleDoor;
+
+    #[block]
+    struct JungleFence;
+
+    #[block]
+    struct JungleFenceGate;
+
+    #[block]
+    struct JungleLeaves;
+
+    #[block]
+    struct JungleLog;
+
+    #[block]
+    struct JunglePlanks;
+
+    #[block]
+    struct JunglePressurePlate;
+
+    #[block]
+    struct JungleSapling;
+
+    #[block]
+    #[wall_block(JungleWallSign)]
+    #[max_stack_size(16)]
+    struct JungleSign;
+
+    #[block]
+    struct JungleSlab;
+
+    #[block]
+    struct JungleStairs;
+
+    #[block]
+    struct JungleTrapdoor;
+
+    #[block]
+    struct JungleWood;
+
+    #[block]
+    struct Kelp;
+
+    #[max_stack_size(1)]
+    #[rarity(Epic)]
+    struct KnowledgeBook;
+
+    #[block]
+    struct Ladder;
+
+    #[block]
+    struct Lantern;
+
+    #[block]
+    struct LapisBlock;
+
+    struct LapisLazuli;
+
+    #[block]
+    struct LapisOre;
+
+    #[block]
+    struct LargeAmethystBud;
+
+    #[block]
+    struct LargeFern;
+
+    #[max_stack_size(1)]
+    struct LavaBucket;
+
+    struct Lead;
+
+    struct Leather;
+
+    #[max_stack_size(1)]
+    struct LeatherBoots;
+
+    #[max_stack_size(1)]
+    struct LeatherChestplate;
+
+    #[max_stack_size(1)]
+    struct LeatherHelmet;
+
+    #[max_stack_size(1)]
+    struct LeatherHorseArmor;
+
+    #[max_stack_size(1)]
+    struct LeatherLeggings;
+
+    #[block]
+    struct Lectern;
+
+    #[block]
+    struct Lever;
+
+    #[block]
+    #[rarity(Epic)]
+    struct Light;
+
+    #[block]
+    #[wall_block(LightBlueWallBanner)]
+    #[max_stack_size(16)]
+    struct LightBlueBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct LightBlueBed;
+
+    #[block]
+    struct LightBlueCandle;
+
+    #[block]
+    struct LightBlueCarpet;
+
+    #[block]
+    struct LightBlueConcrete;
+
+    #[block]
+    struct LightBlueConcretePowder;
+
+    struct LightBlueDye;
+
+    #[block]
+    struct LightBlueGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct LightBlueShulkerBox;
+
+    #[block]
+    struct LightBlueStainedGlass;
+
+    #[block]
+    struct LightBlueStainedGlassPane;
+
+    #[block]
+    struct LightBlueTerracotta;
+
+    #[block]
+    struct LightBlueWool;
+
+    #[block]
+    #[wall_block(LightGrayWallBanner)]
+    #[max_stack_size(16)]
+    struct LightGrayBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct LightGrayBed;
+
+    #[block]
+    struct LightGrayCandle;
+
+    #[block]
+    struct LightGrayCarpet;
+
+    #[block]
+    struct LightGrayConcrete;
+
+    #[block]
+    struct LightGrayConcretePowder;
+
+    struct LightGrayDye;
+
+    #[block]
+    struct LightGrayGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct LightGrayShulkerBox;
+
+    #[block]
+    struct LightGrayStainedGlass;
+
+    #[block]
+    struct LightGrayStainedGlassPane;
+
+    #[block]
+    struct LightGrayTerracotta;
+
+    #[block]
+    struct LightGrayWool;
+
+    #[block]
+    struct LightWeightedPressurePlate;
+
+    #[block]
+    struct LightningRod;
+
+    #[block]
+    struct Lilac;
+
+    #[block]
+    struct LilyOfTheValley;
+
+    #[block]
+    struct LilyPad;
+
+    #[block]
+    #[wall_block(LimeWallBanner)]
+    #[max_stack_size(16)]
+    struct LimeBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct LimeBed;
+
+    #[block]
+    struct LimeCandle;
+
+    #[block]
+    struct LimeCarpet;
+
+    #[block]
+    struct LimeConcrete;
+
+    #[block]
+    struct LimeConcretePowder;
+
+    struct LimeDye;
+
+    #[block]
+    struct LimeGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct LimeShulkerBox;
+
+    #[block]
+    struct LimeStainedGlass;
+
+    #[block]
+    struct LimeStainedGlassPane;
+
+    #[block]
+    struct LimeTerracotta;
+
+    #[block]
+    struct LimeWool;
+
+    #[max_stack_size(1)]
+    struct LingeringPotion;
+
+    struct LlamaSpawnEgg;
+
+    #[block]
+    struct Lodestone;
+
+    #[block]
+    struct Loom;
+
+    #[block]
+    #[wall_block(MagentaWallBanner)]
+    #[max_stack_size(16)]
+    struct MagentaBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct MagentaBed;
+
+    #[block]
+    struct MagentaCandle;
+
+    #[block]
+    struct MagentaCarpet;
+
+    #[block]
+    struct MagentaConcrete;
+
+    #[block]
+    struct MagentaConcretePowder;
+
+    struct MagentaDye;
+
+    #[block]
+    struct MagentaGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct MagentaShulkerBox;
+
+    #[block]
+    struct MagentaStainedGlass;
+
+    #[block]
+    struct MagentaStainedGlassPane;
+
+    #[block]
+    struct MagentaTerracotta;
+
+    #[block]
+    struct MagentaWool;
+
+    #[block]
+    struct MagmaBlock;
+
+    struct MagmaCream;
+
+    struct MagmaCubeSpawnEgg;
+
+    struct Map;
+
+    #[block]
+    struct MediumAmethystBud;
+
+    #[block]
+    struct Melon;
+
+    #[block(MelonStem)]
+    struct MelonSeeds;
+
+    struct MelonSlice;
+
+    #[max_stack_size(1)]
+    struct MilkBucket;
+
+    #[max_stack_size(1)]
+    struct Minecart;
+
+    #[max_stack_size(1)]
+    #[rarity(Epic)]
+    struct MojangBannerPattern;
+
+    struct MooshroomSpawnEgg;
+
+    #[block]
+    struct MossBlock;
+
+    #[block]
+    struct MossCarpet;
+
+    #[block]
+    struct MossyCobblestone;
+
+    #[block]
+    struct MossyCobblestoneSlab;
+
+    #[block]
+    struct MossyCobblestoneStairs;
+
+    #[block]
+    struct MossyCobblestoneWall;
+
+    #[block]
+    struct MossyStoneBrickSlab;
+
+    #[block]
+    struct MossyStoneBrickStairs;
+
+    #[block]
+    struct MossyStoneBrickWall;
+
+    #[block]
+    struct MossyStoneBricks;
+
+    struct MuleSpawnEgg;
+
+    #[block]
+    struct MushroomStem;
+
+    #[max_stack_size(1)]
+    struct MushroomStew;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDisc11;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDisc13;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscBlocks;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscCat;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscChirp;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscFar;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscMall;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscMellohi;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscOtherside;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscPigstep;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscStal;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscStrad;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscWait;
+
+    #[max_stack_size(1)]
+    #[rarity(Rare)]
+    struct MusicDiscWard;
+
+    struct Mutton;
+
+    #[block]
+    struct Mycelium;
+
+    struct NameTag;
+
+    struct NautilusShell;
+
+    struct NetherBrick;
+
+    #[block]
+    struct NetherBrickFence;
+
+    #[block]
+    struct NetherBrickSlab;
+
+    #[block]
+    struct NetherBrickStairs;
+
+    #[block]
+    struct NetherBrickWall;
+
+    #[block]
+    struct NetherBricks;
+
+    #[block]
+    struct NetherGoldOre;
+
+    #[block]
+    struct NetherQuartzOre;
+
+    #[block]
+    struct NetherSprouts;
+
+    #[rarity(Uncommon)]
+    struct NetherStar;
+
+    #[block]
+    struct NetherWart;
+
+    #[block]
+    struct NetherWartBlock;
+
+    #[max_stack_size(1)]
+    #[tool(Axe(Tier::Netherite))]
+    struct NetheriteAxe;
+
+    #[block]
+    struct NetheriteBlock;
+
+    #[max_stack_size(1)]
+    struct NetheriteBoots;
+
+    #[max_stack_size(1)]
+    struct NetheriteChestplate;
+
+    #[max_stack_size(1)]
+    struct NetheriteHelmet;
+
+    #[max_stack_size(1)]
+    #[tool(Hoe(Tier::Netherite))]
+    struct NetheriteHoe;
+
+    struct NetheriteIngot;
+
+    #[max_stack_size(1)]
+    struct NetheriteLeggings;
+
+    #[max_stack_size(1)]
+    #[tool(Pickaxe(Tier::Netherite))]
+    struct NetheritePickaxe;
+
+    struct NetheriteScrap;
+
+    #[max_stack_size(1)]
+    #[tool(Shovel(Tier::Netherite))]
+    struct NetheriteShovel;
+
+    #[max_stack_size(1)]
+    #[tool(Sword(Tier::Netherite))]
+    struct NetheriteSword;
+
+    #[block]
+    struct Netherrack;
+
+    #[block]
+    struct NoteBlock;
+
+    #[max_stack_size(1)]
+    struct OakBoat;
+
+    #[block]
+    struct OakButton;
+
+    #[block]
+    struct OakDoor;
+
+    #[block]
+    struct OakFence;
+
+    #[block]
+    struct OakFenceGate;
+
+    #[block]
+    struct OakLeaves;
+
+    #[block]
+    struct OakLog;
+
+    #[block]
+    struct OakPlanks;
+
+    #[block]
+    struct OakPressurePlate;
+
+    #[block]
+    struct OakSapling;
+
+    #[block]
+    #[wall_block(OakWallSign)]
+    #[max_stack_size(16)]
+    struct OakSign;
+
+    #[block]
+    struct OakSlab;
+
+    #[block]
+    struct OakStairs;
+
+    #[block]
+    struct OakTrapdoor;
+
+    #[block]
+    struct OakWood;
+
+    #[block]
+    struct Observer;
+
+    #[block]
+    struct Obsidian;
+
+    struct OcelotSpawnEgg;
+
+    #[block]
+    #[wall_block(OrangeWallBanner)]
+    #[max_stack_size(16)]
+    struct OrangeBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct OrangeBed;
+
+    #[block]
+    struct OrangeCandle;
+
+    #[block]
+    struct OrangeCarpet;
+
+    #[block]
+    struct OrangeConcrete;
+
+    #[block]
+    struct OrangeConcretePowder;
+
+    struct OrangeDye;
+
+    #[block]
+    struct OrangeGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct OrangeShulkerBox;
+
+    #[block]
+    struct OrangeStainedGlass;
+
+    #[block]
+    struct OrangeStainedGlassPane;
+
+    #[block]
+    struct OrangeTerracotta;
+
+    #[block]
+    struct OrangeTulip;
+
+    #[block]
+    struct OrangeWool;
+
+    #[block]
+    struct OxeyeDaisy;
+
+    #[block]
+    struct OxidizedCopper;
+
+    #[block]
+    struct OxidizedCutCopper;
+
+    #[block]
+    struct OxidizedCutCopperSlab;
+
+    #[block]
+    struct OxidizedCutCopperStairs;
+
+    #[block]
+    struct PackedIce;
+
+    struct Painting;
+
+    struct PandaSpawnEgg;
+
+    struct Paper;
+
+    struct ParrotSpawnEgg;
+
+    #[block]
+    struct Peony;
+
+    #[block]
+    struct PetrifiedOakSlab;
+
+    struct PhantomMembrane;
+
+    struct PhantomSpawnEgg;
+
+    struct PigSpawnEgg;
+
+    #[max_stack_size(1)]
+    struct PiglinBannerPattern;
+
+    struct PiglinBruteSpawnEgg;
+
+    struct PiglinSpawnEgg;
+
+    struct PillagerSpawnEgg;
+
+    #[block]
+    #[wall_block(PinkWallBanner)]
+    #[max_stack_size(16)]
+    struct PinkBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct PinkBed;
+
+    #[block]
+    struct PinkCandle;
+
+    #[block]
+    struct PinkCarpet;
+
+    #[block]
+    struct PinkConcrete;
+
+    #[block]
+    struct PinkConcretePowder;
+
+    struct PinkDye;
+
+    #[block]
+    struct PinkGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct PinkShulkerBox;
+
+    #[block]
+    struct PinkStainedGlass;
+
+    #[block]
+    struct PinkStainedGlassPane;
+
+    #[block]
+    struct PinkTerracotta;
+
+    #[block]
+    struct PinkTulip;
+
+    #[block]
+    struct PinkWool;
+
+    #[block]
+    struct Piston;
+
+    #[block]
+    #[wall_block(PlayerWallHead)]
+    #[rarity(Uncommon)]
+    struct PlayerHead;
+
+    #[block]
+    struct Podzol;
+
+    #[block]
+    struct PointedDripstone;
+
+    struct PoisonousPotato;
+
+    struct PolarBearSpawnEgg;
+
+    #[block]
+    struct PolishedAndesite;
+
+    #[block]
+    struct PolishedAndesiteSlab;
+
+    #[block]
+    struct PolishedAndesiteStairs;
+
+    #[block]
+    struct PolishedBasalt;
+
+    #[block]
+    struct PolishedBlackstone;
+
+    #[block]
+    struct PolishedBlackstoneBrickSlab;
+
+    #[block]
+    struct PolishedBlackstoneBrickStairs;
+
+    #[block]
+    struct PolishedBlackstoneBrickWall;
+
+    #[block]
+    struct PolishedBlackstoneBricks;
+
+    #[block]
+    struct PolishedBlackstoneButton;
+
+    #[block]
+    struct PolishedBlackstonePressurePlate;
+
+    #[block]
+    struct PolishedBlackstoneSlab;
+
+    #[block]
+    struct PolishedBlackstoneStairs;
+
+    #[block]
+    struct PolishedBlackstoneWall;
+
+    #[block]
+    struct PolishedDeepslate;
+
+    #[block]
+    struct PolishedDeepslateSlab;
+
+    #[block]
+    struct PolishedDeepslateStairs;
+
+    #[block]
+    struct PolishedDeepslateWall;
+
+    #[block]
+    struct PolishedDiorite;
+
+    #[block]
+    struct PolishedDioriteSlab;
+
+    #[block]
+    struct PolishedDioriteStairs;
+
+    #[block]
+    struct PolishedGranite;
+
+    #[block]
+    struct PolishedGraniteSlab;
+
+    #[block]
+    struct PolishedGraniteStairs;
+
+    struct PoppedChorusFruit;
+
+    #[block]
+    struct Poppy;
+
+    struct Porkchop;
+
+    #[block(Potatoes)]
+    struct Potato;
+
+    #[max_stack_size(1)]
+    struct Potion;
+
+    #[block(PowderSnow)]
+    #[max_stack_size(1)]
+    struct PowderSnowBucket;
+
+    #[block]
+    struct PoweredRail;
+
+    #[block]
+    struct Prismarine;
+
+    #[block]
+    struct PrismarineBrickSlab;
+
+    #[block]
+    struct PrismarineBrickStairs;
+
+    #[block]
+    struct PrismarineBricks;
+
+    struct PrismarineCrystals;
+
+    struct PrismarineShard;
+
+    #[block]
+    struct PrismarineSlab;
+
+    #[block]
+    struct PrismarineStairs;
+
+    #[block]
+    struct PrismarineWall;
+
+    struct Pufferfish;
+
+    #[max_stack_size(1)]
+    struct PufferfishBucket;
+
+    struct PufferfishSpawnEgg;
+
+    #[block]
+    struct Pumpkin;
+
+    struct PumpkinPie;
+
+    #[block(PumpkinStem)]
+    struct PumpkinSeeds;
+
+    #[block]
+    #[wall_block(PurpleWallBanner)]
+    #[max_stack_size(16)]
+    struct PurpleBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct PurpleBed;
+
+    #[block]
+    struct PurpleCandle;
+
+    #[block]
+    struct PurpleCarpet;
+
+    #[block]
+    struct PurpleConcrete;
+
+    #[block]
+    struct PurpleConcretePowder;
+
+    struct PurpleDye;
+
+    #[block]
+    struct PurpleGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct PurpleShulkerBox;
+
+    #[block]
+    struct PurpleStainedGlass;
+
+    #[block]
+    struct PurpleStainedGlassPane;
+
+    #[block]
+    struct PurpleTerracotta;
+
+    #[block]
+    struct PurpleWool;
+
+    #[block]
+    struct PurpurBlock;
+
+    #[block]
+    struct PurpurPillar;
+
+    #[block]
+    struct PurpurSlab;
+
+    #[block]
+    struct PurpurStairs;
+
+    struct Quartz;
+
+    #[block]
+    struct QuartzBlock;
+
+    #[block]
+    struct QuartzBricks;
+
+    #[block]
+    struct QuartzPillar;
+
+    #[block]
+    struct QuartzSlab;
+
+    #[block]
+    struct QuartzStairs;
+
+    struct Rabbit;
+
+    struct RabbitFoot;
+
+    struct RabbitHide;
+
+    struct RabbitSpawnEgg;
+
+    #[max_stack_size(1)]
+    struct RabbitStew;
+
+    #[block]
+    struct Rail;
+
+    struct RavagerSpawnEgg;
+
+    struct RawCopper;
+
+    #[block]
+    struct RawCopperBlock;
+
+    struct RawGold;
+
+    #[block]
+    struct RawGoldBlock;
+
+    struct RawIron;
+
+    #[block]
+    struct RawIronBlock;
+
+    #[block]
+    #[wall_block(RedWallBanner)]
+    #[max_stack_size(16)]
+    struct RedBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct RedBed;
+
+    #[block]
+    struct RedCandle;
+
+    #[block]
+    struct RedCarpet;
+
+    #[block]
+    struct RedConcrete;
+
+    #[block]
+    struct RedConcretePowder;
+
+    struct RedDye;
+
+    #[block]
+    struct RedGlazedTerracotta;
+
+    #[block]
+    struct RedMushroom;
+
+    #[block]
+    struct RedMushroomBlock;
+
+    #[block]
+    struct RedNetherBrickSlab;
+
+    #[block]
+    struct RedNetherBrickStairs;
+
+    #[block]
+    struct RedNetherBrickWall;
+
+    #[block]
+    struct RedNetherBricks;
+
+    #[block]
+    struct RedSand;
+
+    #[block]
+    struct RedSandstone;
+
+    #[block]
+    struct RedSandstoneSlab;
+
+    #[block]
+    struct RedSandstoneStairs;
+
+    #[block]
+    struct RedSandstoneWall;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct RedShulkerBox;
+
+    #[block]
+    struct RedStainedGlass;
+
+    #[block]
+    struct RedStainedGlassPane;
+
+    #[block]
+    struct RedTerracotta;
+
+    #[block]
+    struct RedTulip;
+
+    #[block]
+    struct RedWool;
+
+    #[block(RedstoneWire)]
+    struct Redstone;
+
+    #[block]
+    struct RedstoneBlock;
+
+    #[block]
+    struct RedstoneLamp;
+
+    #[block]
+    struct RedstoneOre;
+
+    #[block]
+    #[wall_block(RedstoneWallTorch)]
+    struct RedstoneTorch;
+
+    #[block]
+    struct Repeater;
+
+    #[block]
+    #[rarity(Epic)]
+    struct RepeatingCommandBlock;
+
+    #[block]
+    struct RespawnAnchor;
+
+    #[block]
+    struct RootedDirt;
+
+    #[block]
+    struct RoseBush;
+
+    struct RottenFlesh;
+
+    #[max_stack_size(1)]
+    struct Saddle;
+
+    struct Salmon;
+
+    #[max_stack_size(1)]
+    struct SalmonBucket;
+
+    struct SalmonSpawnEgg;
+
+    #[block]
+    struct Sand;
+
+    #[block]
+    struct Sandstone;
+
+    #[block]
+    struct SandstoneSlab;
+
+    #[block]
+    struct SandstoneStairs;
+
+    #[block]
+    struct SandstoneWall;
+
+    #[block]
+    struct Scaffolding;
+
+    #[block]
+    struct SculkSensor;
+
+    struct Scute;
+
+    #[block]
+    struct SeaLantern;
+
+    #[block]
+    struct SeaPickle;
+
+    #[block]
+    struct Seagrass;
+
+    #[max_stack_size(1)]
+    #[tool(Shears)]
+    struct Shears;
+
+    struct SheepSpawnEgg;
+
+    #[max_stack_size(1)]
+    struct Shield;
+
+    #[block]
+    struct Shroomlight;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct ShulkerBox;
+
+    struct ShulkerShell;
+
+    struct ShulkerSpawnEgg;
+
+    struct SilverfishSpawnEgg;
+
+    struct SkeletonHorseSpawnEgg;
+
+    #[block]
+    #[wall_block(SkeletonWallSkull)]
+    #[rarity(Uncommon)]
+    struct SkeletonSkull;
+
+    struct SkeletonSpawnEgg;
+
+    #[max_stack_size(1)]
+    #[rarity(Uncommon)]
+    struct SkullBannerPattern;
+
+    struct SlimeBall;
+
+    #[block]
+    struct SlimeBlock;
+
+    struct SlimeSpawnEgg;
+
+    #[block]
+    struct SmallAmethystBud;
+
+    #[block]
+    struct SmallDripleaf;
+
+    #[block]
+    struct SmithingTable;
+
+    #[block]
+    struct Smoker;
+
+    #[block]
+    struct SmoothBasalt;
+
+    #[block]
+    struct SmoothQuartz;
+
+    #[block]
+    struct SmoothQuartzSlab;
+
+    #[block]
+    struct SmoothQuartzStairs;
+
+    #[block]
+    struct SmoothRedSandstone;
+
+    #[block]
+    struct SmoothRedSandstoneSlab;
+
+    #[block]
+    struct SmoothRedSandstoneStairs;
+
+    #[block]
+    struct SmoothSandstone;
+
+    #[block]
+    struct SmoothSandstoneSlab;
+
+    #[block]
+    struct SmoothSandstoneStairs;
+
+    #[block]
+    struct SmoothStone;
+
+    #[block]
+    struct SmoothStoneSlab;
+
+    #[block]
+    struct Snow;
+
+    #[block]
+    struct SnowBlock;
+
+    #[max_stack_size(16)]
+    struct Snowball;
+
+    #[block]
+    struct SoulCampfire;
+
+    #[block]
+    struct SoulLantern;
+
+    #[block]
+    struct SoulSand;
+
+    #[block]
+    struct SoulSoil;
+
+    #[block]
+    #[wall_block(SoulWallTorch)]
+    struct SoulTorch;
+
+    #[block]
+    struct Spawner;
+
+    struct SpectralArrow;
+
+    struct SpiderEye;
+
+    struct SpiderSpawnEgg;
+
+    #[max_stack_size(1)]
+    struct SplashPotion;
+
+    #[block]
+    struct Sponge;
+
+    #[block]
+    struct SporeBlossom;
+
+    #[max_stack_size(1)]
+    struct SpruceBoat;
+
+    #[block]
+    struct SpruceButton;
+
+    #[block]
+    struct SpruceDoor;
+
+    #[block]
+    struct SpruceFence;
+
+    #[block]
+    struct SpruceFenceGate;
+
+    #[block]
+    struct SpruceLeaves;
+
+    #[block]
+    struct SpruceLog;
+
+    #[block]
+    struct SprucePlanks;
+
+    #[block]
+    struct SprucePressurePlate;
+
+    #[block]
+    struct SpruceSapling;
+
+    #[block]
+    #[wall_block(SpruceWallSign)]
+    #[max_stack_size(16)]
+    struct SpruceSign;
+
+    #[block]
+    struct SpruceSlab;
+
+    #[block]
+    struct SpruceStairs;
+
+    #[block]
+    struct SpruceTrapdoor;
+
+    #[block]
+    struct SpruceWood;
+
+    #[max_stack_size(1)]
+    struct Spyglass;
+
+    struct SquidSpawnEgg;
+
+    struct Stick;
+
+    #[block]
+    struct StickyPiston;
+
+    #[block]
+    struct Stone;
+
+    #[max_stack_size(1)]
+    #[tool(Axe(Tier::Stone))]
+    struct StoneAxe;
+
+    #[block]
+    struct StoneBrickSlab;
+
+    #[block]
+    struct StoneBrickStairs;
+
+    #[block]
+    struct StoneBrickWall;
+
+    #[block]
+    struct StoneBricks;
+
+    #[block]
+    struct StoneButton;
+
+    #[max_stack_size(1)]
+    #[tool(Hoe(Tier::Stone))]
+    struct StoneHoe;
+
+    #[max_stack_size(1)]
+    #[tool(Pickaxe(Tier::Stone))]
+    struct StonePickaxe;
+
+    #[block]
+    struct StonePressurePlate;
+
+    #[max_stack_size(1)]
+    #[tool(Shovel(Tier::Stone))]
+    struct StoneShovel;
+
+    #[block]
+    struct StoneSlab;
+
+    #[block]
+    struct StoneStairs;
+
+    #[max_stack_size(1)]
+    #[tool(Sword(Tier::Stone))]
+    struct StoneSword;
+
+    #[block]
+    struct Stonecutter;
+
+    struct StraySpawnEgg;
+
+    struct StriderSpawnEgg;
+
+    #[block(Tripwire)]
+    struct String;
+
+    #[block]
+    struct StrippedAcaciaLog;
+
+    #[block]
+    struct StrippedAcaciaWood;
+
+    #[block]
+    struct StrippedBirchLog;
+
+    #[block]
+    struct StrippedBirchWood;
+
+    #[block]
+    struct StrippedCrimsonHyphae;
+
+    #[block]
+    struct StrippedCrimsonStem;
+
+    #[block]
+    struct StrippedDarkOakLog;
+
+    #[block]
+    struct StrippedDarkOakWood;
+
+    #[block]
+    struct StrippedJungleLog;
+
+    #[block]
+    struct StrippedJungleWood;
+
+    #[block]
+    struct StrippedOakLog;
+
+    #[block]
+    struct StrippedOakWood;
+
+    #[block]
+    struct StrippedSpruceLog;
+
+    #[block]
+    struct StrippedSpruceWood;
+
+    #[block]
+    struct StrippedWarpedHyphae;
+
+    #[block]
+    struct StrippedWarpedStem;
+
+    #[block]
+    #[rarity(Epic)]
+    struct StructureBlock;
+
+    #[block]
+    struct StructureVoid;
+
+    struct Sugar;
+
+    #[block]
+    struct SugarCane;
+
+    #[block]
+    struct Sunflower;
+
+    #[max_stack_size(1)]
+    struct SuspiciousStew;
+
+    #[block(SweetBerryBush)]
+    struct SweetBerries;
+
+    #[block]
+    struct TallGrass;
+
+    #[block]
+    struct Target;
+
+    #[block]
+    struct Terracotta;
+
+    #[block]
+    struct TintedGlass;
+
+    struct TippedArrow;
+
+    #[block]
+    struct Tnt;
+
+    #[max_stack_size(1)]
+    struct TntMinecart;
+
+    #[block]
+    #[wall_block(WallTorch)]
+    struct Torch;
+
+    #[max_stack_size(1)]
+    #[rarity(Uncommon)]
+    struct TotemOfUndying;
+
+    struct TraderLlamaSpawnEgg;
+
+    #[block]
+    struct TrappedChest;
+
+    #[max_stack_size(1)]
+    #[rarity(Epic)]
+    struct Trident;
+
+    #[block]
+    struct TripwireHook;
+
+    struct TropicalFish;
+
+    #[max_stack_size(1)]
+    struct TropicalFishBucket;
+
+    struct TropicalFishSpawnEgg;
+
+    #[block]
+    struct TubeCoral;
+
+    #[block]
+    struct TubeCoralBlock;
+
+    #[block]
+    #[wall_block(TubeCoralWallFan)]
+    struct TubeCoralFan;
+
+    #[block]
+    struct Tuff;
+
+    #[block]
+    struct TurtleEgg;
+
+    #[max_stack_size(1)]
+    struct TurtleHelmet;
+
+    struct TurtleSpawnEgg;
+
+    #[block]
+    struct TwistingVines;
+
+    struct VexSpawnEgg;
+
+    struct VillagerSpawnEgg;
+
+    struct VindicatorSpawnEgg;
+
+    #[block]
+    struct Vine;
+
+    struct WanderingTraderSpawnEgg;
+
+    #[block]
+    struct WarpedButton;
+
+    #[block]
+    struct WarpedDoor;
+
+    #[block]
+    struct WarpedFence;
+
+    #[block]
+    struct WarpedFenceGate;
+
+    #[block]
+    struct WarpedFungus;
+
+    #[max_stack_size(1)]
+    struct WarpedFungusOnAStick;
+
+    #[block]
+    struct WarpedHyphae;
+
+    #[block]
+    struct WarpedNylium;
+
+    #[block]
+    struct WarpedPlanks;
+
+    #[block]
+    struct WarpedPressurePlate;
+
+    #[block]
+    struct WarpedRoots;
+
+    #[block]
+    #[wall_block(WarpedWallSign)]
+    #[max_stack_size(16)]
+    struct WarpedSign;
+
+    #[block]
+    struct WarpedSlab;
+
+    #[block]
+    struct WarpedStairs;
+
+    #[block]
+    struct WarpedStem;
+
+    #[block]
+    struct WarpedTrapdoor;
+
+    #[block]
+    struct WarpedWartBlock;
+
+    #[max_stack_size(1)]
+    struct WaterBucket;
+
+    #[block]
+    struct WaxedCopperBlock;
+
+    #[block]
+    struct WaxedCutCopper;
+
+    #[block]
+    struct WaxedCutCopperSlab;
+
+    #[block]
+    struct WaxedCutCopperStairs;
+
+    #[block]
+    struct WaxedExposedCopper;
+
+    #[block]
+    struct WaxedExposedCutCopper;
+
+    #[block]
+    struct WaxedExposedCutCopperSlab;
+
+    #[block]
+    struct WaxedExposedCutCopperStairs;
+
+    #[block]
+    struct WaxedOxidizedCopper;
+
+    #[block]
+    struct WaxedOxidizedCutCopper;
+
+    #[block]
+    struct WaxedOxidizedCutCopperSlab;
+
+    #[block]
+    struct WaxedOxidizedCutCopperStairs;
+
+    #[block]
+    struct WaxedWeatheredCopper;
+
+    #[block]
+    struct WaxedWeatheredCutCopper;
+
+    #[block]
+    struct WaxedWeatheredCutCopperSlab;
+
+    #[block]
+    struct WaxedWeatheredCutCopperStairs;
+
+    #[block]
+    struct WeatheredCopper;
+
+    #[block]
+    struct WeatheredCutCopper;
+
+    #[block]
+    struct WeatheredCutCopperSlab;
+
+    #[block]
+    struct WeatheredCutCopperStairs;
+
+    #[block]
+    struct WeepingVines;
+
+    #[block]
+    struct WetSponge;
+
+    struct Wheat;
+
+    #[block(Wheat)]
+    struct WheatSeeds;
+
+    #[block]
+    #[wall_block(WhiteWallBanner)]
+    #[max_stack_size(16)]
+    struct WhiteBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct WhiteBed;
+
+    #[block]
+    struct WhiteCandle;
+
+    #[block]
+    struct WhiteCarpet;
+
+    #[block]
+    struct WhiteConcrete;
+
+    #[block]
+    struct WhiteConcretePowder;
+
+    struct WhiteDye;
+
+    #[block]
+    struct WhiteGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct WhiteShulkerBox;
+
+    #[block]
+    struct WhiteStainedGlass;
+
+    #[block]
+    struct WhiteStainedGlassPane;
+
+    #[block]
+    struct WhiteTerracotta;
+
+    #[block]
+    struct WhiteTulip;
+
+    #[block]
+    struct WhiteWool;
+
+    struct WitchSpawnEgg;
+
+    #[block]
+    struct WitherRose;
+
+    #[block]
+    #[wall_block(WitherSkeletonWallSkull)]
+    #[rarity(Uncommon)]
+    struct WitherSkeletonSkull;
+
+    struct WitherSkeletonSpawnEgg;
+
+    struct WolfSpawnEgg;
+
+    #[max_stack_size(1)]
+    #[tool(Axe(Tier::Wood))]
+    struct WoodenAxe;
+
+    #[max_stack_size(1)]
+    #[tool(Hoe(Tier::Wood))]
+    struct WoodenHoe;
+
+    #[max_stack_size(1)]
+    #[tool(Pickaxe(Tier::Wood))]
+    struct WoodenPickaxe;
+
+    #[max_stack_size(1)]
+    #[tool(Shovel(Tier::Wood))]
+    struct WoodenShovel;
+
+    #[max_stack_size(1)]
+    #[tool(Sword(Tier::Wood))]
+    struct WoodenSword;
+
+    #[max_stack_size(1)]
+    struct WritableBook;
+
+    #[max_stack_size(16)]
+    struct WrittenBook;
+
+    #[block]
+    #[wall_block(YellowWallBanner)]
+    #[max_stack_size(16)]
+    struct YellowBanner;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct YellowBed;
+
+    #[block]
+    struct YellowCandle;
+
+    #[block]
+    struct YellowCarpet;
+
+    #[block]
+    struct YellowConcrete;
+
+    #[block]
+    struct YellowConcretePowder;
+
+    struct YellowDye;
+
+    #[block]
+    struct YellowGlazedTerracotta;
+
+    #[block]
+    #[max_stack_size(1)]
+    struct YellowShulkerBox;
+
+    #[block]
+    struct YellowStainedGlass;
+
+    #[block]
+    struct YellowStainedGlassPane;
+
+    #[block]
+    struct YellowTerracotta;
+
+    #[block]
+    struct YellowWool;
+
+    struct ZoglinSpawnEgg;
+
+    #[block]
+    #[wall_block(ZombieWallHead)]
+    #[rarity(Uncommon)]
+    struct ZombieHead;
+
+    struct ZombieHorseSpawnEgg;
+
+    struct ZombieSpawnEgg;
+
+    struct ZombieVillagerSpawnEgg;
+
+    struct ZombifiedPiglinSpawnEgg;
+}
@@ -0,0 +1,116 @@
+//! How blocks emit light and let it through.
+
+use crate::tag::vanilla;
+use crate::{state, Block, BlockId};
+
+impl Block {
+    /// Returns the light level emitted by the block, from 0 to 15.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn light_emission(self) -> u8 {
+        use BlockId::*;
+
+        let id = self.id();
+        let lit = self.get::<state::Lit>().is_some_and(|lit| lit.0);
+
+        match id {
+            Glowstone | JackOLantern | SeaLantern | Shroomlight | Beacon | Conduit | EndPortal
+            | EndGateway | Lava | LavaCauldron | Fire | Lantern => 15,
+            RedstoneLamp | Campfire if lit => 15,
+            Torch | WallTorch | EndRod => 14,
+            CaveVines | CaveVinesPlant
+                if self
+                    .get::<state::Berries>()
+                    .is_some_and(|berries| berries.0) =>
+            {
+                14
+            }
+            Furnace | Smoker | BlastFurnace if lit => 13,
+            NetherPortal => 11,
+            SoulTorch | SoulWallTorch | SoulLantern | SoulFire | CryingObsidian => 10,
+            SoulCampfire if lit => 10,
+            RedstoneOre | DeepslateRedstoneOre if lit => 9,
+            RedstoneTorch | RedstoneWallTorch if lit => 7,
+            EnchantingTable | EnderChest | GlowLichen => 7,
+            AmethystCluster => 5,
+            LargeAmethystBud => 4,
+            MagmaBlock => 3,
+            MediumAmethystBud => 2,
+            SmallAmethystBud | BrewingStand | BrownMushroom | DragonEgg | EndPortalFrame
+            | SculkSensor => 1,
+            // Dead sea pickles, out of water, don't glow.
+            SeaPickle
+                if self
+                    .get::<state::Waterlogged>()
+                    .is_some_and(|waterlogged| waterlogged.0) =>
+            {
+                self.get::<state::Pickles>()
+                    .map_or(0, |pickles| 3 + 3 * pickles.0.get() as u8)
+            }
+            RespawnAnchor => self
+                .get::<state::RespawnAnchorCharges>()
+                .map_or(0, |charges| (charges.0.get() * 15 / 4) as u8),
+            Light => self
+                .get::<state::Level>()
+                .map_or(0, |level| level.0.get() as u8),
+            _ if lit && id.is_in(vanilla("candles")) => self
+                .get::<state::Candles>()
+                .map_or(0, |candles| 3 * candles.0.get() as u8),
+            _ if lit && id.is_in(vanilla("candle_cakes")) => 3,
+            _ => 0,
+        }
+    }
+
+    /// Returns by how much the block dims the light passing through it, from 0 for blocks that
+    /// let it through untouched, like glass, to 15 for opaque blocks.
+    #[must_use]
+    pub fn light_block(self) -> u8 {
+        let id = self.id();
+
+        if id == BlockId::TintedGlass || self.is_solid_render() {
+            15
+        } else {
+            u8::from(!self.propagates_skylight_down())
+        }
+    }
+
+    /// Returns `true` if the block is an opaque full block, which hides its neighbors.
+    #[must_use]
+    pub fn is_solid_render(self) -> bool {
+        self.behavior().can_occlude && self.occlusion_shape().is_full()
+    }
+
+    /// Returns `true` if the faces of the block only stop light where its shape covers them, like
+    /// slabs and stairs. Light passes through the other blocks as long as they aren't opaque.
+    #[must_use]
+    pub fn uses_shape_for_light_occlusion(self) -> bool {
+        use BlockId::*;
+
+        let id = self.id();
+
+        match id {
+            DirtPath | Farmland | Snow | DaylightDetector | EnchantingTable | Stonecutter
+            | Lectern | EndPortalFrame | Composter | PistonHead | SculkSensor => true,
+            Piston | StickyPiston => self
+                .get::<state::Extended>()
+                .is_some_and(|extended| extended.0),
+            _ if id.is_in(vanilla("slabs")) => {
+                self.get::<state::SlabType>() != Some(state::SlabType::Double)
+            }
+            _ => id.is_in(vanilla("stairs")),
+        }
+    }
+
+    /// Returns `true` if sunlight passes down through the block without getting dimmer.
+    #[must_use]
+    pub fn propagates_skylight_down(self) -> bool {
+        let id = self.id();
+
+        match id {
+            BlockId::TintedGlass => false,
+            BlockId::Barrier => true,
+            _ if id.is_in(vanilla("impermeable")) => true,
+            _ => !self.outline_shape().is_full() && self.fluid_state().is_empty(),
+        }
+    }
+}
@@ -0,0 +1,796 @@
+//! Loot tables, which decide what blocks drop when broken. The vanilla 1.18.2 block loot tables
+//! are built in from `generated/data/minecraft/loot_tables/blocks/`, and data packs can add to or
+//! replace them through their `data/<namespace>/loot_tables/blocks/` directory.
+//!
+//! Parts of loot tables that depend on block entities, like `copy_nbt` or `dynamic` entries, and
+//! entries referencing item tags or other loot tables are parsed but don't generate anything.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use anyhow::{anyhow, Context as _, Result};
+use quartz_nbt::NbtCompound;
+use serde::{de, Deserialize, Deserializer};
+
+use crate::item::{ItemId, ItemStack, Tool};
+use crate::macros::loot_tables;
+use crate::random::Random;
+use crate::tag::{normalize, read_dir};
+use crate::{resource, Block, BlockId};
+
+impl Block {
+    /// Returns the items dropped by the block when broken by a player holding the given stack,
+    /// according to the vanilla loot tables. Blocks that require the correct tool for drops don't
+    /// drop anything otherwise.
+    pub fn drops(self, tool: Option<&ItemStack>, random: &mut Random) -> Vec<ItemStack> {
+        if !self.can_harvest(tool.map(|tool| tool.id)) {
+            return Vec::new();
+        }
+
+        let context = Context {
+            block: self,
+            tool,
+            explosion_radius: None,
+        };
+
+        Registry::vanilla()
+            .get_block(self.id())
+            .map(|table| table.roll(&context, random))
+            .unwrap_or_default()
+    }
+}
+
+/// The circumstances under which a loot table is rolled.
+#[derive(Clone, Copy, Debug)]
+pub struct Context<'a> {
+    /// The block that was broken.
+    pub block: Block,
+    /// The stack held by the player that broke the block, if any.
+    pub tool: Option<&'a ItemStack>,
+    /// The radius of the explosion that destroyed the block, if any.
+    pub explosion_radius: Option<f32>,
+}
+
+impl Context<'_> {
+    fn enchantment_level(&self, enchantment: &str) -> u8 {
+        let Ok(enchantment) = resource::Location::new(enchantment) else {
+            return 0;
+        };
+
+        self.tool
+            .map_or(0, |tool| tool.enchantment_level(enchantment))
+    }
+}
+
+/// A loot table, made of pools of entries each generating items.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Table {
+    #[serde(default)]
+    pools: Vec<Pool>,
+    #[serde(default)]
+    functions: Vec<Function>,
+}
+
+impl Table {
+    /// Parses a loot table from the contents of a loot table file.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Rolls the loot table, returning the generated stacks. Stacks larger than the maximum stack
+    /// size of their item are split.
+    pub fn roll(&self, context: &Context<'_>, random: &mut Random) -> Vec<ItemStack> {
+        let mut stacks = Vec::new();
+
+        for pool in &self.pools {
+            pool.roll(context, random, &mut |stack, random| {
+                let stack = apply_functions(&self.functions, stack, context, random);
+
+                if stack.is_empty() {
+                    return;
+                }
+
+                let max_stack_size = stack.id.max_stack_size();
+                let mut count = stack.count;
+
+                while count > max_stack_size {
+                    stacks.push(ItemStack {
+                        count: max_stack_size,
+                        ..stack.clone()
+                    });
+                    count -= max_stack_size;
+                }
+
+                stacks.push(ItemStack { count, ..stack });
+            });
+        }
+
+        stacks
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct Pool {
+    rolls: Number,
+    entries: Vec<Entry>,
+    #[serde(default)]
+    conditions: Vec<Condition>,
+    #[serde(default)]
+    functions: Vec<Function>,
+}
+
+impl Pool {
+    fn roll(
+        &self,
+        context: &Context<'_>,
+        random: &mut Random,
+        output: &mut dyn FnMut(ItemStack, &mut Random),
+    ) {
+        if !test_conditions(&self.conditions, context, random) {
+            return;
+        }
+
+        for _ in 0..self.rolls.int(random) {
+            let mut choices = Vec::new();
+
+            for entry in &self.entries {
+                entry.expand(context, random, &mut choices);
+            }
+
+            let total_weight = choices.iter().map(|choice| choice.weight()).sum::<i32>();
+
+            let choice = match choices.as_slice() {
+                [] => continue,
+                _ if total_weight == 0 => continue,
+                [choice] => choice,
+                _ => {
+                    let mut pick = random.next_i32_below(total_weight);
+
+                    choices
+                        .iter()
+                        .find(|choice| {
+                            pick -= choice.weight();
+                            pick < 0
+                        })
+                        .unwrap()
+                }
+            };
+
+            if let Some(stack) = choice.create(context, random) {
+                output(
+                    apply_functions(&self.functions, stack, context, random),
+                    random,
+                );
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    kind: EntryKind,
+    #[serde(default)]
+    conditions: Vec<Condition>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type")]
+enum EntryKind {
+    #[serde(rename = "minecraft:item", alias = "item")]
+    Item {
+        #[serde(deserialize_with = "location")]
+        name: ItemId,
+        #[serde(default = "weight_default")]
+        weight: i32,
+        #[serde(default)]
+        functions: Vec<Function>,
+    },
+    #[serde(rename = "minecraft:empty", alias = "empty")]
+    Empty {
+        #[serde(default = "weight_default")]
+        weight: i32,
+    },
+    #[serde(rename = "minecraft:alternatives", alias = "alternatives")]
+    Alternatives { children: Vec<Entry> },
+    #[serde(rename = "minecraft:group", alias = "group")]
+    Group { children: Vec<Entry> },
+    #[serde(rename = "minecraft:sequence", alias = "sequence")]
+    Sequence { children: Vec<Entry> },
+    /// Entries referencing item tags, other loot tables or block entity contents.
+    #[serde(other)]
+    Unsupported,
+}
+
+fn weight_default() -> i32 {
+    1
+}
+
+impl Entry {
+    fn weight(&self) -> i32 {
+        match self.kind {
+            EntryKind::Item { weight, .. } | EntryKind::Empty { weight } => weight.max(0),
+            _ => 0,
+        }
+    }
+
+    /// Adds the entries to choose from to `choices`, returning `false` if the conditions of the
+    /// entry don't pass.
+    fn expand<'a>(
+        &'a self,
+        context: &Context<'_>,
+        random: &mut Random,
+        choices: &mut Vec<&'a Entry>,
+    ) -> bool {
+        if !test_conditions(&self.conditions, context, random) {
+            return false;
+        }
+
+        match &self.kind {
+            EntryKind::Item { .. } | EntryKind::Empty { .. } => {
+                choices.push(self);
+                true
+            }
+            EntryKind::Alternatives { children } => children
+                .iter()
+                .any(|child| child.expand(context, random, choices)),
+            EntryKind::Group { children } => {
+                for child in children {
+                    child.expand(context, random, choices);
+                }
+
+                true
+            }
+            EntryKind::Sequence { children } => children
+                .iter()
+                .all(|child| child.expand(context, random, choices)),
+            EntryKind::Unsupported => false,
+        }
+    }
+
+    fn create(&self, context: &Context<'_>, random: &mut Random) -> Option<ItemStack> {
+        match &self.kind {
+            EntryKind::Item {
+                name, functions, ..
+            } => Some(apply_functions(
+                functions,
+                ItemStack::new(*name, 1),
+                context,
+                random,
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "condition")]
+enum Condition {
+    #[serde(rename = "minecraft:survives_explosion", alias = "survives_explosion")]
+    SurvivesExplosion,
+    #[serde(rename = "minecraft:match_tool", alias = "match_tool")]
+    MatchTool { predicate: ItemPredicate },
+    #[serde(
+        rename = "minecraft:block_state_property",
+        alias = "block_state_property"
+    )]
+    BlockStateProperty {
+        #[serde(deserialize_with = "location")]
+        block: BlockId,
+        #[serde(default)]
+        properties: HashMap<String, PropertyPredicate>,
+    },
+    #[serde(rename = "minecraft:random_chance", alias = "random_chance")]
+    RandomChance { chance: f32 },
+    #[serde(rename = "minecraft:table_bonus", alias = "table_bonus")]
+    TableBonus {
+        enchantment: String,
+        chances: Vec<f32>,
+    },
+    #[serde(rename = "minecraft:inverted", alias = "inverted")]
+    Inverted { term: Box<Condition> },
+    #[serde(rename = "minecraft:alternative", alias = "alternative")]
+    Alternative { terms: Vec<Condition> },
+}
+
+impl Condition {
+    fn test(&self, context: &Context<'_>, random: &mut Random) -> bool {
+        match self {
+            Condition::SurvivesExplosion => context
+                .explosion_radius
+                .is_none_or(|radius| random.next_f32() <= 1.0 / radius),
+            Condition::MatchTool { predicate } => {
+                context.tool.is_some_and(|tool| predicate.test(tool))
+            }
+            Condition::BlockStateProperty { block, properties } => {
+                context.block.id() == *block
+                    && properties.iter().all(|(name, predicate)| {
+                        context
+                            .block
+                            .properties()
+                            .find(|&(other, _)| other == name)
+                            .is_some_and(|(_, property)| predicate.test(&property.to_string()))
+                    })
+            }
+            Condition::RandomChance { chance } => random.next_f32() < *chance,
+            Condition::TableBonus {
+                enchantment,
+                chances,
+            } => {
+                let level = usize::from(context.enchantment_level(enchantment));
+                let chance = chances
+                    .get(level.min(chances.len().saturating_sub(1)))
+                    .copied()
+                    .unwrap_or(0.0);
+
+                random.next_f32() < chance
+            }
+            Condition::Inverted { term } => !term.test(context, random),
+            Condition::Alternative { terms } => terms.iter().any(|term| term.test(context, random)),
+        }
+    }
+}
+
+fn test_conditions(conditions: &[Condition], context: &Context<'_>, random: &mut Random) -> bool {
+    conditions
+        .iter()
+        .all(|condition| condition.test(context, random))
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct ItemPredicate {
+    #[serde(default, deserialize_with = "locations")]
+    items: Option<Vec<ItemId>>,
+    tag: Option<String>,
+    #[serde(default)]
+    enchantments: Vec<EnchantmentPredicate>,
+}
+
+impl ItemPredicate {
+    fn test(&self, stack: &ItemStack) -> bool {
+        if self
+            .items
+            .as_ref()
+            .is_some_and(|items| !items.contains(&stack.id))
+        {
+            return false;
+        }
+
+        if self
+            .tag
+            .as_ref()
+            .is_some_and(|tag| !item_tag_contains(tag, stack.id))
+        {
+            return false;
+        }
+
+        self.enchantments.iter().all(|predicate| {
+            let level = match &predicate.enchantment {
+                Some(enchantment) => resource::Location::new(enchantment)
+                    .map_or(0, |enchantment| stack.enchantment_level(enchantment)),
+                None if stack.is_enchanted() => 1,
+                None => 0,
+            };
+
+            level > 0 && predicate.levels.test(i32::from(level))
+        })
+    }
+}
+
+/// Returns `true` if the item is in the given item tag. Only the vanilla item tags used by the
+/// vanilla block loot tables are known.
+fn item_tag_contains(tag: &str, item: ItemId) -> bool {
+    match normalize(tag).as_deref() {
+        Ok("minecraft:cluster_max_harvestables") => matches!(item.tool(), Some(Tool::Pickaxe(_))),
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct EnchantmentPredicate {
+    enchantment: Option<String>,
+    #[serde(default)]
+    levels: IntRange,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum PropertyPredicate {
+    Exact(String),
+    Range { min: Option<i32>, max: Option<i32> },
+}
+
+impl PropertyPredicate {
+    fn test(&self, value: &str) -> bool {
+        match self {
+            PropertyPredicate::Exact(expected) => value == expected,
+            &PropertyPredicate::Range { min, max } => value
+                .parse::<i32>()
+                .is_ok_and(|value| IntRange::Range { min, max }.test(value)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(untagged)]
+enum IntRange {
+    Exact(i32),
+    Range { min: Option<i32>, max: Option<i32> },
+}
+
+impl Default for IntRange {
+    fn default() -> Self {
+        IntRange::Range {
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl IntRange {
+    fn test(self, value: i32) -> bool {
+        match self {
+            IntRange::Exact(expected) => value == expected,
+            IntRange::Range { min, max } => {
+                min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+            }
+        }
+    }
+
+    fn clamp(self, value: i32) -> i32 {
+        match self {
+            IntRange::Exact(expected) => expected,
+            IntRange::Range { min, max } => {
+                let value = min.map_or(value, |min| value.max(min));
+
+                max.map_or(value, |max| value.min(max))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct Function {
+    #[serde(flatten)]
+    kind: FunctionKind,
+    #[serde(default)]
+    conditions: Vec<Condition>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "function")]
+enum FunctionKind {
+    #[serde(rename = "minecraft:set_count", alias = "set_count")]
+    SetCount {
+        count: Number,
+        #[serde(default)]
+        add: bool,
+    },
+    #[serde(rename = "minecraft:apply_bonus", alias = "apply_bonus")]
+    ApplyBonus {
+        enchantment: String,
+        #[serde(flatten)]
+        formula: Formula,
+    },
+    #[serde(rename = "minecraft:explosion_decay", alias = "explosion_decay")]
+    ExplosionDecay,
+    #[serde(rename = "minecraft:limit_count", alias = "limit_count")]
+    LimitCount { limit: IntRange },
+    #[serde(rename = "minecraft:copy_state", alias = "copy_state")]
+    CopyState {
+        #[serde(deserialize_with = "location")]
+        block: BlockId,
+        properties: Vec<String>,
+    },
+    /// Functions depending on block entities, like `copy_name` or `set_contents`.
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(tag = "formula", content = "parameters")]
+enum Formula {
+    #[serde(rename = "minecraft:ore_drops", alias = "ore_drops")]
+    OreDrops,
+    #[serde(
+        rename = "minecraft:uniform_bonus_count",
+        alias = "uniform_bonus_count"
+    )]
+    UniformBonusCount {
+        #[serde(rename = "bonusMultiplier")]
+        bonus_multiplier: i32,
+    },
+    #[serde(
+        rename = "minecraft:binomial_with_bonus_count",
+        alias = "binomial_with_bonus_count"
+    )]
+    BinomialWithBonusCount { extra: i32, probability: f32 },
+}
+
+impl Function {
+    fn apply(&self, mut stack: ItemStack, context: &Context<'_>, random: &mut Random) -> ItemStack {
+        if !test_conditions(&self.conditions, context, random) {
+            return stack;
+        }
+
+        let count = i32::from(stack.count);
+        let count = match &self.kind {
+            FunctionKind::SetCount { count: n, add } => {
+                let n = n.int(random);
+
+                if *add {
+                    count + n
+                } else {
+                    n
+                }
+            }
+            FunctionKind::ApplyBonus {
+                enchantment,
+                formula,
+            } => {
+                let level = i32::from(context.enchantment_level(enchantment));
+
+                formula.apply(count, level, random)
+            }
+            FunctionKind::ExplosionDecay => match context.explosion_radius {
+                Some(radius) => (0..count)
+                    .filter(|_| random.next_f32() <= 1.0 / radius)
+                    .count()
+                    .try_into()
+                    .unwrap(),
+                None => count,
+            },
+            FunctionKind::LimitCount { limit } => limit.clamp(count),
+            FunctionKind::CopyState { block, properties } => {
+                if context.block.id() == *block {
+                    let nbt = stack.nbt.get_or_insert_with(NbtCompound::new);
+                    let mut state = nbt
+                        .get::<_, &NbtCompound>("BlockStateTag")
+                        .cloned()
+                        .unwrap_or_default();
+
+                    for (name, property) in context.block.properties() {
+                        if properties.iter().any(|other| other == name) {
+                            state.insert(name, property.to_string());
+                        }
+                    }
+
+                    nbt.insert("BlockStateTag", state);
+                }
+
+                count
+            }
+            FunctionKind::Unsupported => count,
+        };
+
+        stack.count = u8::try_from(count.clamp(0, 255)).unwrap();
+
+        stack
+    }
+}
+
+fn apply_functions(
+    functions: &[Function],
+    stack: ItemStack,
+    context: &Context<'_>,
+    random: &mut Random,
+) -> ItemStack {
+    functions.iter().fold(stack, |stack, function| {
+        function.apply(stack, context, random)
+    })
+}
+
+impl Formula {
+    fn apply(self, count: i32, level: i32, random: &mut Random) -> i32 {
+        match self {
+            Formula::OreDrops if level > 0 => {
+                let bonus = (random.next_i32_below(level + 2) - 1).max(0);
+
+                count * (bonus + 1)
+            }
+            Formula::OreDrops => count,
+            Formula::UniformBonusCount { bonus_multiplier } => {
+                count + random.next_i32_below(bonus_multiplier * level + 1)
+            }
+            Formula::BinomialWithBonusCount { extra, probability } => {
+                count + binomial(level + extra, probability, random)
+            }
+        }
+    }
+}
+
+/// Returns the number of successes out of `n` trials with probability `p`.
+fn binomial(n: i32, p: f32, random: &mut Random) -> i32 {
+    (0..n).fold(0, |count, _| count + i32::from(random.next_f32() < p))
+}
+
+/// A number generated at random, as used for counts and rolls.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Number {
+    Constant(f32),
+    Provider(NumberProvider),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type")]
+enum NumberProvider {
+    #[serde(rename = "minecraft:constant", alias = "constant")]
+    Constant { value: f32 },
+    #[serde(rename = "minecraft:uniform", alias = "uniform")]
+    Uniform { min: Box<Number>, max: Box<Number> },
+    #[serde(rename = "minecraft:binomial", alias = "binomial")]
+    Binomial { n: Box<Number>, p: Box<Number> },
+}
+
+impl Number {
+    #[allow(clippy::cast_possible_truncation)]
+    fn int(&self, random: &mut Random) -> i32 {
+        match self {
+            Number::Provider(NumberProvider::Uniform { min, max }) => {
+                let min = min.int(random);
+                let max = max.int(random);
+
+                random.next_i32_between(min, max)
+            }
+            Number::Provider(NumberProvider::Binomial { n, p }) => {
+                let n = n.int(random);
+                let p = p.float(random);
+
+                binomial(n, p, random)
+            }
+            // Like vanilla, other numbers are rounded to the nearest integer.
+            _ => (self.float(random) + 0.5).floor() as i32,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn float(&self, random: &mut Random) -> f32 {
+        match self {
+            Number::Constant(value) | Number::Provider(NumberProvider::Constant { value }) => {
+                *value
+            }
+            Number::Provider(NumberProvider::Uniform { min, max }) => {
+                let min = min.float(random);
+                let max = max.float(random);
+
+                if min >= max {
+                    min
+                } else {
+                    random.next_f32() * (max - min) + min
+                }
+            }
+            Number::Provider(NumberProvider::Binomial { .. }) => self.int(random) as f32,
+        }
+    }
+}
+
+fn location<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: for<'a> TryFrom<&'a resource::Location, Error = anyhow::Error>,
+{
+    let s = String::deserialize(deserializer)?;
+
+    resource::Location::new(&s)
+        .map_err(anyhow::Error::from)
+        .and_then(T::try_from)
+        .map_err(de::Error::custom)
+}
+
+fn locations<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: for<'a> TryFrom<&'a resource::Location, Error = anyhow::Error>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|s| {
+            resource::Location::new(s)
+                .map_err(anyhow::Error::from)
+                .and_then(T::try_from)
+                .map_err(de::Error::custom)
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+/// The names and contents of the vanilla block loot table files.
+const VANILLA_TABLES: &[(&str, &str)] = loot_tables!("generated/data/minecraft/loot_tables/blocks");
+
+/// A set of loot tables, keyed by their resource location.
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    tables: HashMap<String, Table>,
+}
+
+impl Registry {
+    /// Creates a new `Registry` without any loot tables.
+    #[must_use]
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Returns the registry of the built-in vanilla block loot tables.
+    #[must_use]
+    pub fn vanilla() -> &'static Registry {
+        static VANILLA: LazyLock<Registry> = LazyLock::new(|| {
+            let mut registry = Registry::new();
+
+            for (name, json) in VANILLA_TABLES {
+                registry
+                    .add(&format!("minecraft:blocks/{name}"), json)
+                    .expect("the vanilla loot tables should be valid");
+            }
+
+            registry
+        });
+
+        &VANILLA
+    }
+
+    /// Returns the loot table with the given resource location.
+    #[must_use]
+    pub fn get(&self, location: &str) -> Option<&Table> {
+        self.tables.get(&normalize(location).ok()?)
+    }
+
+    /// Returns the loot table of the given block.
+    #[must_use]
+    pub fn get_block(&self, id: BlockId) -> Option<&Table> {
+        self.tables
+            .get(&format!("minecraft:blocks/{}", id.to_location()))
+    }
+
+    /// Adds the loot table with the given resource location, parsed from the contents of a loot
+    /// table file. This replaces any loot table with the same resource location.
+    pub fn add(&mut self, location: &str, json: &str) -> Result<()> {
+        let location = normalize(location)?;
+        let table = Table::from_json(json)
+            .with_context(|| format!("failed to parse loot table `{location}`"))?;
+        self.tables.insert(location, table);
+
+        Ok(())
+    }
+
+    /// Adds the block loot tables of the unzipped data pack at the given path, that is all the
+    /// files matching `data/<namespace>/loot_tables/blocks/*.json`.
+    pub fn add_data_pack(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let data = path.as_ref().join("data");
+
+        for namespace in read_dir(&data)? {
+            let namespace = namespace?;
+            let dir = namespace.path().join("loot_tables").join("blocks");
+
+            if !dir.is_dir() {
+                continue;
+            }
+
+            let namespace = namespace.file_name();
+            let namespace = namespace
+                .to_str()
+                .ok_or_else(|| anyhow!("non-UTF-8 namespace in `{}`", data.display()))?;
+
+            for entry in read_dir(&dir)? {
+                let path = entry?.path();
+
+                if path.extension().is_none_or(|extension| extension != "json") {
+                    continue;
+                }
+
+                let name = path
+                    .file_stem()
+                    .and_then(|name| name.to_str())
+                    .ok_or_else(|| anyhow!("non-UTF-8 path `{}`", path.display()))?;
+                let json = fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read `{}`", path.display()))?;
+
+                self.add(&format!("{namespace}:blocks/{name}"), &json)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -3,63 +3,74 @@ use proc_macro::{self, TokenStream};
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{
-    parse_macro_input, Field, Fields, FieldsUnnamed, File, Ident, Item, ItemEnum, ItemStruct, Path,
-    Type, TypePath,
+    parse_macro_input, Expr, Fields, FieldsUnnamed, File, Ident, Item, ItemEnum, ItemStruct,
+    LitStr, Path, Type, TypePath,
 };
 
-/// Derives an enum `BlockId` whose variants are the same as the names of the blocks passed to this
-/// macro. Also derives `Block::id`.
+mod report;
+
+/// Derives an enum `BlockId` whose variants are the blocks listed in vanilla's `blocks.json`
+/// report at the given path, relative to the manifest directory of the crate, numbered in the
+/// order vanilla registers them. Also derives `Block::id`, as well as a typed view struct for
+/// each block in the `blocks` module.
+///
+/// The state properties of the blocks are mapped to the property types of the `state` module by
+/// their names and values, and each block gets its behavior from the constant of the same name in
+/// the `behavior` module. Updating to another version of the game means swapping the report, and
+/// adding the property types and behavior of any new blocks.
 ///
 /// # Panics
 ///
-/// Panics if the provided token stream consists of items other than structs.
+/// Panics if the report can't be read, or has a property that doesn't match any property type.
 #[allow(clippy::too_many_lines)]
 #[proc_macro]
 pub fn blocks(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as File);
-    let items = input.items.iter().map(|item| match item {
-        Item::Struct(item_struct) => item_struct,
-        _ => panic!("expected a struct"),
-    });
-    let block_count = input.items.len();
+    let path = parse_macro_input!(input as LitStr).value();
+    let blocks = report::read_blocks(&path);
+    let block_count = blocks.len();
     let max_id = u16::try_from(block_count - 1).unwrap();
-    let ident1 = items.clone().map(|ItemStruct { ident, .. }| ident);
+    let state_count = blocks.iter().map(|block| block.state_count).sum::<u32>();
+    let ident1 = blocks.iter().map(|block| &block.ident);
     let ident2 = ident1.clone();
     let screaming_ident = ident1
         .clone()
         .map(|ident| Ident::new(&ident.to_string().to_shouty_snake_case(), Span::call_site()));
-    let property_names = items.clone().map(|ItemStruct { fields, .. }| {
-        let property_name = fields.iter().map(property_name);
+    let property_names = blocks.iter().map(|block| {
+        let property_name = block.properties.iter().map(|property| &property.name);
 
         quote! { #(#property_name,)* }
     });
-    let property_definitions = items.clone().map(|ItemStruct { fields, .. }| {
-        let property_name = fields.iter().map(property_name);
-        let property_type1 = fields.iter().map(|field| &field.ty);
-        let property_type2 = property_type1.clone();
-        let offset = (0..fields.len()).map(|i| {
-            let property_type = property_type2.clone().take(i);
-
-            quote! { (16 #(+ <#property_type>::BITS)*) }
-        });
+    let property_definitions = blocks.iter().map(|block| {
+        let property_name = block.properties.iter().map(|property| &property.name);
+        let property_type = block.properties.iter().map(|property| &property.ty);
+        let offset = property_offsets(&block.properties);
 
         quote! {
             #(
                 state::PropertyDefinition {
                     name: #property_name,
-                    id: <#property_type1>::ID,
+                    id: <#property_type>::ID,
                     offset: #offset,
                 },
             )*
         }
     });
+    let default_states = blocks.iter().map(|block| {
+        let default = block.properties.iter().map(|property| &property.default);
+        let offset = property_offsets(&block.properties);
+
+        quote! { 0 #(| (#default.to_bits() << #offset))* }
+    });
+    let base_state_id = blocks.iter().map(|block| block.base_state_id);
+    let typed_blocks = blocks.iter().map(impl_typed_block);
     let numeric_id = 0..=max_id;
-    let string_id1 = ident1
-        .clone()
-        .map(|ident| ident.to_string().to_snake_case());
+    let string_id1 = blocks.iter().map(|block| &block.location);
     let string_id2 = string_id1.clone();
 
     quote! {
+        // Rebuild when the report changes.
+        const _: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #path));
+
         /// Block IDs. This enum is automatically derived by the `blocks!` proc macro.
         #[derive(Clone, Copy, Debug)]
         #[repr(u16)]
@@ -74,6 +85,13 @@ pub fn blocks(input: TokenStream) -> TokenStream {
             /// The maximum numeric ID.
             const MAX: u16 = #max_id;
 
+            /// The total number of block states.
+            const STATE_COUNT: u32 = #state_count;
+
+            /// The global state ID of the first state of each block, indexed by the numeric block
+            /// ID.
+            const BASE_STATE_IDS: [u32; BlockId::COUNT] = [#(#base_state_id,)*];
+
             /// Converts the given resource location to a `BlockId`.
             #[must_use]
             pub fn from_location(s: &str) -> Option<Self> {
@@ -110,7 +128,8 @@ pub fn blocks(input: TokenStream) -> TokenStream {
                 &LUT[self as usize]
             }
 
-            /// Returns the state definition of the block.
+            /// Returns the state definition of the block. The properties are sorted by name, which
+            /// is the order in which vanilla numbers the states of a block.
             #[must_use]
             pub const fn state_definition(self) -> &'static state::Definition {
                 const LUT: [state::Definition; BlockId::COUNT] = [
@@ -124,23 +143,272 @@ pub fn blocks(input: TokenStream) -> TokenStream {
 
                 &LUT[self as usize]
             }
+
+            /// Returns the block state bits of the vanilla default state of the block, excluding
+            /// the block ID.
+            const fn default_state(self) -> u32 {
+                const LUT: [u32; BlockId::COUNT] = [
+                    #(#default_states,)*
+                ];
+
+                LUT[self as usize]
+            }
+        }
+
+        /// Typed views of blocks, exposing the block state properties as fields. These structs
+        /// are automatically derived by the `blocks!` proc macro.
+        pub mod blocks {
+            use super::{state, Block, BlockId};
+
+            #(#typed_blocks)*
+        }
+    }
+    .into()
+}
+
+/// Expands to a slice of the names and contents of the JSON files in the given directory,
+/// relative to the manifest directory of the crate, sorted by name. The names don't include the
+/// `.json` extension, and the contents are embedded with `include_str!`.
+///
+/// # Panics
+///
+/// Panics if the directory can't be read, or has a file that isn't a JSON file with a UTF-8 name.
+#[proc_macro]
+pub fn loot_tables(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr).value();
+    let manifest_dir =
+        std::env::var("CARGO_MANIFEST_DIR").expect("expected to be compiled by Cargo");
+    let dir = std::path::Path::new(&manifest_dir).join(&path);
+    let mut names = std::fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("failed to read `{}`: {err}", dir.display()))
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().and_then(|name| name.to_str());
+            let is_json = path
+                .extension()
+                .is_some_and(|extension| extension == "json");
+
+            match name {
+                Some(name) if is_json => name.to_owned(),
+                _ => panic!("unexpected file `{}`", path.display()),
+            }
+        })
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    let file = names.iter().map(|name| format!("{path}/{name}.json"));
+
+    quote! {
+        &[#((#names, include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #file))),)*]
+    }
+    .into()
+}
+
+fn impl_typed_block(block: &report::Block) -> TokenStream2 {
+    let ident = &block.ident;
+    let field_ident1 = block
+        .properties
+        .iter()
+        .map(report::Property::field_ident)
+        .collect::<Vec<_>>();
+    let field_ident2 = field_ident1.iter();
+    let field_ident3 = field_ident1.iter();
+    let field_type1 = block.properties.iter().map(|property| &property.ty);
+    let field_type2 = field_type1.clone();
+    let offset1 = property_offsets(&block.properties);
+    let offset2 = offset1.clone();
+
+    quote! {
+        #[doc = concat!("Typed view of [`BlockId::", stringify!(#ident), "`].")]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct #ident {
+            #(pub #field_ident1: #field_type1,)*
+        }
+
+        impl #ident {
+            /// The ID of the block.
+            pub const ID: BlockId = BlockId::#ident;
+        }
+
+        impl TryFrom<Block> for #ident {
+            type Error = ::anyhow::Error;
+
+            #[allow(unused_variables)]
+            fn try_from(block: Block) -> ::anyhow::Result<Self> {
+                if block.id() != Self::ID {
+                    ::anyhow::bail!(
+                        "expected block `{}`, found `{}`",
+                        Self::ID.to_location(),
+                        block.id().to_location(),
+                    );
+                }
+
+                Ok(#ident {
+                    #(#field_ident2: block.property_at::<#field_type2>(#offset1),)*
+                })
+            }
+        }
+
+        impl From<#ident> for Block {
+            #[allow(unused_mut, unused_variables)]
+            fn from(typed: #ident) -> Self {
+                let mut block = Block::from_id(#ident::ID);
+                #(block.set_property(typed.#field_ident3.into(), #offset2);)*
+
+                block
+            }
+        }
+    }
+}
+
+/// Returns the expressions computing the offsets within the block state at which each of the
+/// properties is stored.
+fn property_offsets(properties: &[report::Property]) -> Vec<TokenStream2> {
+    let property_type = properties.iter().map(|property| &property.ty);
+
+    (0..properties.len())
+        .map(|i| {
+            let property_type = property_type.clone().take(i);
+
+            quote! { (16 #(+ <#property_type>::BITS)*) }
+        })
+        .collect()
+}
+
+/// Derives an enum `ItemId` whose variants are the same as the names of the items passed to this
+/// macro, along with the lookup tables backing its properties.
+///
+/// Items default to a maximum stack size of 64 and a common rarity, which can be overridden with
+/// `#[max_stack_size(...)]` and `#[rarity(...)]`. Items that place a block are annotated with
+/// `#[block]` if the block has the same name, or `#[block(...)]` otherwise, and additionally with
+/// `#[wall_block(...)]` if they place another block when used on the side of a block. Tools are
+/// annotated with `#[tool(...)]`, giving the `Tool` variant.
+///
+/// # Panics
+///
+/// Panics if the provided token stream consists of items other than unit structs.
+#[proc_macro]
+pub fn items(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as File);
+    let items = input.items.iter().map(|item| match item {
+        Item::Struct(item_struct) if matches!(item_struct.fields, Fields::Unit) => item_struct,
+        _ => panic!("expected a unit struct"),
+    });
+    let item_count = input.items.len();
+    let max_id = u16::try_from(item_count - 1).unwrap();
+    let ident1 = items.clone().map(|ItemStruct { ident, .. }| ident);
+    let ident2 = ident1.clone();
+    let properties = items.clone().map(|ItemStruct { ident, attrs, .. }| {
+        let attr_args = |name: &str| {
+            attrs
+                .iter()
+                .find(|attr| attr.path().is_ident(name))
+                .map(|attr| attr.parse_args::<Expr>().ok())
+        };
+        let max_stack_size = match attr_args("max_stack_size") {
+            Some(Some(size)) => quote! { #size },
+            Some(None) => panic!("expected a stack size"),
+            None => quote! { 64 },
+        };
+        let rarity = match attr_args("rarity") {
+            Some(Some(rarity)) => quote! { Rarity::#rarity },
+            Some(None) => panic!("expected a rarity"),
+            None => quote! { Rarity::Common },
+        };
+        let block = match attr_args("block") {
+            Some(Some(block)) => quote! { Some(BlockId::#block) },
+            Some(None) => quote! { Some(BlockId::#ident) },
+            None => quote! { None },
+        };
+        let wall_block = match attr_args("wall_block") {
+            Some(Some(block)) => quote! { Some(BlockId::#block) },
+            Some(None) => panic!("expected a block"),
+            None => quote! { None },
+        };
+        let tool = match attr_args("tool") {
+            Some(Some(tool)) => quote! { Some(Tool::#tool) },
+            Some(None) => panic!("expected a tool"),
+            None => quote! { None },
+        };
+
+        quote! {
+            Properties {
+                max_stack_size: #max_stack_size,
+                rarity: #rarity,
+                block: #block,
+                wall_block: #wall_block,
+                tool: #tool,
+            }
+        }
+    });
+    let numeric_id = 0..=max_id;
+    let string_id1 = ident1.clone().map(item_location);
+    let string_id2 = string_id1.clone();
+
+    quote! {
+        /// Item IDs. This enum is automatically derived by the `items!` proc macro.
+        #[derive(Clone, Copy, Debug)]
+        #[repr(u16)]
+        pub enum ItemId {
+            #(#ident1 = #numeric_id,)*
+        }
+
+        impl ItemId {
+            /// The number of item IDs.
+            const COUNT: usize = #item_count;
+
+            /// The maximum numeric ID.
+            const MAX: u16 = #max_id;
+
+            /// Converts the given resource location to an `ItemId`.
+            #[must_use]
+            pub fn from_location(s: &str) -> Option<Self> {
+                static LUT: ::phf::Map<&'static str, ItemId> = ::phf::phf_map! {
+                    #(#string_id1 => ItemId::#ident2,)*
+                };
+
+                LUT.get(s).copied()
+            }
+
+            /// Converts the `ItemId` to its resource location representation.
+            #[must_use]
+            pub const fn to_location(self) -> &'static str {
+                const LUT: [&'static str; ItemId::COUNT] = [
+                    #(#string_id2,)*
+                ];
+
+                LUT[self as usize]
+            }
+
+            /// Returns the properties of the item.
+            const fn properties(self) -> &'static Properties {
+                const LUT: [Properties; ItemId::COUNT] = [
+                    #(#properties,)*
+                ];
+
+                &LUT[self as usize]
+            }
         }
     }
     .into()
 }
 
-fn property_name(field: &Field) -> String {
-    let name = field
-        .ident
-        .as_ref()
-        .expect("expected a named field")
-        .to_string();
-
-    if &name == "ty" {
-        "type".to_owned()
-    } else {
-        name
+/// Returns the resource location of the item. Unlike for blocks, some item names contain numbers,
+/// like `music_disc_13`, which are separated from the preceding word by an underscore.
+fn item_location(ident: &Ident) -> String {
+    let snake_case = ident.to_string().to_snake_case();
+    let mut location = String::with_capacity(snake_case.len() + 1);
+    let mut previous = '_';
+
+    for c in snake_case.chars() {
+        if c.is_ascii_digit() && previous.is_ascii_alphabetic() {
+            location.push('_');
+        }
+
+        location.push(c);
+        previous = c;
     }
+
+    location
 }
 
 #[allow(clippy::too_many_lines)]
@@ -196,6 +464,9 @@ pub fn properties(input: TokenStream) -> TokenStream {
     let ident7 = ident1.clone();
     let ident8 = ident1.clone();
     let ident9 = ident1.clone();
+    let ident10 = ident1.clone();
+    let ident11 = ident1.clone();
+    let value_count_ident = ident1.clone();
 
     quote! {
         #types
@@ -226,7 +497,7 @@ pub fn properties(input: TokenStream) -> TokenStream {
             }
 
             #[must_use]
-            pub fn to_bits(self) -> u32 {
+            pub const fn to_bits(self) -> u32 {
                 match self {
                     #(Self::#ident4(property) => property.to_bits(),)*
                 }
@@ -240,6 +511,14 @@ pub fn properties(input: TokenStream) -> TokenStream {
             }
         }
 
+        impl ::std::fmt::Display for Property {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    #(Self::#ident11(property) => property.fmt(f),)*
+                }
+            }
+        }
+
         /// Block state property IDs. This enum is automatically derived by the `properties!` proc
         /// macro.
         #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -255,6 +534,14 @@ pub fn properties(input: TokenStream) -> TokenStream {
                 }
             }
 
+            /// Returns the number of values this property can take.
+            #[must_use]
+            pub const fn value_count(self) -> u32 {
+                match self {
+                    #(Self::#value_count_ident => #value_count_ident::MAX - #value_count_ident::MIN + 1,)*
+                }
+            }
+
             /// Returns the number of bits needed to represent this property.
             #[must_use]
             pub const fn bits(self) -> u32 {
@@ -264,6 +551,26 @@ pub fn properties(input: TokenStream) -> TokenStream {
             }
         }
 
+        #(
+            impl PropertyValue for #ident10 {
+                const ID: PropertyId = PropertyId::#ident10;
+
+                fn from_bits(bits: u32) -> Option<Self> {
+                    Self::from_bits(bits)
+                }
+
+                fn to_bits(self) -> u32 {
+                    Self::to_bits(self)
+                }
+            }
+
+            impl From<#ident10> for Property {
+                fn from(property: #ident10) -> Self {
+                    Property::#ident10(property)
+                }
+            }
+        )*
+
         impl<'de> ::serde::de::DeserializeSeed<'de> for PropertyId {
             type Value = Property;
 
@@ -308,7 +615,7 @@ fn impl_boolean_property(ident: &Ident) -> TokenStream2 {
             }
 
             #[must_use]
-            pub fn to_bits(self) -> u32 {
+            pub const fn to_bits(self) -> u32 {
                 match self {
                     Self(false) => 0,
                     Self(true) => 1,
@@ -365,6 +672,12 @@ fn impl_boolean_property(ident: &Ident) -> TokenStream2 {
                 s.parse().map(Self).map_err(Into::into)
             }
         }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
     }
 }
 
@@ -388,10 +701,10 @@ fn impl_integer_property(ident: &Ident, path: &Path) -> TokenStream2 {
             }
 
             #[must_use]
-            pub fn to_bits(self) -> u32 {
+            pub const fn to_bits(self) -> u32 {
                 // The value is stored in the range [0, MAX - MIN] instead of
                 // [MIN, MAX] so that a block whose state is initialized to all
-                // zeros is valid and the default state.
+                // zeros is valid.
                 self.0.0 - <#path>::MIN
             }
         }
@@ -454,6 +767,12 @@ fn impl_integer_property(ident: &Ident, path: &Path) -> TokenStream2 {
                 s.parse().map(Self).map_err(Into::into)
             }
         }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                self.0.0.fmt(f)
+            }
+        }
     }
 }
 
@@ -466,11 +785,14 @@ fn impl_enum_property(item: &ItemEnum) -> TokenStream2 {
     let variant2 = variant1.clone();
     let variant3 = variant1.clone();
     let variant4 = variant1.clone();
+    let variant5 = variant1.clone();
     let string_variant1 = variant1
         .clone()
         .map(|ident| ident.to_string().to_snake_case());
     let string_variant2 = string_variant1.clone();
     let string_variant3 = string_variant1.clone();
+    let string_variant4 = string_variant1.clone();
+    let string_variant5 = string_variant1.clone();
 
     quote! {
         impl #ident {
@@ -493,7 +815,7 @@ fn impl_enum_property(item: &ItemEnum) -> TokenStream2 {
             }
 
             #[must_use]
-            pub fn to_bits(self) -> u32 {
+            pub const fn to_bits(self) -> u32 {
                 match self {
                     #(Self::#variant2 => #numeric_variant2,)*
                 }
@@ -537,9 +859,23 @@ fn impl_enum_property(item: &ItemEnum) -> TokenStream2 {
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 match s {
                     #(#string_variant3 => Ok(#ident::#variant4),)*
-                    _ => Err(::anyhow::Error::msg("Matching variant not found")),
+                    _ => Err(::anyhow::anyhow!(
+                        "invalid value `{}`, expected one of: {}",
+                        s,
+                        [#(#string_variant4,)*].join(", "),
+                    )),
                 }
             }
         }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let s = match self {
+                    #(#ident::#variant5 => #string_variant5,)*
+                };
+
+                f.write_str(s)
+            }
+        }
     }
 }
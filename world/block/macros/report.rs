@@ -0,0 +1,328 @@
+//! Reading vanilla's `blocks.json` report, which lists every block with its state properties and
+//! states, as generated by the game's data generator.
+
+use std::fs;
+use std::path::Path;
+
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use serde_json::{Map, Value};
+use syn::Ident;
+
+/// A block listed in the report.
+pub(crate) struct Block {
+    /// The path of the block's resource location, without the `minecraft` namespace.
+    pub(crate) location: String,
+    pub(crate) ident: Ident,
+    /// The state properties of the block, sorted by name like vanilla sorts them.
+    pub(crate) properties: Vec<Property>,
+    /// The global state ID of the block's first state.
+    pub(crate) base_state_id: u32,
+    pub(crate) state_count: u32,
+}
+
+/// A state property of a block listed in the report.
+pub(crate) struct Property {
+    pub(crate) name: String,
+    /// The property type from the `state` module.
+    pub(crate) ty: TokenStream2,
+    /// The expression constructing the value of the property in the block's default state.
+    pub(crate) default: TokenStream2,
+}
+
+impl Property {
+    /// Returns the name of the field holding the property in the typed view of the block.
+    pub(crate) fn field_ident(&self) -> Ident {
+        if self.name == "type" {
+            Ident::new("ty", Span::call_site())
+        } else {
+            Ident::new(&self.name, Span::call_site())
+        }
+    }
+}
+
+/// The enum property types from the `state` module, with their values in the order vanilla lists
+/// them. The order of the variants of each type must match.
+const ENUM_TYPES: &[(&str, &[&str])] = &[
+    ("AttachFace", &["floor", "wall", "ceiling"]),
+    ("Axis", &["x", "y", "z"]),
+    ("BambooLeaves", &["none", "small", "large"]),
+    ("BedPart", &["head", "foot"]),
+    (
+        "BellAttachType",
+        &["floor", "ceiling", "single_wall", "double_wall"],
+    ),
+    ("ChestType", &["single", "left", "right"]),
+    ("ComparatorMode", &["compare", "subtract"]),
+    ("DoorHingeSide", &["left", "right"]),
+    ("DoubleBlockHalf", &["upper", "lower"]),
+    (
+        "DripstoneThickness",
+        &["tip_merge", "tip", "frustum", "middle", "base"],
+    ),
+    ("Facing", &["north", "east", "south", "west", "up", "down"]),
+    (
+        "FrontAndTop",
+        &[
+            "down_east",
+            "down_north",
+            "down_south",
+            "down_west",
+            "up_east",
+            "up_north",
+            "up_south",
+            "up_west",
+            "west_up",
+            "east_up",
+            "north_up",
+            "south_up",
+        ],
+    ),
+    ("Half", &["top", "bottom"]),
+    ("HorizontalAxis", &["x", "z"]),
+    ("HopperFacing", &["down", "north", "south", "west", "east"]),
+    ("HorizontalFacing", &["north", "east", "south", "west"]),
+    (
+        "NoteBlockInstrument",
+        &[
+            "harp",
+            "basedrum",
+            "snare",
+            "hat",
+            "bass",
+            "flute",
+            "bell",
+            "guitar",
+            "chime",
+            "xylophone",
+            "iron_xylophone",
+            "cow_bell",
+            "didgeridoo",
+            "bit",
+            "banjo",
+            "pling",
+        ],
+    ),
+    ("PistonType", &["normal", "sticky"]),
+    (
+        "RailShape",
+        &[
+            "north_south",
+            "east_west",
+            "ascending_east",
+            "ascending_west",
+            "ascending_north",
+            "ascending_south",
+            "south_east",
+            "south_west",
+            "north_west",
+            "north_east",
+        ],
+    ),
+    (
+        "StraightRailShape",
+        &[
+            "north_south",
+            "east_west",
+            "ascending_east",
+            "ascending_west",
+            "ascending_north",
+            "ascending_south",
+        ],
+    ),
+    ("RedstoneSide", &["up", "side", "none"]),
+    ("SculkSensorPhase", &["inactive", "active", "cooldown"]),
+    ("SlabType", &["top", "bottom", "double"]),
+    (
+        "StairsShape",
+        &[
+            "straight",
+            "inner_left",
+            "inner_right",
+            "outer_left",
+            "outer_right",
+        ],
+    ),
+    ("StructureMode", &["save", "load", "corner", "data"]),
+    ("Tilt", &["none", "unstable", "partial", "full"]),
+    ("VerticalDirection", &["up", "down"]),
+    ("WallSide", &["none", "low", "tall"]),
+];
+
+/// Reads the blocks listed in the report at the given path, relative to the manifest directory of
+/// the crate being compiled, in the order of their global state IDs, which is the order vanilla
+/// registers them.
+///
+/// # Panics
+///
+/// Panics if the report can't be read, is malformed, or has a property that doesn't match any
+/// property type.
+pub(crate) fn read_blocks(path: &str) -> Vec<Block> {
+    let manifest_dir =
+        std::env::var("CARGO_MANIFEST_DIR").expect("expected to be compiled by Cargo");
+    let path = Path::new(&manifest_dir).join(path);
+    let report = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read `{}`: {err}", path.display()));
+    let report: Map<String, Value> = serde_json::from_str(&report)
+        .unwrap_or_else(|err| panic!("failed to parse `{}`: {err}", path.display()));
+
+    let mut blocks = report
+        .iter()
+        .map(|(location, block)| read_block(location, block))
+        .collect::<Vec<_>>();
+    blocks.sort_by_key(|block| block.base_state_id);
+
+    let mut next_state_id = 0;
+
+    for block in &blocks {
+        assert!(
+            block.base_state_id == next_state_id,
+            "expected the states of `{}` to start at ID {next_state_id}",
+            block.location,
+        );
+        next_state_id += block.state_count;
+    }
+
+    blocks
+}
+
+fn read_block(location: &str, block: &Value) -> Block {
+    let location = location
+        .strip_prefix("minecraft:")
+        .unwrap_or_else(|| panic!("expected `{location}` to be in the `minecraft` namespace"));
+    let states = block["states"]
+        .as_array()
+        .unwrap_or_else(|| panic!("expected `{location}` to list its states"));
+    let default_state = states
+        .iter()
+        .find(|state| state["default"].as_bool() == Some(true))
+        .unwrap_or_else(|| panic!("expected `{location}` to have a default state"));
+    let state_ids = states
+        .iter()
+        .map(|state| {
+            state["id"]
+                .as_u64()
+                .and_then(|id| u32::try_from(id).ok())
+                .unwrap_or_else(|| panic!("expected the states of `{location}` to have IDs"))
+        })
+        .collect::<Vec<_>>();
+    let base_state_id = state_ids.iter().copied().min().unwrap_or(0);
+    let state_count = u32::try_from(states.len()).unwrap();
+
+    assert!(
+        state_ids
+            .iter()
+            .zip(base_state_id..)
+            .all(|(&id, expected)| id == expected),
+        "expected the state IDs of `{location}` to be consecutive",
+    );
+
+    let mut properties = Vec::new();
+    let mut value_product = 1;
+
+    if let Some(definitions) = block.get("properties").and_then(Value::as_object) {
+        for (name, values) in definitions {
+            let values = values
+                .as_array()
+                .and_then(|values| values.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+                .unwrap_or_else(|| panic!("expected `{location}.{name}` to list its values"));
+            let default = default_state["properties"][name]
+                .as_str()
+                .unwrap_or_else(|| panic!("expected `{location}.{name}` to have a default"));
+
+            properties.push(read_property(name, &values, default));
+            value_product *= u32::try_from(values.len()).unwrap();
+        }
+    }
+
+    assert!(
+        value_product == state_count,
+        "expected `{location}` to have a state for each combination of property values",
+    );
+
+    Block {
+        location: location.to_owned(),
+        ident: Ident::new(&location.to_upper_camel_case(), Span::call_site()),
+        properties,
+        base_state_id,
+        state_count,
+    }
+}
+
+/// Returns the property with the given name and values, mapping it to the property type of the
+/// `state` module that has these values.
+fn read_property(name: &str, values: &[&str], default: &str) -> Property {
+    let (ty, default) = if values == ["true", "false"] {
+        let ty = type_ident(&name.to_upper_camel_case());
+        let value = default == "true";
+
+        (ty.clone(), quote! { state::#ty(#value) })
+    } else if let Some(range) = integer_range(values) {
+        let ty = type_ident(&integer_type(name, range));
+        let value = default.parse::<u32>().unwrap();
+
+        (
+            ty.clone(),
+            quote! {
+                state::#ty(match state::Integer::new(#value) {
+                    Ok(n) => n,
+                    Err(_) => panic!("default value is out of range"),
+                })
+            },
+        )
+    } else {
+        let &(ty, _) = ENUM_TYPES
+            .iter()
+            .find(|(_, enum_values)| *enum_values == values)
+            .unwrap_or_else(|| {
+                panic!("no property type has the values {values:?} of property `{name}`")
+            });
+        let ty = type_ident(ty);
+        let variant = type_ident(&default.to_upper_camel_case());
+
+        (ty.clone(), quote! { state::#ty::#variant })
+    };
+
+    Property {
+        name: name.to_owned(),
+        ty: quote! { state::#ty },
+        default,
+    }
+}
+
+/// Returns the name of the integer property type with the given name and range of values. Most
+/// are named after the property, but some names are shared by properties with different ranges.
+fn integer_type(name: &str, (min, max): (u32, u32)) -> String {
+    match (name, min, max) {
+        ("age", 0, _) => format!("Age{max}"),
+        ("distance", 0, 7) => "StabilityDistance".to_owned(),
+        ("level", 0, 15) => "Level".to_owned(),
+        ("level", 1, 3) => "LevelCauldron".to_owned(),
+        ("level", 0, 8) => "LevelComposter".to_owned(),
+        ("level", 1, 8) => "LevelFlowing".to_owned(),
+        ("honey_level", 0, 5) => "LevelHoney".to_owned(),
+        ("charges", 0, 4) => "RespawnAnchorCharges".to_owned(),
+        ("rotation", 0, 15) => "Rotation16".to_owned(),
+        _ => name.to_upper_camel_case(),
+    }
+}
+
+/// Returns the smallest and largest value if the values are consecutive integers.
+fn integer_range(values: &[&str]) -> Option<(u32, u32)> {
+    let values = values
+        .iter()
+        .map(|value| value.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let (&min, &max) = (values.first()?, values.last()?);
+
+    values
+        .iter()
+        .zip(min..)
+        .all(|(&value, expected)| value == expected)
+        .then_some((min, max))
+}
+
+fn type_ident(name: &str) -> Ident {
+    Ident::new(name, Span::call_site())
+}
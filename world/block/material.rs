@@ -1,6 +1,19 @@
+//! Materials and map colors of blocks, which mirror vanilla's `Material` and `MaterialColor`.
+
+use enum_map::Enum;
+use strum::EnumIter;
+
+use crate::{Block, BlockId};
+
+/// The material of a block, which groups blocks with similar physical behavior. Every field
+/// mirrors the vanilla field of the same name.
+///
+/// Materials are only equal if they are the same vanilla material, not whenever their fields are,
+/// like those of [`Material::AIR`] and [`Material::STRUCTURAL_AIR`].
 #[allow(clippy::struct_excessive_bools)] // This is, in fact, not a state machine.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Material {
+    kind: Kind,
     pub color: Color,
     pub blocks_motion: bool,
     pub solid_blocking: bool,
@@ -12,43 +25,43 @@ pub struct Material {
 }
 
 impl Material {
-    pub(crate) const AIR: Self = Self::new(Color::None)
+    pub const AIR: Self = Self::new(Kind::Air, Color::None)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .replaceable();
 
-    pub(crate) const STRUCTURAL_AIR: Self = Self::new(Color::None)
+    pub const STRUCTURAL_AIR: Self = Self::new(Kind::StructuralAir, Color::None)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .replaceable();
 
-    pub(crate) const PORTAL: Self = Self::new(Color::None)
+    pub const PORTAL: Self = Self::new(Kind::Portal, Color::None)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .not_pushable();
 
-    pub(crate) const CLOTH_DECORATION: Self = Self::new(Color::Wool)
+    pub const CLOTH_DECORATION: Self = Self::new(Kind::ClothDecoration, Color::Wool)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .flammable();
 
-    pub(crate) const PLANT: Self = Self::new(Color::Plant)
+    pub const PLANT: Self = Self::new(Kind::Plant, Color::Plant)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .destroy_on_push();
 
-    pub(crate) const WATER_PLANT: Self = Self::new(Color::Water)
+    pub const WATER_PLANT: Self = Self::new(Kind::WaterPlant, Color::Water)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .destroy_on_push();
 
-    pub(crate) const REPLACEABLE_PLANT: Self = Self::new(Color::Plant)
+    pub const REPLACEABLE_PLANT: Self = Self::new(Kind::ReplaceablePlant, Color::Plant)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
@@ -56,21 +69,22 @@ impl Material {
         .replaceable()
         .flammable();
 
-    pub(crate) const REPLACEABLE_FIREPROOF_PLANT: Self = Self::new(Color::Plant)
-        .no_collider()
-        .non_solid_blocking()
-        .non_solid()
-        .destroy_on_push()
-        .replaceable();
+    pub const REPLACEABLE_FIREPROOF_PLANT: Self =
+        Self::new(Kind::ReplaceableFireproofPlant, Color::Plant)
+            .no_collider()
+            .non_solid_blocking()
+            .non_solid()
+            .destroy_on_push()
+            .replaceable();
 
-    pub(crate) const REPLACEABLE_WATER_PLANT: Self = Self::new(Color::Water)
+    pub const REPLACEABLE_WATER_PLANT: Self = Self::new(Kind::ReplaceableWaterPlant, Color::Water)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .destroy_on_push()
         .replaceable();
 
-    pub(crate) const WATER: Self = Self::new(Color::Water)
+    pub const WATER: Self = Self::new(Kind::Water, Color::Water)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
@@ -78,7 +92,7 @@ impl Material {
         .replaceable()
         .liquid();
 
-    pub(crate) const BUBBLE_COLUMN: Self = Self::new(Color::Water)
+    pub const BUBBLE_COLUMN: Self = Self::new(Kind::BubbleColumn, Color::Water)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
@@ -86,7 +100,7 @@ impl Material {
         .replaceable()
         .liquid();
 
-    pub(crate) const LAVA: Self = Self::new(Color::Fire)
+    pub const LAVA: Self = Self::new(Kind::Lava, Color::Fire)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
@@ -94,103 +108,116 @@ impl Material {
         .replaceable()
         .liquid();
 
-    pub(crate) const TOP_SNOW: Self = Self::new(Color::Snow)
+    pub const TOP_SNOW: Self = Self::new(Kind::TopSnow, Color::Snow)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .destroy_on_push()
         .replaceable();
 
-    pub(crate) const FIRE: Self = Self::new(Color::None)
+    pub const FIRE: Self = Self::new(Kind::Fire, Color::None)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .destroy_on_push()
         .replaceable();
 
-    pub(crate) const DECORATION: Self = Self::new(Color::None)
+    pub const DECORATION: Self = Self::new(Kind::Decoration, Color::None)
         .no_collider()
         .non_solid_blocking()
         .non_solid()
         .destroy_on_push();
 
-    pub(crate) const WEB: Self = Self::new(Color::Wool)
+    pub const WEB: Self = Self::new(Kind::Web, Color::Wool)
         .no_collider()
         .non_solid_blocking()
         .destroy_on_push();
 
-    pub(crate) const SCULK: Self = Self::new(Color::ColorBlack);
+    pub const SCULK: Self = Self::new(Kind::Sculk, Color::ColorBlack);
 
-    pub(crate) const BUILDABLE_GLASS: Self = Self::new(Color::None);
+    pub const BUILDABLE_GLASS: Self = Self::new(Kind::BuildableGlass, Color::None);
 
-    pub(crate) const CLAY: Self = Self::new(Color::Clay);
+    pub const CLAY: Self = Self::new(Kind::Clay, Color::Clay);
 
-    pub(crate) const DIRT: Self = Self::new(Color::Dirt);
+    pub const DIRT: Self = Self::new(Kind::Dirt, Color::Dirt);
 
-    pub(crate) const GRASS: Self = Self::new(Color::Grass);
+    pub const GRASS: Self = Self::new(Kind::Grass, Color::Grass);
 
-    pub(crate) const ICE_SOLID: Self = Self::new(Color::Ice);
+    pub const ICE_SOLID: Self = Self::new(Kind::IceSolid, Color::Ice);
 
-    pub(crate) const SAND: Self = Self::new(Color::Sand);
+    pub const SAND: Self = Self::new(Kind::Sand, Color::Sand);
 
-    pub(crate) const SPONGE: Self = Self::new(Color::ColorYellow);
+    pub const SPONGE: Self = Self::new(Kind::Sponge, Color::ColorYellow);
 
-    pub(crate) const SHULKER_SHELL: Self = Self::new(Color::ColorPurple);
+    pub const SHULKER_SHELL: Self = Self::new(Kind::ShulkerShell, Color::ColorPurple);
 
-    pub(crate) const WOOD: Self = Self::new(Color::Wood).flammable();
+    pub const WOOD: Self = Self::new(Kind::Wood, Color::Wood).flammable();
 
-    pub(crate) const NETHER_WOOD: Self = Self::new(Color::Wood);
+    pub const NETHER_WOOD: Self = Self::new(Kind::NetherWood, Color::Wood);
 
-    pub(crate) const BAMBOO_SAPLING: Self = Self::new(Color::Wood)
+    pub const BAMBOO_SAPLING: Self = Self::new(Kind::BambooSapling, Color::Wood)
         .no_collider()
         .flammable()
         .destroy_on_push();
 
-    pub(crate) const BAMBOO: Self = Self::new(Color::Wood).flammable().destroy_on_push();
+    pub const BAMBOO: Self = Self::new(Kind::Bamboo, Color::Wood)
+        .flammable()
+        .destroy_on_push();
 
-    pub(crate) const WOOL: Self = Self::new(Color::Wool).flammable();
+    pub const WOOL: Self = Self::new(Kind::Wool, Color::Wool).flammable();
 
-    pub(crate) const EXPLOSIVE: Self = Self::new(Color::Fire).non_solid_blocking().flammable();
+    pub const EXPLOSIVE: Self = Self::new(Kind::Explosive, Color::Fire)
+        .non_solid_blocking()
+        .flammable();
 
-    pub(crate) const LEAVES: Self = Self::new(Color::Plant)
+    pub const LEAVES: Self = Self::new(Kind::Leaves, Color::Plant)
         .non_solid_blocking()
         .flammable()
         .destroy_on_push();
 
-    pub(crate) const GLASS: Self = Self::new(Color::None).non_solid_blocking();
+    pub const GLASS: Self = Self::new(Kind::Glass, Color::None).non_solid_blocking();
 
-    pub(crate) const ICE: Self = Self::new(Color::Ice).non_solid_blocking();
+    pub const ICE: Self = Self::new(Kind::Ice, Color::Ice).non_solid_blocking();
 
-    pub(crate) const CACTUS: Self = Self::new(Color::Plant)
+    pub const CACTUS: Self = Self::new(Kind::Cactus, Color::Plant)
         .non_solid_blocking()
         .destroy_on_push();
 
-    pub(crate) const STONE: Self = Self::new(Color::Stone);
+    pub const STONE: Self = Self::new(Kind::Stone, Color::Stone);
 
-    pub(crate) const METAL: Self = Self::new(Color::Metal);
+    pub const METAL: Self = Self::new(Kind::Metal, Color::Metal);
 
-    pub(crate) const SNOW: Self = Self::new(Color::Snow);
+    pub const SNOW: Self = Self::new(Kind::Snow, Color::Snow);
 
-    pub(crate) const HEAVY_METAL: Self = Self::new(Color::Metal).not_pushable();
+    pub const HEAVY_METAL: Self = Self::new(Kind::HeavyMetal, Color::Metal).not_pushable();
 
-    pub(crate) const BARRIER: Self = Self::new(Color::None).not_pushable();
+    pub const BARRIER: Self = Self::new(Kind::Barrier, Color::None).not_pushable();
 
-    pub(crate) const PISTON: Self = Self::new(Color::Stone).not_pushable();
+    pub const PISTON: Self = Self::new(Kind::Piston, Color::Stone).not_pushable();
 
-    pub(crate) const MOSS: Self = Self::new(Color::Plant).destroy_on_push();
+    pub const MOSS: Self = Self::new(Kind::Moss, Color::Plant).destroy_on_push();
 
-    pub(crate) const VEGETABLE: Self = Self::new(Color::Plant).destroy_on_push();
+    pub const VEGETABLE: Self = Self::new(Kind::Vegetable, Color::Plant).destroy_on_push();
 
-    pub(crate) const EGG: Self = Self::new(Color::Plant).destroy_on_push();
+    pub const EGG: Self = Self::new(Kind::Egg, Color::Plant).destroy_on_push();
 
-    pub(crate) const CAKE: Self = Self::new(Color::None).destroy_on_push();
+    pub const CAKE: Self = Self::new(Kind::Cake, Color::None).destroy_on_push();
 
-    pub(crate) const AMETHYST: Self = Self::new(Color::ColorPurple);
+    pub const AMETHYST: Self = Self::new(Kind::Amethyst, Color::ColorPurple);
 
-    pub(crate) const POWDER_SNOW: Self = Self::new(Color::Snow).no_collider().non_solid();
+    pub const POWDER_SNOW: Self = Self::new(Kind::PowderSnow, Color::Snow)
+        .no_collider()
+        .non_solid();
+
+    /// Returns which vanilla material this is.
+    #[must_use]
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
 
-    const fn new(color: Color) -> Self {
+    const fn new(kind: Kind, color: Color) -> Self {
         Material {
+            kind,
             color,
             push_reaction: PushReaction::Normal,
             blocks_motion: true,
@@ -251,11 +278,72 @@ impl Material {
     }
 }
 
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl Eq for Material {}
+
+/// The vanilla materials, which tell them apart where their fields are the same. Each is named
+/// like the constant of [`Material`] it identifies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Air,
+    StructuralAir,
+    Portal,
+    ClothDecoration,
+    Plant,
+    WaterPlant,
+    ReplaceablePlant,
+    ReplaceableFireproofPlant,
+    ReplaceableWaterPlant,
+    Water,
+    BubbleColumn,
+    Lava,
+    TopSnow,
+    Fire,
+    Decoration,
+    Web,
+    Sculk,
+    BuildableGlass,
+    Clay,
+    Dirt,
+    Grass,
+    IceSolid,
+    Sand,
+    Sponge,
+    ShulkerShell,
+    Wood,
+    NetherWood,
+    BambooSapling,
+    Bamboo,
+    Wool,
+    Explosive,
+    Leaves,
+    Glass,
+    Ice,
+    Cactus,
+    Stone,
+    Metal,
+    Snow,
+    HeavyMetal,
+    Barrier,
+    Piston,
+    Moss,
+    Vegetable,
+    Egg,
+    Cake,
+    Amethyst,
+    PowderSnow,
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Colors used for blocks displayed in a map.
+/// Colors used for blocks displayed in a map, in the order of their vanilla IDs.
 #[allow(clippy::enum_variant_names)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Enum, EnumIter, PartialEq, Eq, Hash)]
 pub enum Color {
     None,
     Grass,
@@ -322,10 +410,44 @@ pub enum Color {
 }
 
 impl Color {
+    /// Returns the vanilla ID of the color.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the ID of the color at the given brightness, which is what maps store for each
+    /// pixel, like vanilla's `MaterialColor::getPackedId`.
+    #[must_use]
+    pub const fn packed_id(self, brightness: Brightness) -> u8 {
+        self.id() * 4 + brightness as u8
+    }
+
+    /// Returns the RGB value of the color at the given brightness as `0xRRGGBB`, like vanilla's
+    /// `MaterialColor::calculateRGBColor`.
+    #[must_use]
+    pub fn rgb(self, brightness: Brightness) -> u32 {
+        let rgb = self.base_rgb();
+        let modifier = brightness.modifier();
+        let channel = |shift: u32| (((rgb >> shift) & 0xff) * modifier / 255) << shift;
+
+        channel(16) | channel(8) | channel(0)
+    }
+
+    /// Returns an iterator over the default states of the vanilla blocks with this map color.
+    pub fn blocks(self) -> impl Iterator<Item = Block> {
+        BlockId::iter()
+            .filter(move |id| id.properties().material_color == self)
+            .map(Block::from_id)
+    }
+
+    /// Returns the RGB value of the color at the [`Brightness::High`] brightness, which is the
+    /// value vanilla declares the color with.
     #[allow(clippy::unreadable_literal)] // Sir, this is a color.
     #[must_use]
     #[rustfmt::skip]
-    pub fn rgb(self) -> u32 {
+    pub const fn base_rgb(self) -> u32 {
         match self {
             Self::None                 => 0x000000,
             Self::Grass                => 0x7fb238,
@@ -393,6 +515,62 @@ impl Color {
     }
 }
 
+/// The shades each map color is drawn in, which depend on the height of the block relative to
+/// the block north of it, in the order of their vanilla IDs.
+#[derive(Clone, Copy, Debug, Enum, EnumIter, PartialEq, Eq, Hash)]
+pub enum Brightness {
+    /// Used for blocks lower than the block north of them.
+    Low,
+    /// Used for blocks level with the block north of them.
+    Normal,
+    /// Used for blocks higher than the block north of them.
+    High,
+    /// Not used by vanilla when drawing maps, but valid in the data of maps.
+    Lowest,
+}
+
+impl Brightness {
+    /// Returns the factor that the channels of a color are multiplied with at this brightness,
+    /// out of 255.
+    #[must_use]
+    pub const fn modifier(self) -> u32 {
+        match self {
+            Brightness::Low => 180,
+            Brightness::Normal => 220,
+            Brightness::High => 255,
+            Brightness::Lowest => 135,
+        }
+    }
+}
+
+/// Returns the block out of the given ones whose map color is nearest to the given `0xRRGGBB`
+/// color at one of the given brightnesses, along with that brightness, for converting images to
+/// map art. Colors are compared by their squared distance in RGB space, and blocks with the
+/// [`Color::None`] map color are skipped as they are transparent on maps. Returns `None` if no
+/// block or brightness was given.
+///
+/// Pass [`Brightness::Normal`] alone for flat map art, or also [`Brightness::Low`] and
+/// [`Brightness::High`] for staircased map art.
+pub fn nearest_block(
+    rgb: u32,
+    blocks: impl IntoIterator<Item = Block>,
+    brightnesses: &[Brightness],
+) -> Option<(Block, Brightness)> {
+    blocks
+        .into_iter()
+        .filter(|block| block.map_color() != Color::None)
+        .flat_map(|block| brightnesses.iter().map(move |&b| (block, b)))
+        .min_by_key(|&(block, brightness)| distance(rgb, block.map_color().rgb(brightness)))
+}
+
+/// Returns the squared distance between the given `0xRRGGBB` colors in RGB space.
+fn distance(a: u32, b: u32) -> u32 {
+    [16, 8, 0]
+        .into_iter()
+        .map(|shift| ((a >> shift) & 0xff).abs_diff((b >> shift) & 0xff).pow(2))
+        .sum()
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// What should happen when a piston tries to push the block.
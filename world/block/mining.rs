@@ -0,0 +1,176 @@
+//! Block breaking, following vanilla's formula for how long it takes a player to break a block
+//! and whether the block drops anything when broken.
+
+use crate::item::{ItemId, Tier, Tool};
+use crate::material::Kind;
+use crate::tag::{self, vanilla};
+use crate::{Block, BlockId};
+
+/// The circumstances under which a player breaks a block. The default is a player standing on the
+/// ground out of water, with an empty hand and without any effects.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Conditions {
+    /// The held item, if any. Items that aren't tools mine like an empty hand.
+    pub tool: Option<ItemId>,
+    /// The level of the Efficiency enchantment of the held item.
+    pub efficiency: u8,
+    /// The level of the Haste effect, or 0 if the player doesn't have it.
+    pub haste: u8,
+    /// The level of the Mining Fatigue effect, or 0 if the player doesn't have it.
+    pub mining_fatigue: u8,
+    /// Whether the player's eyes are in water without the Aqua Affinity enchantment.
+    pub underwater: bool,
+    /// Whether the player is not standing on the ground, like when flying or swimming.
+    pub airborne: bool,
+}
+
+impl Block {
+    /// Returns the fraction of the block that is broken each tick under the given conditions. This
+    /// is 0 for unbreakable blocks like bedrock and infinite for blocks that break instantly.
+    #[must_use]
+    pub fn destroy_progress(self, conditions: Conditions) -> f32 {
+        let destroy_time = self.behavior().destroy_time;
+
+        if destroy_time < 0.0 {
+            return 0.0;
+        }
+
+        let divisor = if self.can_harvest(conditions.tool) {
+            30.0
+        } else {
+            100.0
+        };
+
+        destroy_speed(self, conditions) / destroy_time / divisor
+    }
+
+    /// Returns the number of ticks it takes to break the block under the given conditions, or
+    /// `None` if the block can't be broken. Blocks that break instantly take 0 ticks.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn break_ticks(self, conditions: Conditions) -> Option<u32> {
+        let progress = self.destroy_progress(conditions);
+
+        if progress <= 0.0 {
+            None
+        } else if progress >= 1.0 {
+            Some(0)
+        } else {
+            Some((1.0 / progress).ceil() as u32)
+        }
+    }
+
+    /// Returns `true` if the block drops anything when broken with the given item, which is always
+    /// the case for blocks that don't require the correct tool for drops.
+    #[must_use]
+    pub fn can_harvest(self, tool: Option<ItemId>) -> bool {
+        !self.behavior().requires_correct_tool_for_drops
+            || tool.is_some_and(|tool| tool.is_correct_tool_for_drops(self))
+    }
+}
+
+impl ItemId {
+    /// Returns the speed multiplier of the item when mining the given block, before enchantments
+    /// and effects are taken into account.
+    #[must_use]
+    pub fn destroy_speed(self, block: Block) -> f32 {
+        let id = block.id();
+
+        match self.tool() {
+            Some(Tool::Sword(_)) if id == BlockId::Cobweb => 15.0,
+            Some(Tool::Sword(_)) if is_sword_efficient(id) => 1.5,
+            Some(Tool::Shears) if id == BlockId::Cobweb || id.is_in(vanilla("leaves")) => 15.0,
+            Some(Tool::Shears) if id.is_in(vanilla("wool")) => 5.0,
+            Some(Tool::Shears) if matches!(id, BlockId::Vine | BlockId::GlowLichen) => 2.0,
+            Some(
+                Tool::Shovel(tier) | Tool::Pickaxe(tier) | Tool::Axe(tier) | Tool::Hoe(tier),
+            ) if id.is_in(mineable(self)) => tier.speed(),
+            _ => 1.0,
+        }
+    }
+
+    /// Returns `true` if the item is the correct tool to harvest the given block.
+    #[must_use]
+    pub fn is_correct_tool_for_drops(self, block: Block) -> bool {
+        let id = block.id();
+
+        match self.tool() {
+            Some(Tool::Sword(_)) => id == BlockId::Cobweb,
+            Some(Tool::Shears) => {
+                matches!(id, BlockId::Cobweb | BlockId::RedstoneWire | BlockId::Tripwire)
+            }
+            Some(
+                Tool::Shovel(tier) | Tool::Pickaxe(tier) | Tool::Axe(tier) | Tool::Hoe(tier),
+            ) => id.is_in(mineable(self)) && tier.level() >= required_level(id),
+            None => false,
+        }
+    }
+}
+
+/// Returns the speed at which the player mines the block, including enchantments and effects.
+fn destroy_speed(block: Block, conditions: Conditions) -> f32 {
+    let mut speed = conditions
+        .tool
+        .map_or(1.0, |tool| tool.destroy_speed(block));
+
+    if speed > 1.0 && conditions.efficiency > 0 {
+        speed += f32::from(conditions.efficiency).powi(2) + 1.0;
+    }
+
+    if conditions.haste > 0 {
+        speed *= 1.0 + f32::from(conditions.haste) * 0.2;
+    }
+
+    speed *= match conditions.mining_fatigue {
+        0 => 1.0,
+        1 => 0.3,
+        2 => 0.09,
+        3 => 0.0027,
+        _ => 0.00081,
+    };
+
+    if conditions.underwater {
+        speed /= 5.0;
+    }
+
+    if conditions.airborne {
+        speed /= 5.0;
+    }
+
+    speed
+}
+
+/// Returns the tag of the blocks the given digging tool is meant for.
+fn mineable(tool: ItemId) -> &'static tag::Tag {
+    match tool.tool() {
+        Some(Tool::Shovel(_)) => vanilla("mineable/shovel"),
+        Some(Tool::Pickaxe(_)) => vanilla("mineable/pickaxe"),
+        Some(Tool::Axe(_)) => vanilla("mineable/axe"),
+        Some(Tool::Hoe(_)) => vanilla("mineable/hoe"),
+        _ => unreachable!("expected a digging tool"),
+    }
+}
+
+/// Returns the minimum [`Tier::level`] of a tool to harvest the block.
+fn required_level(id: BlockId) -> u8 {
+    if id.is_in(vanilla("needs_diamond_tool")) {
+        Tier::Diamond.level()
+    } else if id.is_in(vanilla("needs_iron_tool")) {
+        Tier::Iron.level()
+    } else if id.is_in(vanilla("needs_stone_tool")) {
+        Tier::Stone.level()
+    } else {
+        0
+    }
+}
+
+/// Returns `true` if swords mine the block faster than an empty hand, which is the case for
+/// leaves and for blocks made of plant, replaceable plant or vegetable material.
+fn is_sword_efficient(id: BlockId) -> bool {
+    let is_plant = matches!(
+        id.properties().material.kind(),
+        Kind::Plant | Kind::ReplaceablePlant | Kind::Vegetable
+    );
+
+    is_plant || id.is_in(vanilla("leaves"))
+}
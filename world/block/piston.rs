@@ -0,0 +1,103 @@
+//! Properties of blocks that decide how pistons move them.
+
+pub use crate::material::PushReaction;
+use crate::tag::vanilla;
+use crate::{Block, BlockId};
+
+impl Block {
+    /// Returns what happens to the block when a piston pushes or pulls it. Most blocks take the
+    /// reaction of their material, but some override it, like glazed terracotta which can only be
+    /// pushed.
+    #[must_use]
+    pub fn push_reaction(self) -> PushReaction {
+        use BlockId::*;
+
+        let id = self.id();
+
+        match id {
+            WhiteGlazedTerracotta
+            | OrangeGlazedTerracotta
+            | MagentaGlazedTerracotta
+            | LightBlueGlazedTerracotta
+            | YellowGlazedTerracotta
+            | LimeGlazedTerracotta
+            | PinkGlazedTerracotta
+            | GrayGlazedTerracotta
+            | LightGrayGlazedTerracotta
+            | CyanGlazedTerracotta
+            | PurpleGlazedTerracotta
+            | BlueGlazedTerracotta
+            | BrownGlazedTerracotta
+            | GreenGlazedTerracotta
+            | RedGlazedTerracotta
+            | BlackGlazedTerracotta => PushReaction::PushOnly,
+            PointedDripstone
+            | AmethystCluster
+            | LargeAmethystBud
+            | MediumAmethystBud
+            | SmallAmethystBud => PushReaction::Destroy,
+            _ if id.is_in(vanilla("doors")) || id.is_in(vanilla("beds")) => PushReaction::Destroy,
+            _ => self.behavior().material.push_reaction,
+        }
+    }
+
+    /// Returns `true` if the block has a block entity in vanilla, which holds data that doesn't
+    /// fit in the block state, like the items of a chest. Pistons can't move these blocks.
+    #[must_use]
+    pub fn has_block_entity(self) -> bool {
+        use BlockId::*;
+
+        let id = self.id();
+
+        matches!(
+            id,
+            Chest
+                | TrappedChest
+                | EnderChest
+                | Furnace
+                | Smoker
+                | BlastFurnace
+                | Jukebox
+                | Dispenser
+                | Dropper
+                | Spawner
+                | MovingPiston
+                | BrewingStand
+                | EnchantingTable
+                | EndPortal
+                | EndGateway
+                | Beacon
+                | SkeletonSkull
+                | SkeletonWallSkull
+                | WitherSkeletonSkull
+                | WitherSkeletonWallSkull
+                | ZombieHead
+                | ZombieWallHead
+                | PlayerHead
+                | PlayerWallHead
+                | CreeperHead
+                | CreeperWallHead
+                | DragonHead
+                | DragonWallHead
+                | DaylightDetector
+                | Hopper
+                | Comparator
+                | CommandBlock
+                | RepeatingCommandBlock
+                | ChainCommandBlock
+                | StructureBlock
+                | Jigsaw
+                | Conduit
+                | Barrel
+                | Lectern
+                | Bell
+                | BeeNest
+                | Beehive
+                | SculkSensor
+        ) || id.is_in(vanilla("signs"))
+            || id.is_in(vanilla("beds"))
+            || id.is_in(vanilla("banners"))
+            || id.is_in(vanilla("shulker_boxes"))
+            || id.is_in(vanilla("campfires"))
+    }
+}
@@ -0,0 +1,96 @@
+//! A seedable pseudorandom number generator, producing the same sequences as the one vanilla uses
+//! (`java.util.Random`) for the same seed.
+
+/// A linear congruential generator with a 48-bit state.
+#[derive(Clone, Debug)]
+pub struct Random {
+    seed: u64,
+}
+
+impl Default for Random {
+    /// Creates a generator with a seed of 0.
+    fn default() -> Self {
+        Random::new(0)
+    }
+}
+
+impl Random {
+    const MULTIPLIER: u64 = 0x5_deec_e66d;
+    const ADDEND: u64 = 0xb;
+    const MASK: u64 = (1 << 48) - 1;
+
+    /// Creates a new generator with the given seed.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Random {
+            seed: (seed ^ Self::MULTIPLIER) & Self::MASK,
+        }
+    }
+
+    /// Returns the next `bits` random bits, with `bits` at most 32.
+    #[allow(clippy::cast_possible_truncation)]
+    fn next(&mut self, bits: u32) -> u32 {
+        self.seed = self
+            .seed
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(Self::ADDEND)
+            & Self::MASK;
+
+        (self.seed >> (48 - bits)) as u32
+    }
+
+    /// Returns a random integer, uniformly distributed over all `i32` values.
+    pub fn next_i32(&mut self) -> i32 {
+        self.next(32).cast_signed()
+    }
+
+    /// Returns a random integer in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is not positive.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn next_i32_below(&mut self, bound: i32) -> i32 {
+        assert!(bound > 0, "bound must be positive");
+
+        if bound.count_ones() == 1 {
+            return ((i64::from(bound) * i64::from(self.next(31))) >> 31) as i32;
+        }
+
+        // Like vanilla, reject the values that would make the distribution uneven.
+        loop {
+            let bits = self.next(31).cast_signed();
+            let value = bits % bound;
+
+            if bits.checked_sub(value).and_then(|n| n.checked_add(bound - 1)).is_some() {
+                return value;
+            }
+        }
+    }
+
+    /// Returns a random integer in `min..=max`, or `min` if `max` is less than `min`.
+    pub fn next_i32_between(&mut self, min: i32, max: i32) -> i32 {
+        if min >= max {
+            min
+        } else {
+            min + self.next_i32_below(max - min + 1)
+        }
+    }
+
+    /// Returns a random boolean.
+    pub fn next_bool(&mut self) -> bool {
+        self.next(1) != 0
+    }
+
+    /// Returns a random float in `0.0..1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn next_f32(&mut self) -> f32 {
+        self.next(24) as f32 / (1 << 24) as f32
+    }
+
+    /// Returns a random double in `0.0..1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn next_f64(&mut self) -> f64 {
+        ((u64::from(self.next(26)) << 27) + u64::from(self.next(27))) as f64 / (1_u64 << 53) as f64
+    }
+}
@@ -0,0 +1,51 @@
+//! Properties of blocks that take part in redstone circuits.
+
+use crate::tag::vanilla;
+use crate::{Block, BlockId};
+
+impl Block {
+    /// Returns `true` if the block conducts redstone power, meaning that it can be powered by
+    /// redstone components around it. Most full blocks are conductors.
+    #[must_use]
+    pub fn is_redstone_conductor(self) -> bool {
+        use BlockId::*;
+
+        match self.id() {
+            SoulSand => true,
+            Beacon | Observer | Piston | StickyPiston | RedstoneBlock => false,
+            // Glass and leaves.
+            id if id.is_in(vanilla("impermeable")) || id.is_in(vanilla("leaves")) => false,
+            _ => self.collision_shape().is_full(),
+        }
+    }
+
+    /// Returns `true` if the block emits redstone power by itself.
+    #[must_use]
+    pub fn is_signal_source(self) -> bool {
+        use BlockId::*;
+
+        let id = self.id();
+
+        id.is_in(vanilla("buttons"))
+            || id.is_in(vanilla("pressure_plates"))
+            || matches!(
+                id,
+                RedstoneWire
+                    | Repeater
+                    | Comparator
+                    | RedstoneTorch
+                    | RedstoneWallTorch
+                    | RedstoneBlock
+                    | Lever
+                    | DetectorRail
+                    | DaylightDetector
+                    | Observer
+                    | Target
+                    | TrappedChest
+                    | TripwireHook
+                    | Lectern
+                    | SculkSensor
+                    | LightningRod
+            )
+    }
+}
@@ -0,0 +1,134 @@
+//! Global block state IDs, as used by the network protocol and vanilla's `blocks.json` report.
+//! The IDs are compatible with vanilla 1.18.2, and custom blocks follow the vanilla ones.
+
+use std::ops::Range;
+
+use crate::custom::{self, CustomBlockId};
+use crate::{state, Block, BlockId};
+
+impl Block {
+    /// Returns the global state ID of the block.
+    #[must_use]
+    pub fn state_id(self) -> u32 {
+        let mut index = 0;
+
+        for definition in self.state_definition().properties {
+            let bits = self.property_bits(definition.id, definition.offset);
+            index = index * definition.id.value_count() + value_index(definition.id, bits);
+        }
+
+        match self.custom_id() {
+            Some(id) => id.base_state_id() + index,
+            None => BlockId::BASE_STATE_IDS[self.id().to_numeric() as usize] + index,
+        }
+    }
+
+    /// Converts the given global state ID to a `Block`, or returns `None` if the ID is out of
+    /// range.
+    #[must_use]
+    pub fn from_state_id(state_id: u32) -> Option<Self> {
+        if state_id >= STATE_COUNT {
+            let id = custom::from_state_id(state_id)?;
+
+            return Some(Block::from_state_index(
+                Block::from_custom(id),
+                state_id - id.base_state_id(),
+            ));
+        }
+
+        let numeric_id = BlockId::BASE_STATE_IDS.partition_point(|&base| base <= state_id) - 1;
+        let block_id = BlockId::from_numeric(u16::try_from(numeric_id).ok()?)?;
+
+        Some(Block::from_state_index(
+            Block::from_id(block_id),
+            state_id - BlockId::BASE_STATE_IDS[numeric_id],
+        ))
+    }
+
+    /// Returns the state of the given block with the given index within the block's states.
+    fn from_state_index(mut block: Block, mut index: u32) -> Self {
+        let definitions = block.state_definition().properties;
+
+        for definition in definitions.iter().rev() {
+            let value_count = definition.id.value_count();
+            // The value index is its own inverse.
+            let bits = value_index(definition.id, index % value_count);
+            index /= value_count;
+
+            block.set_property(
+                state::Property::from_bits(definition.id, bits).unwrap(),
+                definition.offset,
+            );
+        }
+
+        block
+    }
+}
+
+impl BlockId {
+    /// Returns the range of global state IDs of the block's states.
+    #[must_use]
+    pub fn state_ids(self) -> Range<u32> {
+        let base = BlockId::BASE_STATE_IDS[self.to_numeric() as usize];
+
+        base..base + self.state_count()
+    }
+
+    /// Returns an iterator over all valid states of the block, in the order of their global state
+    /// IDs.
+    pub fn states(self) -> impl Iterator<Item = Block> {
+        (0..self.state_count())
+            .map(move |index| Block::from_state_index(Block::from_id(self), index))
+    }
+}
+
+impl CustomBlockId {
+    /// Returns the range of global state IDs of the custom block's states, which follow those of
+    /// the vanilla blocks and of the custom blocks registered before it.
+    #[must_use]
+    pub fn state_ids(self) -> Range<u32> {
+        let base = self.base_state_id();
+
+        base..base + self.state_count()
+    }
+
+    /// Returns an iterator over all valid states of the custom block, in the order of their
+    /// global state IDs.
+    pub fn states(self) -> impl Iterator<Item = Block> {
+        (0..self.state_count())
+            .map(move |index| Block::from_state_index(Block::from_custom(self), index))
+    }
+}
+
+/// Converts the bits of a property to the index of the value in vanilla's list of values of the
+/// property. These are the same except for booleans, which vanilla lists as `true` then `false`.
+fn value_index(id: state::PropertyId, bits: u32) -> u32 {
+    if id.ty() == state::PropertyType::Boolean {
+        1 - bits
+    } else {
+        bits
+    }
+}
+
+/// The total number of vanilla block states.
+pub(crate) const STATE_COUNT: u32 = BlockId::STATE_COUNT;
+
+// The report must list as many states for each block as its property types allow.
+const _: () = {
+    let mut numeric_id = 0;
+
+    while numeric_id <= BlockId::MAX {
+        let Some(block_id) = BlockId::from_numeric(numeric_id) else {
+            unreachable!();
+        };
+        let base_state_id = BlockId::BASE_STATE_IDS[numeric_id as usize];
+        let next_base_state_id = if numeric_id < BlockId::MAX {
+            BlockId::BASE_STATE_IDS[numeric_id as usize + 1]
+        } else {
+            STATE_COUNT
+        };
+
+        assert!(next_base_state_id - base_state_id == block_id.state_count());
+        numeric_id += 1;
+    }
+};
@@ -0,0 +1,1500 @@
+//! Outline, collision and occlusion shapes of blocks. The shapes match vanilla 1.18.2, except for
+//! the parts that vanilla derives from the position of the block or from the entity colliding with
+//! it, like the random offsets of plants or the collision shape of scaffolding.
+
+use std::borrow::Cow;
+use std::sync::LazyLock;
+
+use crate::state::{self, Facing, HorizontalFacing};
+use crate::{registry, Block, BlockId};
+
+impl Block {
+    /// Returns the shape used for targeting the block, which is also what vanilla draws the
+    /// outline of when the block is targeted.
+    #[must_use]
+    pub fn outline_shape(self) -> &'static VoxelShape {
+        &shapes(self).outline
+    }
+
+    /// Returns the shape entities collide with.
+    #[must_use]
+    pub fn collision_shape(self) -> &'static VoxelShape {
+        &shapes(self).collision
+    }
+
+    /// Returns the shape used to hide the faces of neighboring blocks. This is empty for blocks
+    /// that can't occlude.
+    #[must_use]
+    pub fn occlusion_shape(self) -> &'static VoxelShape {
+        &shapes(self).occlusion
+    }
+
+    /// Returns whether the face of the block in the given direction is hidden by the given
+    /// neighbor in that direction.
+    #[must_use]
+    pub fn is_face_occluded_by(self, face: Facing, neighbor: Block) -> bool {
+        neighbor.behavior().can_occlude
+            && self
+                .occlusion_shape()
+                .is_face_covered_by(face, neighbor.occlusion_shape())
+    }
+}
+
+/// An axis-aligned box, in block coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    /// The minimum corner of the box.
+    pub min: [f64; 3],
+    /// The maximum corner of the box.
+    pub max: [f64; 3],
+}
+
+impl Aabb {
+    /// The box spanning the whole block.
+    pub const FULL: Self = Aabb::new([0.0; 3], [1.0; 3]);
+
+    /// Creates a new box out of its minimum and maximum corners.
+    #[must_use]
+    pub const fn new(min: [f64; 3], max: [f64; 3]) -> Self {
+        Aabb { min, max }
+    }
+
+    /// Creates a new box out of coordinates given in pixels, or sixteenths of a block, which is
+    /// how vanilla declares its shapes.
+    #[must_use]
+    pub const fn from_pixels(
+        min_x: f64,
+        min_y: f64,
+        min_z: f64,
+        max_x: f64,
+        max_y: f64,
+        max_z: f64,
+    ) -> Self {
+        Aabb::new(
+            [min_x / 16.0, min_y / 16.0, min_z / 16.0],
+            [max_x / 16.0, max_y / 16.0, max_z / 16.0],
+        )
+    }
+
+    /// Returns `true` if the box has no volume.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        (0..3).any(|axis| self.min[axis] >= self.max[axis])
+    }
+
+    /// Returns `true` if the two boxes overlap. Boxes that merely touch don't overlap.
+    #[must_use]
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        (0..3).all(|axis| self.min[axis] < other.max[axis] && other.min[axis] < self.max[axis])
+    }
+
+    /// Returns the box moved by the given offset.
+    #[must_use]
+    pub fn translate(self, offset: [f64; 3]) -> Self {
+        Aabb::new(
+            [0, 1, 2].map(|axis| self.min[axis] + offset[axis]),
+            [0, 1, 2].map(|axis| self.max[axis] + offset[axis]),
+        )
+    }
+
+    /// Pushes the parts of the box not covered by `other` to `out`.
+    fn subtract(self, other: &Aabb, out: &mut Vec<Aabb>) {
+        if !self.intersects(other) {
+            out.push(self);
+            return;
+        }
+
+        let mut rest = self;
+
+        for axis in 0..3 {
+            if rest.min[axis] < other.min[axis] {
+                let mut part = rest;
+                part.max[axis] = other.min[axis];
+                out.push(part);
+                rest.min[axis] = other.min[axis];
+            }
+
+            if rest.max[axis] > other.max[axis] {
+                let mut part = rest;
+                part.min[axis] = other.max[axis];
+                out.push(part);
+                rest.max[axis] = other.max[axis];
+            }
+        }
+    }
+
+    /// Rotates the box clockwise around the center of the block, as seen from above, by the given
+    /// number of quarter turns.
+    fn rotate_y(self, quarter_turns: u32) -> Self {
+        (0..quarter_turns % 4).fold(self, |aabb, _| {
+            Aabb::new(
+                [1.0 - aabb.max[2], aabb.min[1], aabb.min[0]],
+                [1.0 - aabb.min[2], aabb.max[1], aabb.max[0]],
+            )
+        })
+    }
+
+    /// Mirrors the box vertically around the center of the block.
+    fn flip_y(self) -> Self {
+        Aabb::new(
+            [self.min[0], 1.0 - self.max[1], self.min[2]],
+            [self.max[0], 1.0 - self.min[1], self.max[2]],
+        )
+    }
+
+    /// Rotates a box declared for a block facing north to the given facing.
+    fn horizontal(self, facing: HorizontalFacing) -> Self {
+        self.rotate_y(facing as u32)
+    }
+
+    /// Rotates a box declared for a block facing up to the given facing.
+    fn directional(self, facing: Facing) -> Self {
+        let north = || {
+            Aabb::new(
+                [self.min[0], self.min[2], 1.0 - self.max[1]],
+                [self.max[0], self.max[2], 1.0 - self.min[1]],
+            )
+        };
+
+        match facing {
+            Facing::Up => self,
+            Facing::Down => self.flip_y(),
+            Facing::North => north(),
+            Facing::East => north().rotate_y(1),
+            Facing::South => north().rotate_y(2),
+            Facing::West => north().rotate_y(3),
+        }
+    }
+}
+
+/// A shape made out of a union of boxes, in block coordinates. The boxes may extend outside the
+/// block, like the collision boxes of fences do.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoxelShape {
+    boxes: Cow<'static, [Aabb]>,
+}
+
+impl VoxelShape {
+    /// The shape with no volume.
+    pub const EMPTY: Self = VoxelShape {
+        boxes: Cow::Borrowed(&[]),
+    };
+
+    /// The shape spanning the whole block.
+    pub const FULL: Self = VoxelShape {
+        boxes: Cow::Borrowed(&[Aabb::FULL]),
+    };
+
+    /// Creates a new shape out of the union of the given boxes. Boxes with no volume are
+    /// discarded.
+    pub fn new(boxes: impl IntoIterator<Item = Aabb>) -> Self {
+        VoxelShape {
+            boxes: boxes.into_iter().filter(|aabb| !aabb.is_empty()).collect(),
+        }
+    }
+
+    /// Returns the boxes the shape is made of. The boxes may overlap.
+    #[must_use]
+    pub fn boxes(&self) -> &[Aabb] {
+        &self.boxes
+    }
+
+    /// Returns `true` if the shape has no volume.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// Returns `true` if the shape covers the whole block.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        VoxelShape::FULL.subtract(self).is_empty()
+    }
+
+    /// Returns the smallest box containing the shape, or `None` if the shape is empty.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Aabb> {
+        self.boxes.iter().copied().reduce(|a, b| {
+            Aabb::new(
+                [0, 1, 2].map(|axis| a.min[axis].min(b.min[axis])),
+                [0, 1, 2].map(|axis| a.max[axis].max(b.max[axis])),
+            )
+        })
+    }
+
+    /// Returns the union of the two shapes.
+    #[must_use]
+    pub fn union(&self, other: &VoxelShape) -> Self {
+        VoxelShape::new(self.boxes.iter().chain(other.boxes.iter()).copied())
+    }
+
+    /// Returns the parts of the shape not covered by `other`.
+    #[must_use]
+    pub fn subtract(&self, other: &VoxelShape) -> Self {
+        let mut boxes = self.boxes.to_vec();
+        let mut parts = Vec::new();
+
+        for other in other.boxes.iter() {
+            for aabb in boxes.drain(..) {
+                aabb.subtract(other, &mut parts);
+            }
+
+            boxes.append(&mut parts);
+        }
+
+        VoxelShape::new(boxes)
+    }
+
+    /// Returns `true` if the two shapes overlap. Shapes that merely touch don't overlap.
+    #[must_use]
+    pub fn intersects(&self, other: &VoxelShape) -> bool {
+        self.boxes
+            .iter()
+            .any(|a| other.boxes.iter().any(|b| a.intersects(b)))
+    }
+
+    /// Returns the shape moved by the given offset.
+    #[must_use]
+    pub fn translate(&self, offset: [f64; 3]) -> Self {
+        VoxelShape::new(self.boxes.iter().map(|aabb| aabb.translate(offset)))
+    }
+
+    /// Returns the parts of the shape touching the given face of the block, projected onto the
+    /// face and extruded through the whole block.
+    #[must_use]
+    pub fn face(&self, face: Facing) -> Self {
+        let (axis, positive) = match face {
+            Facing::West => (0, false),
+            Facing::East => (0, true),
+            Facing::Down => (1, false),
+            Facing::Up => (1, true),
+            Facing::North => (2, false),
+            Facing::South => (2, true),
+        };
+
+        VoxelShape::new(self.boxes.iter().filter_map(|aabb| {
+            let touches = if positive {
+                aabb.min[axis] < 1.0 && aabb.max[axis] >= 1.0
+            } else {
+                aabb.min[axis] <= 0.0 && aabb.max[axis] > 0.0
+            };
+
+            touches.then(|| {
+                let mut face =
+                    Aabb::new(aabb.min.map(|n| n.max(0.0)), aabb.max.map(|n| n.min(1.0)));
+                face.min[axis] = 0.0;
+                face.max[axis] = 1.0;
+
+                face
+            })
+        }))
+    }
+
+    /// Returns `true` if the shape covers the whole face of the block in the given direction.
+    #[must_use]
+    pub fn is_face_full(&self, face: Facing) -> bool {
+        self.face(face).is_full()
+    }
+
+    /// Returns `true` if the given face of the shape is covered by the opposite face of `other`,
+    /// where `other` is the shape of the neighbor in the direction of the face.
+    #[must_use]
+    pub fn is_face_covered_by(&self, face: Facing, other: &VoxelShape) -> bool {
+        let opposite = match face {
+            Facing::North => Facing::South,
+            Facing::East => Facing::West,
+            Facing::South => Facing::North,
+            Facing::West => Facing::East,
+            Facing::Up => Facing::Down,
+            Facing::Down => Facing::Up,
+        };
+
+        self.face(face).subtract(&other.face(opposite)).is_empty()
+    }
+
+    fn map(&self, f: impl Fn(Aabb) -> Aabb) -> Self {
+        VoxelShape::new(self.boxes.iter().copied().map(f))
+    }
+}
+
+impl From<Aabb> for VoxelShape {
+    fn from(aabb: Aabb) -> Self {
+        VoxelShape::new([aabb])
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The shapes of a block state.
+pub(crate) struct Shapes {
+    outline: VoxelShape,
+    collision: VoxelShape,
+    occlusion: VoxelShape,
+}
+
+impl Shapes {
+    fn new(block: Block) -> Self {
+        let outline = outline(block);
+
+        Shapes {
+            collision: collision(block, &outline),
+            occlusion: occlusion(block, &outline),
+            outline,
+        }
+    }
+}
+
+/// The shapes of every block state, indexed by the global state ID. Like vanilla, we compute the
+/// shapes once up front rather than on every query.
+static SHAPES: LazyLock<Box<[Shapes]>> = LazyLock::new(|| {
+    (0..registry::STATE_COUNT)
+        .map(|state_id| Shapes::new(Block::from_state_id(state_id).unwrap()))
+        .collect()
+});
+
+/// Returns the shapes of the block. Custom blocks have the shapes of their base block in the same
+/// state, except that their own behavior properties decide whether they have collision and can
+/// occlude. These are computed for all states of a custom block the first time one is needed.
+fn shapes(block: Block) -> &'static Shapes {
+    match block.custom_id() {
+        Some(id) => {
+            let shapes = id
+                .shapes()
+                .get_or_init(|| id.states().map(Shapes::new).collect());
+
+            &shapes[(block.state_id() - id.base_state_id()) as usize]
+        }
+        None => &SHAPES[block.state_id() as usize],
+    }
+}
+
+/// Shorthand for [`Aabb::from_pixels`].
+const fn px(min_x: f64, min_y: f64, min_z: f64, max_x: f64, max_y: f64, max_z: f64) -> Aabb {
+    Aabb::from_pixels(min_x, min_y, min_z, max_x, max_y, max_z)
+}
+
+/// Returns the value of the property of type `T` of the block.
+fn get<T: state::PropertyValue>(block: Block) -> T {
+    block.get().expect("missing block state property")
+}
+
+#[allow(clippy::match_same_arms)] // The arms are grouped by kind of block rather than by shape.
+#[allow(clippy::too_many_lines)]
+fn outline(block: Block) -> VoxelShape {
+    use BlockId::*;
+
+    match block.id() {
+        Air | CaveAir | VoidAir | Water | Lava | BubbleColumn | MovingPiston | Light => {
+            VoxelShape::EMPTY
+        }
+        Fire => {
+            let sides = [
+                (
+                    get::<state::North>(block).0,
+                    px(0.0, 0.0, 0.0, 16.0, 16.0, 1.0),
+                ),
+                (
+                    get::<state::East>(block).0,
+                    px(15.0, 0.0, 0.0, 16.0, 16.0, 16.0),
+                ),
+                (
+                    get::<state::South>(block).0,
+                    px(0.0, 0.0, 15.0, 16.0, 16.0, 16.0),
+                ),
+                (
+                    get::<state::West>(block).0,
+                    px(0.0, 0.0, 0.0, 1.0, 16.0, 16.0),
+                ),
+                (
+                    get::<state::Up>(block).0,
+                    px(0.0, 15.0, 0.0, 16.0, 16.0, 16.0),
+                ),
+            ];
+            let shape = VoxelShape::new(
+                sides
+                    .into_iter()
+                    .filter_map(|(set, aabb)| set.then_some(aabb)),
+            );
+
+            if shape.is_empty() {
+                px(0.0, 0.0, 0.0, 16.0, 1.0, 16.0).into()
+            } else {
+                shape
+            }
+        }
+        SoulFire => px(0.0, 0.0, 0.0, 16.0, 1.0, 16.0).into(),
+        NetherPortal => match get::<state::HorizontalAxis>(block) {
+            state::HorizontalAxis::X => px(0.0, 0.0, 6.0, 16.0, 16.0, 10.0).into(),
+            state::HorizontalAxis::Z => px(6.0, 0.0, 0.0, 10.0, 16.0, 16.0).into(),
+        },
+        EndPortal => px(0.0, 6.0, 0.0, 16.0, 12.0, 16.0).into(),
+        StructureVoid => px(5.0, 5.0, 5.0, 11.0, 11.0, 11.0).into(),
+
+        // Building blocks
+        AcaciaSlab
+        | AndesiteSlab
+        | BirchSlab
+        | BlackstoneSlab
+        | BrickSlab
+        | CobbledDeepslateSlab
+        | CobblestoneSlab
+        | CrimsonSlab
+        | CutCopperSlab
+        | CutRedSandstoneSlab
+        | CutSandstoneSlab
+        | DarkOakSlab
+        | DarkPrismarineSlab
+        | DeepslateBrickSlab
+        | DeepslateTileSlab
+        | DioriteSlab
+        | EndStoneBrickSlab
+        | ExposedCutCopperSlab
+        | GraniteSlab
+        | JungleSlab
+        | MossyCobblestoneSlab
+        | MossyStoneBrickSlab
+        | NetherBrickSlab
+        | OakSlab
+        | OxidizedCutCopperSlab
+        | PetrifiedOakSlab
+        | PolishedAndesiteSlab
+        | PolishedBlackstoneBrickSlab
+        | PolishedBlackstoneSlab
+        | PolishedDeepslateSlab
+        | PolishedDioriteSlab
+        | PolishedGraniteSlab
+        | PrismarineBrickSlab
+        | PrismarineSlab
+        | PurpurSlab
+        | QuartzSlab
+        | RedNetherBrickSlab
+        | RedSandstoneSlab
+        | SandstoneSlab
+        | SmoothQuartzSlab
+        | SmoothRedSandstoneSlab
+        | SmoothSandstoneSlab
+        | SmoothStoneSlab
+        | SpruceSlab
+        | StoneBrickSlab
+        | StoneSlab
+        | WarpedSlab
+        | WaxedCutCopperSlab
+        | WaxedExposedCutCopperSlab
+        | WaxedOxidizedCutCopperSlab
+        | WaxedWeatheredCutCopperSlab
+        | WeatheredCutCopperSlab => match get::<state::SlabType>(block) {
+            state::SlabType::Top => px(0.0, 8.0, 0.0, 16.0, 16.0, 16.0).into(),
+            state::SlabType::Bottom => px(0.0, 0.0, 0.0, 16.0, 8.0, 16.0).into(),
+            state::SlabType::Double => VoxelShape::FULL,
+        },
+        AcaciaStairs
+        | AndesiteStairs
+        | BirchStairs
+        | BlackstoneStairs
+        | BrickStairs
+        | CobbledDeepslateStairs
+        | CobblestoneStairs
+        | CrimsonStairs
+        | CutCopperStairs
+        | DarkOakStairs
+        | DarkPrismarineStairs
+        | DeepslateBrickStairs
+        | DeepslateTileStairs
+        | DioriteStairs
+        | EndStoneBrickStairs
+        | ExposedCutCopperStairs
+        | GraniteStairs
+        | JungleStairs
+        | MossyCobblestoneStairs
+        | MossyStoneBrickStairs
+        | NetherBrickStairs
+        | OakStairs
+        | OxidizedCutCopperStairs
+        | PolishedAndesiteStairs
+        | PolishedBlackstoneBrickStairs
+        | PolishedBlackstoneStairs
+        | PolishedDeepslateStairs
+        | PolishedDioriteStairs
+        | PolishedGraniteStairs
+        | PrismarineBrickStairs
+        | PrismarineStairs
+        | PurpurStairs
+        | QuartzStairs
+        | RedNetherBrickStairs
+        | RedSandstoneStairs
+        | SandstoneStairs
+        | SmoothQuartzStairs
+        | SmoothRedSandstoneStairs
+        | SmoothSandstoneStairs
+        | SpruceStairs
+        | StoneBrickStairs
+        | StoneStairs
+        | WarpedStairs
+        | WaxedCutCopperStairs
+        | WaxedExposedCutCopperStairs
+        | WaxedOxidizedCutCopperStairs
+        | WaxedWeatheredCutCopperStairs
+        | WeatheredCutCopperStairs => stairs(get(block), get(block), get(block)),
+        AcaciaFence | BirchFence | CrimsonFence | DarkOakFence | JungleFence | NetherBrickFence
+        | OakFence | SpruceFence | WarpedFence => fence(block, 2.0, 0.0, 16.0, 16.0),
+        BlackStainedGlassPane
+        | BlueStainedGlassPane
+        | BrownStainedGlassPane
+        | CyanStainedGlassPane
+        | GlassPane
+        | GrayStainedGlassPane
+        | GreenStainedGlassPane
+        | LightBlueStainedGlassPane
+        | LightGrayStainedGlassPane
+        | LimeStainedGlassPane
+        | MagentaStainedGlassPane
+        | OrangeStainedGlassPane
+        | PinkStainedGlassPane
+        | PurpleStainedGlassPane
+        | RedStainedGlassPane
+        | WhiteStainedGlassPane
+        | YellowStainedGlassPane
+        | IronBars => pane(block),
+        AndesiteWall
+        | BlackstoneWall
+        | BrickWall
+        | CobbledDeepslateWall
+        | CobblestoneWall
+        | DeepslateBrickWall
+        | DeepslateTileWall
+        | DioriteWall
+        | EndStoneBrickWall
+        | GraniteWall
+        | MossyCobblestoneWall
+        | MossyStoneBrickWall
+        | NetherBrickWall
+        | PolishedBlackstoneBrickWall
+        | PolishedBlackstoneWall
+        | PolishedDeepslateWall
+        | PrismarineWall
+        | RedNetherBrickWall
+        | RedSandstoneWall
+        | SandstoneWall
+        | StoneBrickWall => wall(block, 16.0, 14.0, 16.0),
+        AcaciaFenceGate | BirchFenceGate | CrimsonFenceGate | DarkOakFenceGate
+        | JungleFenceGate | OakFenceGate | SpruceFenceGate | WarpedFenceGate => {
+            let height = if get::<state::InWall>(block).0 {
+                13.0
+            } else {
+                16.0
+            };
+
+            fence_gate(get(block), height)
+        }
+        AcaciaDoor | BirchDoor | CrimsonDoor | DarkOakDoor | IronDoor | JungleDoor | OakDoor
+        | SpruceDoor | WarpedDoor => door(get(block), get(block), get(block)),
+        AcaciaTrapdoor | BirchTrapdoor | CrimsonTrapdoor | DarkOakTrapdoor | IronTrapdoor
+        | JungleTrapdoor | OakTrapdoor | SpruceTrapdoor | WarpedTrapdoor => {
+            trapdoor(get(block), get(block), get(block))
+        }
+        BlackCarpet | BlueCarpet | BrownCarpet | CyanCarpet | GrayCarpet | GreenCarpet
+        | LightBlueCarpet | LightGrayCarpet | LimeCarpet | MagentaCarpet | MossCarpet
+        | OrangeCarpet | PinkCarpet | PurpleCarpet | RedCarpet | WhiteCarpet | YellowCarpet => {
+            px(0.0, 0.0, 0.0, 16.0, 1.0, 16.0).into()
+        }
+        Snow => {
+            let height = f64::from(get::<state::Layers>(block).0.get()) * 2.0;
+
+            px(0.0, 0.0, 0.0, 16.0, height, 16.0).into()
+        }
+        Farmland | DirtPath => px(0.0, 0.0, 0.0, 16.0, 15.0, 16.0).into(),
+        Scaffolding => {
+            if get::<state::Bottom>(block).0 {
+                scaffolding().union(&px(0.0, 0.0, 0.0, 16.0, 2.0, 16.0).into())
+            } else {
+                scaffolding()
+            }
+        }
+        Ladder => px(0.0, 0.0, 13.0, 16.0, 16.0, 16.0)
+            .horizontal(get(block))
+            .into(),
+        Chain => match get::<state::Axis>(block) {
+            state::Axis::X => px(0.0, 6.5, 6.5, 16.0, 9.5, 9.5).into(),
+            state::Axis::Y => px(6.5, 0.0, 6.5, 9.5, 16.0, 9.5).into(),
+            state::Axis::Z => px(6.5, 6.5, 0.0, 9.5, 9.5, 16.0).into(),
+        },
+        Piston | StickyPiston => {
+            if get::<state::Extended>(block).0 {
+                px(0.0, 0.0, 0.0, 16.0, 12.0, 16.0)
+                    .directional(get(block))
+                    .into()
+            } else {
+                VoxelShape::FULL
+            }
+        }
+        PistonHead => {
+            let facing = get(block);
+            let arm_bottom = if get::<state::Short>(block).0 {
+                0.0
+            } else {
+                -4.0
+            };
+
+            VoxelShape::new([
+                px(0.0, 12.0, 0.0, 16.0, 16.0, 16.0).directional(facing),
+                px(6.0, arm_bottom, 6.0, 10.0, 12.0, 10.0).directional(facing),
+            ])
+        }
+
+        // Decoration blocks
+        AcaciaSign | BirchSign | CrimsonSign | DarkOakSign | JungleSign | OakSign | SpruceSign
+        | WarpedSign | BlackBanner | BlueBanner | BrownBanner | CyanBanner | GrayBanner
+        | GreenBanner | LightBlueBanner | LightGrayBanner | LimeBanner | MagentaBanner
+        | OrangeBanner | PinkBanner | PurpleBanner | RedBanner | WhiteBanner | YellowBanner => {
+            px(4.0, 0.0, 4.0, 12.0, 16.0, 12.0).into()
+        }
+        AcaciaWallSign | BirchWallSign | CrimsonWallSign | DarkOakWallSign | JungleWallSign
+        | OakWallSign | SpruceWallSign | WarpedWallSign => px(0.0, 4.5, 14.0, 16.0, 12.5, 16.0)
+            .horizontal(get(block))
+            .into(),
+        BlackWallBanner | BlueWallBanner | BrownWallBanner | CyanWallBanner | GrayWallBanner
+        | GreenWallBanner | LightBlueWallBanner | LightGrayWallBanner | LimeWallBanner
+        | MagentaWallBanner | OrangeWallBanner | PinkWallBanner | PurpleWallBanner
+        | RedWallBanner | WhiteWallBanner | YellowWallBanner => {
+            px(0.0, 0.0, 14.0, 16.0, 12.5, 16.0)
+                .horizontal(get(block))
+                .into()
+        }
+        CreeperHead | DragonHead | PlayerHead | SkeletonSkull | WitherSkeletonSkull
+        | ZombieHead => px(4.0, 0.0, 4.0, 12.0, 8.0, 12.0).into(),
+        CreeperWallHead
+        | DragonWallHead
+        | PlayerWallHead
+        | SkeletonWallSkull
+        | WitherSkeletonWallSkull
+        | ZombieWallHead => px(4.0, 4.0, 8.0, 12.0, 12.0, 16.0)
+            .horizontal(get(block))
+            .into(),
+        BlackBed | BlueBed | BrownBed | CyanBed | GrayBed | GreenBed | LightBlueBed
+        | LightGrayBed | LimeBed | MagentaBed | OrangeBed | PinkBed | PurpleBed | RedBed
+        | WhiteBed | YellowBed => {
+            let facing = get::<HorizontalFacing>(block);
+            let direction = match get::<state::BedPart>(block) {
+                state::BedPart::Head => facing as u32,
+                state::BedPart::Foot => facing as u32 + 2,
+            };
+
+            VoxelShape::new([
+                px(0.0, 3.0, 0.0, 16.0, 9.0, 16.0),
+                px(0.0, 0.0, 0.0, 3.0, 3.0, 3.0).rotate_y(direction),
+                px(13.0, 0.0, 0.0, 16.0, 3.0, 3.0).rotate_y(direction),
+            ])
+        }
+        Chest | TrappedChest => {
+            let facing = get::<HorizontalFacing>(block) as u32;
+
+            match get::<state::ChestType>(block) {
+                state::ChestType::Single => px(1.0, 0.0, 1.0, 15.0, 14.0, 15.0).into(),
+                state::ChestType::Left => px(1.0, 0.0, 0.0, 15.0, 14.0, 15.0)
+                    .rotate_y(facing + 1)
+                    .into(),
+                state::ChestType::Right => px(1.0, 0.0, 0.0, 15.0, 14.0, 15.0)
+                    .rotate_y(facing + 3)
+                    .into(),
+            }
+        }
+        EnderChest => px(1.0, 0.0, 1.0, 15.0, 14.0, 15.0).into(),
+        BlackCandle | BlueCandle | BrownCandle | Candle | CyanCandle | GrayCandle | GreenCandle
+        | LightBlueCandle | LightGrayCandle | LimeCandle | MagentaCandle | OrangeCandle
+        | PinkCandle | PurpleCandle | RedCandle | WhiteCandle | YellowCandle => {
+            match get::<state::Candles>(block).0.get() {
+                1 => px(7.0, 0.0, 7.0, 9.0, 6.0, 9.0).into(),
+                2 => px(5.0, 0.0, 6.0, 11.0, 6.0, 9.0).into(),
+                3 => px(5.0, 0.0, 6.0, 10.0, 6.0, 11.0).into(),
+                _ => px(5.0, 0.0, 5.0, 11.0, 6.0, 10.0).into(),
+            }
+        }
+        Cake => {
+            let min_x = 1.0 + f64::from(get::<state::Bites>(block).0.get()) * 2.0;
+
+            px(min_x, 0.0, 1.0, 15.0, 8.0, 15.0).into()
+        }
+        BlackCandleCake | BlueCandleCake | BrownCandleCake | CandleCake | CyanCandleCake
+        | GrayCandleCake | GreenCandleCake | LightBlueCandleCake | LightGrayCandleCake
+        | LimeCandleCake | MagentaCandleCake | OrangeCandleCake | PinkCandleCake
+        | PurpleCandleCake | RedCandleCake | WhiteCandleCake | YellowCandleCake => {
+            VoxelShape::new([
+                px(1.0, 0.0, 1.0, 15.0, 8.0, 15.0),
+                px(7.0, 8.0, 7.0, 9.0, 14.0, 9.0),
+            ])
+        }
+        FlowerPot
+        | PottedAcaciaSapling
+        | PottedAllium
+        | PottedAzaleaBush
+        | PottedAzureBluet
+        | PottedBamboo
+        | PottedBirchSapling
+        | PottedBlueOrchid
+        | PottedBrownMushroom
+        | PottedCactus
+        | PottedCornflower
+        | PottedCrimsonFungus
+        | PottedCrimsonRoots
+        | PottedDandelion
+        | PottedDarkOakSapling
+        | PottedDeadBush
+        | PottedFern
+        | PottedFloweringAzaleaBush
+        | PottedJungleSapling
+        | PottedLilyOfTheValley
+        | PottedOakSapling
+        | PottedOrangeTulip
+        | PottedOxeyeDaisy
+        | PottedPinkTulip
+        | PottedPoppy
+        | PottedRedMushroom
+        | PottedRedTulip
+        | PottedSpruceSapling
+        | PottedWarpedFungus
+        | PottedWarpedRoots
+        | PottedWhiteTulip
+        | PottedWitherRose => px(5.0, 0.0, 5.0, 11.0, 6.0, 11.0).into(),
+        Torch | SoulTorch | RedstoneTorch => px(6.0, 0.0, 6.0, 10.0, 10.0, 10.0).into(),
+        WallTorch | SoulWallTorch | RedstoneWallTorch => px(5.5, 3.0, 11.0, 10.5, 13.0, 16.0)
+            .horizontal(get(block))
+            .into(),
+        Lantern | SoulLantern => {
+            if get::<state::Hanging>(block).0 {
+                VoxelShape::new([
+                    px(5.0, 1.0, 5.0, 11.0, 8.0, 11.0),
+                    px(6.0, 8.0, 6.0, 10.0, 10.0, 10.0),
+                ])
+            } else {
+                VoxelShape::new([
+                    px(5.0, 0.0, 5.0, 11.0, 7.0, 11.0),
+                    px(6.0, 7.0, 6.0, 10.0, 9.0, 10.0),
+                ])
+            }
+        }
+        Campfire | SoulCampfire => px(0.0, 0.0, 0.0, 16.0, 7.0, 16.0).into(),
+        EndRod | LightningRod => px(6.0, 0.0, 6.0, 10.0, 16.0, 10.0)
+            .directional(get(block))
+            .into(),
+        Conduit => px(5.0, 5.0, 5.0, 11.0, 11.0, 11.0).into(),
+        DragonEgg => px(1.0, 0.0, 1.0, 15.0, 16.0, 15.0).into(),
+        SeaPickle => match get::<state::Pickles>(block).0.get() {
+            1 => px(6.0, 0.0, 6.0, 10.0, 6.0, 10.0).into(),
+            2 => px(3.0, 0.0, 3.0, 13.0, 6.0, 13.0).into(),
+            3 => px(2.0, 0.0, 2.0, 14.0, 6.0, 14.0).into(),
+            _ => px(2.0, 0.0, 2.0, 14.0, 7.0, 14.0).into(),
+        },
+        TurtleEgg => {
+            if get::<state::Eggs>(block).0.get() == 1 {
+                px(3.0, 0.0, 3.0, 12.0, 7.0, 12.0).into()
+            } else {
+                px(1.0, 0.0, 1.0, 15.0, 7.0, 15.0).into()
+            }
+        }
+        AmethystCluster => amethyst(get(block), 7.0, 3.0),
+        LargeAmethystBud => amethyst(get(block), 5.0, 3.0),
+        MediumAmethystBud => amethyst(get(block), 4.0, 3.0),
+        SmallAmethystBud => amethyst(get(block), 3.0, 4.0),
+        PointedDripstone => match get::<state::DripstoneThickness>(block) {
+            state::DripstoneThickness::TipMerge => px(5.0, 0.0, 5.0, 11.0, 16.0, 11.0).into(),
+            state::DripstoneThickness::Tip => match get::<state::VerticalDirection>(block) {
+                state::VerticalDirection::Up => px(5.0, 0.0, 5.0, 11.0, 11.0, 11.0).into(),
+                state::VerticalDirection::Down => px(5.0, 5.0, 5.0, 11.0, 16.0, 11.0).into(),
+            },
+            state::DripstoneThickness::Frustum => px(4.0, 0.0, 4.0, 12.0, 16.0, 12.0).into(),
+            state::DripstoneThickness::Middle => px(3.0, 0.0, 3.0, 13.0, 16.0, 13.0).into(),
+            state::DripstoneThickness::Base => px(2.0, 0.0, 2.0, 14.0, 16.0, 14.0).into(),
+        },
+
+        // Functional blocks
+        Anvil | ChippedAnvil | DamagedAnvil => match get::<HorizontalFacing>(block) {
+            HorizontalFacing::East | HorizontalFacing::West => VoxelShape::new([
+                px(2.0, 0.0, 2.0, 14.0, 4.0, 14.0),
+                px(3.0, 4.0, 4.0, 13.0, 5.0, 12.0),
+                px(4.0, 5.0, 6.0, 12.0, 10.0, 10.0),
+                px(0.0, 10.0, 3.0, 16.0, 16.0, 13.0),
+            ]),
+            HorizontalFacing::North | HorizontalFacing::South => VoxelShape::new([
+                px(2.0, 0.0, 2.0, 14.0, 4.0, 14.0),
+                px(4.0, 4.0, 3.0, 12.0, 5.0, 13.0),
+                px(6.0, 5.0, 4.0, 10.0, 10.0, 12.0),
+                px(3.0, 10.0, 0.0, 13.0, 16.0, 16.0),
+            ]),
+        },
+        Bell => bell(get(block), get(block)),
+        BrewingStand => VoxelShape::new([
+            px(1.0, 0.0, 1.0, 15.0, 2.0, 15.0),
+            px(7.0, 0.0, 7.0, 9.0, 14.0, 9.0),
+        ]),
+        Cauldron | WaterCauldron | LavaCauldron | PowderSnowCauldron => {
+            VoxelShape::FULL.subtract(&VoxelShape::new([
+                px(0.0, 0.0, 4.0, 16.0, 3.0, 12.0),
+                px(4.0, 0.0, 0.0, 12.0, 3.0, 16.0),
+                px(2.0, 0.0, 2.0, 14.0, 3.0, 14.0),
+                px(2.0, 4.0, 2.0, 14.0, 16.0, 14.0),
+            ]))
+        }
+        Composter => composter(get::<state::LevelComposter>(block).0.get()),
+        DaylightDetector => px(0.0, 0.0, 0.0, 16.0, 6.0, 16.0).into(),
+        EnchantingTable => px(0.0, 0.0, 0.0, 16.0, 12.0, 16.0).into(),
+        EndPortalFrame => {
+            let frame = VoxelShape::from(px(0.0, 0.0, 0.0, 16.0, 13.0, 16.0));
+
+            if get::<state::Eye>(block).0 {
+                frame.union(&px(4.0, 13.0, 4.0, 12.0, 16.0, 12.0).into())
+            } else {
+                frame
+            }
+        }
+        Grindstone => grindstone(get(block), get(block)),
+        Hopper => {
+            let spout = match get::<state::HopperFacing>(block) {
+                state::HopperFacing::Down => px(6.0, 0.0, 6.0, 10.0, 4.0, 10.0),
+                state::HopperFacing::North => px(6.0, 4.0, 0.0, 10.0, 8.0, 4.0),
+                state::HopperFacing::East => px(12.0, 4.0, 6.0, 16.0, 8.0, 10.0),
+                state::HopperFacing::South => px(6.0, 4.0, 12.0, 10.0, 8.0, 16.0),
+                state::HopperFacing::West => px(0.0, 4.0, 6.0, 4.0, 8.0, 10.0),
+            };
+
+            VoxelShape::new([
+                px(0.0, 10.0, 0.0, 16.0, 16.0, 16.0),
+                px(4.0, 4.0, 4.0, 12.0, 10.0, 12.0),
+            ])
+            .subtract(&px(2.0, 11.0, 2.0, 14.0, 16.0, 14.0).into())
+            .union(&spout.into())
+        }
+        Lectern => {
+            let facing = get(block);
+
+            lectern_base().union(&VoxelShape::new([
+                px(0.0, 10.0, 1.0, 16.0, 14.0, 5.333_333).horizontal(facing),
+                px(0.0, 12.0, 5.333_333, 16.0, 16.0, 9.666_667).horizontal(facing),
+                px(0.0, 14.0, 9.666_667, 16.0, 18.0, 14.0).horizontal(facing),
+            ]))
+        }
+        SculkSensor => px(0.0, 0.0, 0.0, 16.0, 8.0, 16.0).into(),
+        Stonecutter => px(0.0, 0.0, 0.0, 16.0, 9.0, 16.0).into(),
+
+        // Redstone components
+        RedstoneWire => redstone_wire(block),
+        Repeater | Comparator => px(0.0, 0.0, 0.0, 16.0, 2.0, 16.0).into(),
+        Lever => match get::<state::AttachFace>(block) {
+            state::AttachFace::Floor => match get::<HorizontalFacing>(block) {
+                HorizontalFacing::North | HorizontalFacing::South => {
+                    px(5.0, 0.0, 4.0, 11.0, 6.0, 12.0).into()
+                }
+                HorizontalFacing::East | HorizontalFacing::West => {
+                    px(4.0, 0.0, 5.0, 12.0, 6.0, 11.0).into()
+                }
+            },
+            state::AttachFace::Wall => px(5.0, 4.0, 10.0, 11.0, 12.0, 16.0)
+                .horizontal(get(block))
+                .into(),
+            state::AttachFace::Ceiling => match get::<HorizontalFacing>(block) {
+                HorizontalFacing::North | HorizontalFacing::South => {
+                    px(5.0, 10.0, 4.0, 11.0, 16.0, 12.0).into()
+                }
+                HorizontalFacing::East | HorizontalFacing::West => {
+                    px(4.0, 10.0, 5.0, 12.0, 16.0, 11.0).into()
+                }
+            },
+        },
+        AcaciaButton
+        | BirchButton
+        | CrimsonButton
+        | DarkOakButton
+        | JungleButton
+        | OakButton
+        | PolishedBlackstoneButton
+        | SpruceButton
+        | StoneButton
+        | WarpedButton => button(get(block), get(block), get(block)),
+        AcaciaPressurePlate
+        | BirchPressurePlate
+        | CrimsonPressurePlate
+        | DarkOakPressurePlate
+        | JunglePressurePlate
+        | OakPressurePlate
+        | PolishedBlackstonePressurePlate
+        | SprucePressurePlate
+        | StonePressurePlate
+        | WarpedPressurePlate => pressure_plate(get::<state::Powered>(block).0),
+        LightWeightedPressurePlate | HeavyWeightedPressurePlate => {
+            pressure_plate(get::<state::Power>(block).0.get() > 0)
+        }
+        TripwireHook => px(5.0, 0.0, 10.0, 11.0, 10.0, 16.0)
+            .horizontal(get(block))
+            .into(),
+        Tripwire => {
+            if get::<state::Attached>(block).0 {
+                px(0.0, 1.0, 0.0, 16.0, 2.5, 16.0).into()
+            } else {
+                px(0.0, 0.0, 0.0, 16.0, 8.0, 16.0).into()
+            }
+        }
+        Rail => {
+            let ascending = matches!(
+                get::<state::RailShape>(block),
+                state::RailShape::AscendingEast
+                    | state::RailShape::AscendingWest
+                    | state::RailShape::AscendingNorth
+                    | state::RailShape::AscendingSouth,
+            );
+
+            rail(ascending)
+        }
+        PoweredRail | DetectorRail | ActivatorRail => {
+            let ascending = matches!(
+                get::<state::StraightRailShape>(block),
+                state::StraightRailShape::AscendingEast
+                    | state::StraightRailShape::AscendingWest
+                    | state::StraightRailShape::AscendingNorth
+                    | state::StraightRailShape::AscendingSouth,
+            );
+
+            rail(ascending)
+        }
+
+        // Plants
+        Dandelion | Poppy | BlueOrchid | Allium | AzureBluet | RedTulip | OrangeTulip
+        | WhiteTulip | PinkTulip | OxeyeDaisy | Cornflower | LilyOfTheValley | WitherRose => {
+            px(5.0, 0.0, 5.0, 11.0, 10.0, 11.0).into()
+        }
+        AcaciaSapling | BirchSapling | DarkOakSapling | JungleSapling | OakSapling
+        | SpruceSapling => px(2.0, 0.0, 2.0, 14.0, 12.0, 14.0).into(),
+        Grass | Fern | DeadBush | CrimsonRoots | WarpedRoots | SmallDripleaf => {
+            px(2.0, 0.0, 2.0, 14.0, 13.0, 14.0).into()
+        }
+        NetherSprouts => px(2.0, 0.0, 2.0, 14.0, 3.0, 14.0).into(),
+        BrownMushroom | RedMushroom => px(5.0, 0.0, 5.0, 11.0, 6.0, 11.0).into(),
+        CrimsonFungus | WarpedFungus => px(4.0, 0.0, 4.0, 12.0, 9.0, 12.0).into(),
+        Seagrass => px(2.0, 0.0, 2.0, 14.0, 12.0, 14.0).into(),
+        TallSeagrass | SugarCane => px(2.0, 0.0, 2.0, 14.0, 16.0, 14.0).into(),
+        BrainCoral | BubbleCoral | DeadBrainCoral | DeadBubbleCoral | DeadFireCoral
+        | DeadHornCoral | DeadTubeCoral | FireCoral | HornCoral | TubeCoral => {
+            px(2.0, 0.0, 2.0, 14.0, 15.0, 14.0).into()
+        }
+        BrainCoralFan | BubbleCoralFan | DeadBrainCoralFan | DeadBubbleCoralFan
+        | DeadFireCoralFan | DeadHornCoralFan | DeadTubeCoralFan | FireCoralFan | HornCoralFan
+        | TubeCoralFan => px(2.0, 0.0, 2.0, 14.0, 4.0, 14.0).into(),
+        BrainCoralWallFan
+        | BubbleCoralWallFan
+        | DeadBrainCoralWallFan
+        | DeadBubbleCoralWallFan
+        | DeadFireCoralWallFan
+        | DeadHornCoralWallFan
+        | DeadTubeCoralWallFan
+        | FireCoralWallFan
+        | HornCoralWallFan
+        | TubeCoralWallFan => px(0.0, 4.0, 5.0, 16.0, 12.0, 16.0)
+            .horizontal(get(block))
+            .into(),
+        Wheat => {
+            let height = f64::from(get::<state::Age7>(block).0.get() + 1) * 2.0;
+
+            px(0.0, 0.0, 0.0, 16.0, height, 16.0).into()
+        }
+        Carrots | Potatoes => {
+            let height = f64::from(get::<state::Age7>(block).0.get() + 2);
+
+            px(0.0, 0.0, 0.0, 16.0, height, 16.0).into()
+        }
+        Beetroots => {
+            let height = f64::from(get::<state::Age3>(block).0.get() + 1) * 2.0;
+
+            px(0.0, 0.0, 0.0, 16.0, height, 16.0).into()
+        }
+        NetherWart => {
+            let height = f64::from(get::<state::Age3>(block).0.get()) * 3.0 + 5.0;
+
+            px(0.0, 0.0, 0.0, 16.0, height, 16.0).into()
+        }
+        MelonStem | PumpkinStem => {
+            let height = f64::from(get::<state::Age7>(block).0.get() + 1) * 2.0;
+
+            px(7.0, 0.0, 7.0, 9.0, height, 9.0).into()
+        }
+        AttachedMelonStem | AttachedPumpkinStem => px(6.0, 0.0, 0.0, 10.0, 10.0, 10.0)
+            .horizontal(get(block))
+            .into(),
+        SweetBerryBush => match get::<state::Age3>(block).0.get() {
+            0 => px(3.0, 0.0, 3.0, 13.0, 8.0, 13.0).into(),
+            1 | 2 => px(1.0, 0.0, 1.0, 15.0, 16.0, 15.0).into(),
+            _ => VoxelShape::FULL,
+        },
+        Cocoa => {
+            let aabb = match get::<state::Age2>(block).0.get() {
+                0 => px(6.0, 7.0, 1.0, 10.0, 12.0, 5.0),
+                1 => px(5.0, 5.0, 1.0, 11.0, 12.0, 7.0),
+                _ => px(4.0, 3.0, 1.0, 12.0, 12.0, 9.0),
+            };
+
+            aabb.horizontal(get(block)).into()
+        }
+        Cactus => px(1.0, 0.0, 1.0, 15.0, 16.0, 15.0).into(),
+        Bamboo => match get::<state::BambooLeaves>(block) {
+            state::BambooLeaves::Large => px(3.0, 0.0, 3.0, 13.0, 16.0, 13.0).into(),
+            state::BambooLeaves::None | state::BambooLeaves::Small => {
+                px(5.0, 0.0, 5.0, 11.0, 16.0, 11.0).into()
+            }
+        },
+        BambooSapling => px(4.0, 0.0, 4.0, 12.0, 12.0, 12.0).into(),
+        Kelp => px(0.0, 0.0, 0.0, 16.0, 9.0, 16.0).into(),
+        WeepingVines => px(4.0, 9.0, 4.0, 12.0, 16.0, 12.0).into(),
+        TwistingVines => px(4.0, 0.0, 4.0, 12.0, 15.0, 12.0).into(),
+        TwistingVinesPlant => px(4.0, 0.0, 4.0, 12.0, 16.0, 12.0).into(),
+        WeepingVinesPlant | CaveVines | CaveVinesPlant => {
+            px(1.0, 0.0, 1.0, 15.0, 16.0, 15.0).into()
+        }
+        LilyPad => px(1.0, 0.0, 1.0, 15.0, 1.5, 15.0).into(),
+        HangingRoots => px(2.0, 10.0, 2.0, 14.0, 16.0, 14.0).into(),
+        SporeBlossom => px(2.0, 13.0, 2.0, 14.0, 16.0, 14.0).into(),
+        Azalea | FloweringAzalea => VoxelShape::new([
+            px(0.0, 8.0, 0.0, 16.0, 16.0, 16.0),
+            px(6.0, 0.0, 6.0, 10.0, 8.0, 10.0),
+        ]),
+        BigDripleaf => {
+            let stem = px(5.0, 0.0, 8.0, 11.0, 11.0, 16.0).horizontal(get(block));
+            let leaf = match get::<state::Tilt>(block) {
+                state::Tilt::None | state::Tilt::Unstable => px(0.0, 11.0, 0.0, 16.0, 15.0, 16.0),
+                state::Tilt::Partial => px(0.0, 11.0, 0.0, 16.0, 13.0, 16.0),
+                state::Tilt::Full => return stem.into(),
+            };
+
+            VoxelShape::new([leaf, stem])
+        }
+        BigDripleafStem => px(5.0, 0.0, 9.0, 11.0, 16.0, 15.0)
+            .horizontal(get(block))
+            .into(),
+        ChorusPlant => {
+            let sides = [
+                (
+                    get::<state::North>(block).0,
+                    px(3.0, 3.0, 0.0, 13.0, 13.0, 3.0),
+                ),
+                (
+                    get::<state::East>(block).0,
+                    px(13.0, 3.0, 3.0, 16.0, 13.0, 13.0),
+                ),
+                (
+                    get::<state::South>(block).0,
+                    px(3.0, 3.0, 13.0, 13.0, 13.0, 16.0),
+                ),
+                (
+                    get::<state::West>(block).0,
+                    px(0.0, 3.0, 3.0, 3.0, 13.0, 13.0),
+                ),
+                (
+                    get::<state::Up>(block).0,
+                    px(3.0, 13.0, 3.0, 13.0, 16.0, 13.0),
+                ),
+                (
+                    get::<state::Down>(block).0,
+                    px(3.0, 0.0, 3.0, 13.0, 3.0, 13.0),
+                ),
+            ];
+
+            VoxelShape::new(
+                sides
+                    .into_iter()
+                    .filter_map(|(set, aabb)| set.then_some(aabb))
+                    .chain([px(3.0, 3.0, 3.0, 13.0, 13.0, 13.0)]),
+            )
+        }
+        Vine | GlowLichen => {
+            let sides = [
+                (
+                    get::<state::North>(block).0,
+                    px(0.0, 0.0, 0.0, 16.0, 16.0, 1.0),
+                ),
+                (
+                    get::<state::East>(block).0,
+                    px(15.0, 0.0, 0.0, 16.0, 16.0, 16.0),
+                ),
+                (
+                    get::<state::South>(block).0,
+                    px(0.0, 0.0, 15.0, 16.0, 16.0, 16.0),
+                ),
+                (
+                    get::<state::West>(block).0,
+                    px(0.0, 0.0, 0.0, 1.0, 16.0, 16.0),
+                ),
+                (
+                    get::<state::Up>(block).0,
+                    px(0.0, 15.0, 0.0, 16.0, 16.0, 16.0),
+                ),
+                (
+                    block.get::<state::Down>().is_some_and(|down| down.0),
+                    px(0.0, 0.0, 0.0, 16.0, 1.0, 16.0),
+                ),
+            ];
+
+            VoxelShape::new(
+                sides
+                    .into_iter()
+                    .filter_map(|(set, aabb)| set.then_some(aabb)),
+            )
+        }
+
+        _ => VoxelShape::FULL,
+    }
+}
+
+fn collision(block: Block, outline: &VoxelShape) -> VoxelShape {
+    use BlockId::*;
+
+    if !block.behavior().has_collision {
+        return VoxelShape::EMPTY;
+    }
+
+    match block.id() {
+        Cactus | HoneyBlock => px(1.0, 0.0, 1.0, 15.0, 15.0, 15.0).into(),
+        SoulSand => px(0.0, 0.0, 0.0, 16.0, 14.0, 16.0).into(),
+        Snow => {
+            let height = f64::from(get::<state::Layers>(block).0.get() - 1) * 2.0;
+
+            px(0.0, 0.0, 0.0, 16.0, height, 16.0).into()
+        }
+        // Vanilla only lets entities collide with powder snow when they wear leather boots.
+        PowderSnow => VoxelShape::EMPTY,
+        Bamboo => px(6.5, 0.0, 6.5, 9.5, 16.0, 9.5).into(),
+        AcaciaFence | BirchFence | CrimsonFence | DarkOakFence | JungleFence | NetherBrickFence
+        | OakFence | SpruceFence | WarpedFence => fence(block, 2.0, 0.0, 24.0, 24.0),
+        AndesiteWall
+        | BlackstoneWall
+        | BrickWall
+        | CobbledDeepslateWall
+        | CobblestoneWall
+        | DeepslateBrickWall
+        | DeepslateTileWall
+        | DioriteWall
+        | EndStoneBrickWall
+        | GraniteWall
+        | MossyCobblestoneWall
+        | MossyStoneBrickWall
+        | NetherBrickWall
+        | PolishedBlackstoneBrickWall
+        | PolishedBlackstoneWall
+        | PolishedDeepslateWall
+        | PrismarineWall
+        | RedNetherBrickWall
+        | RedSandstoneWall
+        | SandstoneWall
+        | StoneBrickWall => wall(block, 24.0, 24.0, 24.0),
+        AcaciaFenceGate | BirchFenceGate | CrimsonFenceGate | DarkOakFenceGate
+        | JungleFenceGate | OakFenceGate | SpruceFenceGate | WarpedFenceGate => {
+            if get::<state::Open>(block).0 {
+                VoxelShape::EMPTY
+            } else {
+                fence_gate(get(block), 24.0)
+            }
+        }
+        Lectern => lectern_base(),
+        Composter => composter(0),
+        // Vanilla only lets entities collide with the top of scaffolding when they are above it.
+        Scaffolding => scaffolding(),
+        _ => outline.clone(),
+    }
+}
+
+fn occlusion(block: Block, outline: &VoxelShape) -> VoxelShape {
+    use BlockId::*;
+
+    if !block.behavior().can_occlude {
+        return VoxelShape::EMPTY;
+    }
+
+    match block.id() {
+        AcaciaFence | BirchFence | CrimsonFence | DarkOakFence | JungleFence | NetherBrickFence
+        | OakFence | SpruceFence | WarpedFence => fence(block, 1.0, 6.0, 15.0, 16.0),
+        _ => outline.clone(),
+    }
+}
+
+/// Returns the shape of a fence with the given arm width, arm bottom, arm top and post height, in
+/// pixels.
+fn fence(
+    block: Block,
+    arm_width: f64,
+    arm_bottom: f64,
+    arm_top: f64,
+    post_height: f64,
+) -> VoxelShape {
+    let sides = [
+        get::<state::North>(block).0,
+        get::<state::East>(block).0,
+        get::<state::South>(block).0,
+        get::<state::West>(block).0,
+    ];
+
+    cross(sides, 2.0, arm_width, arm_bottom, arm_top, post_height)
+}
+
+fn pane(block: Block) -> VoxelShape {
+    let sides = [
+        get::<state::North>(block).0,
+        get::<state::East>(block).0,
+        get::<state::South>(block).0,
+        get::<state::West>(block).0,
+    ];
+
+    cross(sides, 1.0, 1.0, 0.0, 16.0, 16.0)
+}
+
+/// Returns the shape of a post with arms extending towards the given sides, in the order north,
+/// east, south, west. The dimensions are in pixels.
+fn cross(
+    sides: [bool; 4],
+    post_width: f64,
+    arm_width: f64,
+    arm_bottom: f64,
+    arm_top: f64,
+    post_height: f64,
+) -> VoxelShape {
+    let post = px(
+        8.0 - post_width,
+        0.0,
+        8.0 - post_width,
+        8.0 + post_width,
+        post_height,
+        8.0 + post_width,
+    );
+    let arm = px(
+        8.0 - arm_width,
+        arm_bottom,
+        0.0,
+        8.0 + arm_width,
+        arm_top,
+        8.0 + arm_width,
+    );
+
+    VoxelShape::new(
+        (0..4)
+            .filter(|&side| sides[side as usize])
+            .map(|side| arm.rotate_y(side))
+            .chain([post]),
+    )
+}
+
+/// Returns the shape of a wall with the given post height and the heights of low and tall sides,
+/// in pixels.
+fn wall(block: Block, post_height: f64, low_height: f64, tall_height: f64) -> VoxelShape {
+    let post = get::<state::Up>(block)
+        .0
+        .then_some(px(4.0, 0.0, 4.0, 12.0, post_height, 12.0));
+    let sides = ["north", "east", "south", "west"].into_iter().zip(0..);
+
+    VoxelShape::new(
+        sides
+            .filter_map(|(name, side)| {
+                let height = match block.property_named::<state::WallSide>(name)? {
+                    state::WallSide::None => return None,
+                    state::WallSide::Low => low_height,
+                    state::WallSide::Tall => tall_height,
+                };
+
+                Some(px(5.0, 0.0, 0.0, 11.0, height, 11.0).rotate_y(side))
+            })
+            .chain(post),
+    )
+}
+
+fn fence_gate(facing: HorizontalFacing, height: f64) -> VoxelShape {
+    px(0.0, 0.0, 6.0, 16.0, height, 10.0)
+        .rotate_y(facing as u32 % 2)
+        .into()
+}
+
+fn stairs(facing: HorizontalFacing, half: state::Half, shape: state::StairsShape) -> VoxelShape {
+    // The steps of stairs facing north, with the bottom half.
+    let steps: &[Aabb] = match shape {
+        state::StairsShape::Straight => &[px(0.0, 8.0, 0.0, 16.0, 16.0, 8.0)],
+        state::StairsShape::InnerLeft => &[
+            px(0.0, 8.0, 0.0, 16.0, 16.0, 8.0),
+            px(0.0, 8.0, 8.0, 8.0, 16.0, 16.0),
+        ],
+        state::StairsShape::InnerRight => &[
+            px(0.0, 8.0, 0.0, 16.0, 16.0, 8.0),
+            px(8.0, 8.0, 8.0, 16.0, 16.0, 16.0),
+        ],
+        state::StairsShape::OuterLeft => &[px(0.0, 8.0, 0.0, 8.0, 16.0, 8.0)],
+        state::StairsShape::OuterRight => &[px(8.0, 8.0, 0.0, 16.0, 16.0, 8.0)],
+    };
+    let shape = VoxelShape::new(
+        steps
+            .iter()
+            .map(|step| step.horizontal(facing))
+            .chain([px(0.0, 0.0, 0.0, 16.0, 8.0, 16.0)]),
+    );
+
+    match half {
+        state::Half::Top => shape.map(Aabb::flip_y),
+        state::Half::Bottom => shape,
+    }
+}
+
+fn door(facing: HorizontalFacing, open: state::Open, hinge: state::DoorHingeSide) -> VoxelShape {
+    let quarter_turns = match (open.0, hinge) {
+        (false, _) => 0,
+        (true, state::DoorHingeSide::Left) => 1,
+        (true, state::DoorHingeSide::Right) => 3,
+    };
+
+    px(0.0, 0.0, 13.0, 16.0, 16.0, 16.0)
+        .rotate_y(facing as u32 + quarter_turns)
+        .into()
+}
+
+fn trapdoor(facing: HorizontalFacing, open: state::Open, half: state::Half) -> VoxelShape {
+    if open.0 {
+        px(0.0, 0.0, 13.0, 16.0, 16.0, 16.0)
+            .horizontal(facing)
+            .into()
+    } else {
+        match half {
+            state::Half::Top => px(0.0, 13.0, 0.0, 16.0, 16.0, 16.0).into(),
+            state::Half::Bottom => px(0.0, 0.0, 0.0, 16.0, 3.0, 16.0).into(),
+        }
+    }
+}
+
+fn button(
+    facing: HorizontalFacing,
+    powered: state::Powered,
+    face: state::AttachFace,
+) -> VoxelShape {
+    let depth = if powered.0 { 1.0 } else { 2.0 };
+    let floor = match facing {
+        HorizontalFacing::North | HorizontalFacing::South => px(5.0, 0.0, 6.0, 11.0, depth, 10.0),
+        HorizontalFacing::East | HorizontalFacing::West => px(6.0, 0.0, 5.0, 10.0, depth, 11.0),
+    };
+
+    match face {
+        state::AttachFace::Floor => floor.into(),
+        state::AttachFace::Wall => px(5.0, 6.0, 16.0 - depth, 11.0, 10.0, 16.0)
+            .horizontal(facing)
+            .into(),
+        state::AttachFace::Ceiling => floor.flip_y().into(),
+    }
+}
+
+fn pressure_plate(pressed: bool) -> VoxelShape {
+    let height = if pressed { 0.5 } else { 1.0 };
+
+    px(1.0, 0.0, 1.0, 15.0, height, 15.0).into()
+}
+
+fn rail(ascending: bool) -> VoxelShape {
+    let height = if ascending { 8.0 } else { 2.0 };
+
+    px(0.0, 0.0, 0.0, 16.0, height, 16.0).into()
+}
+
+fn redstone_wire(block: Block) -> VoxelShape {
+    let sides = ["north", "east", "south", "west"].into_iter().zip(0..);
+    let mut boxes = vec![px(3.0, 0.0, 3.0, 13.0, 1.0, 13.0)];
+
+    for (name, side) in sides {
+        match block.property_named::<state::RedstoneSide>(name) {
+            Some(state::RedstoneSide::Up) => {
+                boxes.push(px(3.0, 0.0, 0.0, 13.0, 1.0, 13.0).rotate_y(side));
+                boxes.push(px(3.0, 0.0, 0.0, 13.0, 16.0, 1.0).rotate_y(side));
+            }
+            Some(state::RedstoneSide::Side) => {
+                boxes.push(px(3.0, 0.0, 0.0, 13.0, 1.0, 13.0).rotate_y(side));
+            }
+            Some(state::RedstoneSide::None) | None => {}
+        }
+    }
+
+    VoxelShape::new(boxes)
+}
+
+fn amethyst(facing: Facing, height: f64, offset: f64) -> VoxelShape {
+    px(offset, 0.0, offset, 16.0 - offset, height, 16.0 - offset)
+        .directional(facing)
+        .into()
+}
+
+fn bell(facing: HorizontalFacing, attachment: state::BellAttachType) -> VoxelShape {
+    let north_south = matches!(facing, HorizontalFacing::North | HorizontalFacing::South);
+    let bar = match attachment {
+        state::BellAttachType::Floor => {
+            return if north_south {
+                px(0.0, 0.0, 4.0, 16.0, 16.0, 12.0).into()
+            } else {
+                px(4.0, 0.0, 0.0, 12.0, 16.0, 16.0).into()
+            };
+        }
+        state::BellAttachType::Ceiling => px(7.0, 13.0, 7.0, 9.0, 16.0, 9.0),
+        state::BellAttachType::SingleWall => px(7.0, 13.0, 0.0, 9.0, 15.0, 13.0).horizontal(facing),
+        state::BellAttachType::DoubleWall => {
+            px(7.0, 13.0, 0.0, 9.0, 15.0, 16.0).rotate_y(u32::from(!north_south))
+        }
+    };
+
+    VoxelShape::new([
+        px(5.0, 6.0, 5.0, 11.0, 13.0, 11.0),
+        px(4.0, 4.0, 4.0, 12.0, 6.0, 12.0),
+        bar,
+    ])
+}
+
+fn composter(level: u32) -> VoxelShape {
+    let bottom = f64::from(1 + level * 2).max(2.0);
+
+    VoxelShape::FULL.subtract(&px(2.0, bottom, 2.0, 14.0, 16.0, 14.0).into())
+}
+
+fn grindstone(facing: HorizontalFacing, face: state::AttachFace) -> VoxelShape {
+    let (boxes, quarter_turns) = match face {
+        state::AttachFace::Floor | state::AttachFace::Ceiling => (
+            [
+                px(2.0, 0.0, 6.0, 4.0, 7.0, 10.0),
+                px(12.0, 0.0, 6.0, 14.0, 7.0, 10.0),
+                px(2.0, 7.0, 5.0, 4.0, 13.0, 11.0),
+                px(12.0, 7.0, 5.0, 14.0, 13.0, 11.0),
+                px(4.0, 4.0, 2.0, 12.0, 16.0, 14.0),
+            ],
+            facing as u32 % 2,
+        ),
+        state::AttachFace::Wall => (
+            [
+                px(2.0, 6.0, 0.0, 4.0, 10.0, 9.0),
+                px(12.0, 6.0, 0.0, 14.0, 10.0, 9.0),
+                px(2.0, 5.0, 7.0, 4.0, 11.0, 13.0),
+                px(12.0, 5.0, 7.0, 14.0, 11.0, 13.0),
+                px(4.0, 2.0, 4.0, 12.0, 14.0, 16.0),
+            ],
+            facing as u32,
+        ),
+    };
+    let flip = face == state::AttachFace::Ceiling;
+
+    VoxelShape::new(boxes.map(|aabb| {
+        let aabb = aabb.rotate_y(quarter_turns);
+
+        if flip {
+            aabb.flip_y()
+        } else {
+            aabb
+        }
+    }))
+}
+
+fn lectern_base() -> VoxelShape {
+    VoxelShape::new([
+        px(0.0, 0.0, 0.0, 16.0, 2.0, 16.0),
+        px(4.0, 2.0, 4.0, 12.0, 14.0, 12.0),
+    ])
+}
+
+fn scaffolding() -> VoxelShape {
+    VoxelShape::new([
+        px(0.0, 14.0, 0.0, 16.0, 16.0, 16.0),
+        px(0.0, 0.0, 0.0, 2.0, 16.0, 2.0),
+        px(14.0, 0.0, 0.0, 16.0, 16.0, 2.0),
+        px(0.0, 0.0, 14.0, 2.0, 16.0, 16.0),
+        px(14.0, 0.0, 14.0, 16.0, 16.0, 16.0),
+    ])
+}
@@ -1,6 +1,10 @@
+//! Sounds of blocks, which mirror vanilla's `SoundType`.
+
 use world_sound::Event;
 
-#[derive(Debug)]
+/// The sounds a block makes when it's broken, stepped on, placed, hit or fallen on. Every field
+/// mirrors the vanilla field of the same name.
+#[derive(Clone, Debug)]
 pub struct Type {
     pub volume: f32,
     pub pitch: f32,
@@ -14,7 +14,16 @@ pub struct Definition {
     pub properties: &'static [PropertyDefinition],
 }
 
+impl Definition {
+    /// Returns the definition of the first property with the given ID, if any.
+    #[must_use]
+    pub fn property(&self, id: PropertyId) -> Option<&'static PropertyDefinition> {
+        self.properties.iter().find(|definition| definition.id == id)
+    }
+}
+
 /// Defines a state property of a particular block.
+#[derive(Clone, Copy, Debug)]
 pub struct PropertyDefinition {
     /// The name of the property.
     pub name: &'static str,
@@ -24,6 +33,19 @@ pub struct PropertyDefinition {
     pub offset: u32,
 }
 
+/// A type of block state property, such as [`Facing`] or [`Powered`]. This trait is automatically
+/// implemented by the `properties!` proc macro.
+pub trait PropertyValue: Copy + Into<Property> {
+    /// The ID of the property.
+    const ID: PropertyId;
+
+    /// Converts the bits stored in the block state to a property value.
+    fn from_bits(bits: u32) -> Option<Self>;
+
+    /// Converts the property value to the bits stored in the block state.
+    fn to_bits(self) -> u32;
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PropertyType {
     Boolean,
@@ -47,10 +69,11 @@ impl<const MIN: u32, const MAX: u32> Integer<MIN, MAX> {
     /// # Errors
     ///
     /// Returns `Err` when the `u32` is outside of the range of this type.
-    pub fn new(n: u32) -> Result<Self, OutOfRangeError> {
+    #[allow(clippy::manual_range_contains)] // `RangeInclusive::contains` is not `const`.
+    pub const fn new(n: u32) -> Result<Self, OutOfRangeError> {
         assert!(MAX > MIN);
 
-        if (MIN..=MAX).contains(&n) {
+        if n >= MIN && n <= MAX {
             Ok(Integer(n))
         } else {
             Err(OutOfRangeError {
@@ -60,6 +83,12 @@ impl<const MIN: u32, const MAX: u32> Integer<MIN, MAX> {
         }
     }
 
+    /// Returns the wrapped `u32`.
+    #[must_use]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
     /// Replaces the wrapped `u32` with the provided one.
     ///
     /// # Errors
@@ -141,44 +170,44 @@ properties! {
     // Booleans
     ///////////////////////////////////////////////////////////////////////////////////////////////
 
-    pub struct Attached(bool);
-    pub struct Berries(bool);
-    pub struct Bottom(bool);
-    pub struct Conditional(bool);
-    pub struct Disarmed(bool);
-    pub struct Drag(bool);
-    pub struct Enabled(bool);
-    pub struct Extended(bool);
-    pub struct Eye(bool);
-    pub struct Falling(bool);
-    pub struct Hanging(bool);
-    pub struct HasBottle0(bool);
-    pub struct HasBottle1(bool);
-    pub struct HasBottle2(bool);
-    pub struct HasRecord(bool);
-    pub struct HasBook(bool);
-    pub struct Inverted(bool);
-    pub struct InWall(bool);
-    pub struct Lit(bool);
-    pub struct Locked(bool);
-    pub struct Occupied(bool);
-    pub struct Open(bool);
-    pub struct Persistent(bool);
-    pub struct Powered(bool);
-    pub struct Short(bool);
-    pub struct SignalFire(bool);
-    pub struct Snowy(bool);
-    pub struct Triggered(bool);
-    pub struct Unstable(bool);
-    pub struct Waterlogged(bool);
-    pub struct VineEnd(bool);
-
-    pub struct Up(bool);
-    pub struct Down(bool);
-    pub struct North(bool);
-    pub struct East(bool);
-    pub struct South(bool);
-    pub struct West(bool);
+    pub struct Attached(pub bool);
+    pub struct Berries(pub bool);
+    pub struct Bottom(pub bool);
+    pub struct Conditional(pub bool);
+    pub struct Disarmed(pub bool);
+    pub struct Drag(pub bool);
+    pub struct Enabled(pub bool);
+    pub struct Extended(pub bool);
+    pub struct Eye(pub bool);
+    pub struct Falling(pub bool);
+    pub struct Hanging(pub bool);
+    pub struct HasBottle0(pub bool);
+    pub struct HasBottle1(pub bool);
+    pub struct HasBottle2(pub bool);
+    pub struct HasRecord(pub bool);
+    pub struct HasBook(pub bool);
+    pub struct Inverted(pub bool);
+    pub struct InWall(pub bool);
+    pub struct Lit(pub bool);
+    pub struct Locked(pub bool);
+    pub struct Occupied(pub bool);
+    pub struct Open(pub bool);
+    pub struct Persistent(pub bool);
+    pub struct Powered(pub bool);
+    pub struct Short(pub bool);
+    pub struct SignalFire(pub bool);
+    pub struct Snowy(pub bool);
+    pub struct Triggered(pub bool);
+    pub struct Unstable(pub bool);
+    pub struct Waterlogged(pub bool);
+    pub struct VineEnd(pub bool);
+
+    pub struct Up(pub bool);
+    pub struct Down(pub bool);
+    pub struct North(pub bool);
+    pub struct East(pub bool);
+    pub struct South(pub bool);
+    pub struct West(pub bool);
 
     ///////////////////////////////////////////////////////////////////////////////////////////////
     // Enums
@@ -222,8 +251,8 @@ properties! {
     pub enum ChestType {
         #[default]
         Single,
-        Right,
         Left,
+        Right,
     }
 
     pub enum ComparatorMode {
@@ -255,28 +284,28 @@ properties! {
 
     pub enum Facing {
         #[default]
-        Down,
-        Up,
         North,
+        East,
         South,
         West,
-        East,
+        Up,
+        Down,
     }
 
     pub enum FrontAndTop {
         #[default]
-        DownNorth,
         DownEast,
+        DownNorth,
         DownSouth,
         DownWest,
-        UpNorth,
         UpEast,
+        UpNorth,
         UpSouth,
         UpWest,
-        NorthUp,
+        WestUp,
         EastUp,
+        NorthUp,
         SouthUp,
-        WestUp,
     }
 
     pub enum Half {
@@ -295,9 +324,9 @@ properties! {
         #[default]
         Down,
         North,
-        East,
         South,
         West,
+        East,
     }
 
     pub enum HorizontalFacing {
@@ -348,6 +377,16 @@ properties! {
         NorthEast,
     }
 
+    pub enum StraightRailShape {
+        #[default]
+        NorthSouth,
+        EastWest,
+        AscendingEast,
+        AscendingWest,
+        AscendingNorth,
+        AscendingSouth,
+    }
+
     pub enum RedstoneSide {
         #[default]
         Up,
@@ -423,31 +462,31 @@ properties! {
     // Integers
     ///////////////////////////////////////////////////////////////////////////////////////////////
 
-    pub struct Age1(Integer<0, 1>);
-    pub struct Age2(Integer<0, 2>);
-    pub struct Age3(Integer<0, 3>);
-    pub struct Age5(Integer<0, 5>);
-    pub struct Age7(Integer<0, 7>);
-    pub struct Age15(Integer<0, 15>);
-    pub struct Age25(Integer<0, 25>);
-    pub struct Bites(Integer<0, 6>);
-    pub struct Candles(Integer<1, 4>);
-    pub struct Delay(Integer<1, 4>);
-    pub struct Distance(Integer<1, 7>);
-    pub struct Eggs(Integer<1, 4>);
-    pub struct Hatch(Integer<0, 2>);
-    pub struct Layers(Integer<1, 8>);
-    pub struct LevelCauldron(Integer<1, 3>);
-    pub struct LevelComposter(Integer<0, 8>);
-    pub struct LevelFlowing(Integer<1, 8>); // TODO: what's this?
-    pub struct LevelHoney(Integer<0, 5>);
-    pub struct Level(Integer<0, 15>);
-    pub struct Moisture(Integer<0, 7>);
-    pub struct Note(Integer<0, 24>);
-    pub struct Pickles(Integer<1, 4>);
-    pub struct Power(Integer<0, 15>);
-    pub struct Stage(Integer<0, 1>);
-    pub struct StabilityDistance(Integer<0, 7>);
-    pub struct RespawnAnchorCharges(Integer<0, 4>);
-    pub struct Rotation16(Integer<0, 15>);
+    pub struct Age1(pub Integer<0, 1>);
+    pub struct Age2(pub Integer<0, 2>);
+    pub struct Age3(pub Integer<0, 3>);
+    pub struct Age5(pub Integer<0, 5>);
+    pub struct Age7(pub Integer<0, 7>);
+    pub struct Age15(pub Integer<0, 15>);
+    pub struct Age25(pub Integer<0, 25>);
+    pub struct Bites(pub Integer<0, 6>);
+    pub struct Candles(pub Integer<1, 4>);
+    pub struct Delay(pub Integer<1, 4>);
+    pub struct Distance(pub Integer<1, 7>);
+    pub struct Eggs(pub Integer<1, 4>);
+    pub struct Hatch(pub Integer<0, 2>);
+    pub struct Layers(pub Integer<1, 8>);
+    pub struct LevelCauldron(pub Integer<1, 3>);
+    pub struct LevelComposter(pub Integer<0, 8>);
+    pub struct LevelFlowing(pub Integer<1, 8>); // TODO: what's this?
+    pub struct LevelHoney(pub Integer<0, 5>);
+    pub struct Level(pub Integer<0, 15>);
+    pub struct Moisture(pub Integer<0, 7>);
+    pub struct Note(pub Integer<0, 24>);
+    pub struct Pickles(pub Integer<1, 4>);
+    pub struct Power(pub Integer<0, 15>);
+    pub struct Stage(pub Integer<0, 1>);
+    pub struct StabilityDistance(pub Integer<0, 7>);
+    pub struct RespawnAnchorCharges(pub Integer<0, 4>);
+    pub struct Rotation16(pub Integer<0, 15>);
 }
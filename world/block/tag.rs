@@ -0,0 +1,2243 @@
+//! Block tags, like `#minecraft:logs` or `#minecraft:mineable/pickaxe`. The vanilla 1.18.2 tags
+//! are built in, and data packs can add to or replace them through their
+//! `data/<namespace>/tags/blocks/` directory.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::LazyLock;
+use std::{fmt, fs};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+use crate::{resource, Block, BlockId};
+
+impl BlockId {
+    /// Returns `true` if the block is in the given tag.
+    #[must_use]
+    pub fn is_in(self, tag: &Tag) -> bool {
+        tag.contains(self)
+    }
+}
+
+impl Block {
+    /// Returns `true` if the block is in the given tag.
+    #[must_use]
+    pub fn is_in(self, tag: &Tag) -> bool {
+        tag.contains(self.id())
+    }
+}
+
+/// A set of blocks, stored as a bitset over the block IDs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Tag {
+    bits: [u64; Tag::WORDS],
+}
+
+impl Tag {
+    const WORDS: usize = BlockId::COUNT.div_ceil(64);
+
+    /// Creates a new tag with no blocks.
+    #[must_use]
+    pub const fn new() -> Self {
+        Tag {
+            bits: [0; Tag::WORDS],
+        }
+    }
+
+    /// Returns `true` if the tag contains the given block.
+    #[must_use]
+    pub fn contains(&self, id: BlockId) -> bool {
+        let index = usize::from(id.to_numeric());
+
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Adds the given block to the tag.
+    pub fn insert(&mut self, id: BlockId) {
+        let index = usize::from(id.to_numeric());
+
+        self.bits[index / 64] |= 1 << (index % 64);
+    }
+
+    /// Adds all the blocks of the given tag to the tag.
+    pub fn extend(&mut self, other: &Tag) {
+        for (word, other) in self.bits.iter_mut().zip(other.bits) {
+            *word |= other;
+        }
+    }
+
+    /// Returns the number of blocks in the tag.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if the tag contains no blocks.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// Returns an iterator over the blocks in the tag.
+    pub fn iter(&self) -> impl Iterator<Item = BlockId> + '_ {
+        BlockId::iter().filter(|&id| self.contains(id))
+    }
+}
+
+impl Default for Tag {
+    fn default() -> Self {
+        Tag::new()
+    }
+}
+
+impl fmt::Debug for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<BlockId> for Tag {
+    fn from_iter<I: IntoIterator<Item = BlockId>>(iter: I) -> Self {
+        let mut tag = Tag::new();
+
+        for id in iter {
+            tag.insert(id);
+        }
+
+        tag
+    }
+}
+
+/// A set of resolved block tags, keyed by their resource location.
+#[derive(Debug, Default)]
+pub struct Registry {
+    tags: HashMap<String, Tag>,
+}
+
+impl Registry {
+    /// Returns the registry of the built-in vanilla tags.
+    #[must_use]
+    pub fn vanilla() -> &'static Registry {
+        static VANILLA: LazyLock<Registry> = LazyLock::new(|| {
+            Loader::vanilla()
+                .build()
+                .expect("the built-in tags should be valid")
+        });
+
+        &VANILLA
+    }
+
+    /// Returns the tag with the given resource location, with or without the leading `#`.
+    #[must_use]
+    pub fn get(&self, location: &str) -> Option<&Tag> {
+        let location = normalize(location.strip_prefix('#').unwrap_or(location)).ok()?;
+
+        self.tags.get(&location)
+    }
+
+    /// Returns an iterator over the resource locations of the tags and the tags themselves.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Tag)> {
+        self.tags
+            .iter()
+            .map(|(location, tag)| (location.as_str(), tag))
+    }
+}
+
+/// Returns the built-in vanilla tag with the given resource location, like `logs` or
+/// `#minecraft:mineable/pickaxe`.
+///
+/// # Panics
+///
+/// Panics if vanilla has no tag with this resource location.
+#[must_use]
+pub fn vanilla(location: &str) -> &'static Tag {
+    Registry::vanilla()
+        .get(location)
+        .unwrap_or_else(|| panic!("the vanilla tag `{location}` should exist"))
+}
+
+/// Collects the tag files of data packs, in order to resolve them into a [`Registry`].
+#[derive(Clone, Debug, Default)]
+pub struct Loader {
+    files: HashMap<String, Vec<Entry>>,
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    /// The resource location of the block or, if prefixed with `#`, of the tag.
+    id: String,
+    /// Whether a missing block or tag is an error rather than being skipped.
+    required: bool,
+}
+
+#[derive(Deserialize)]
+struct TagFile {
+    #[serde(default)]
+    replace: bool,
+    values: Vec<TagFileEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TagFileEntry {
+    Id(String),
+    Object {
+        id: String,
+        #[serde(default = "required_default")]
+        required: bool,
+    },
+}
+
+fn required_default() -> bool {
+    true
+}
+
+impl Loader {
+    /// Creates a new `Loader` without any tags.
+    #[must_use]
+    pub fn new() -> Self {
+        Loader::default()
+    }
+
+    /// Creates a new `Loader` with the built-in vanilla tags.
+    #[must_use]
+    pub fn vanilla() -> Self {
+        let mut loader = Loader::new();
+
+        for &(path, values) in VANILLA {
+            let entries = values
+                .iter()
+                .map(|&value| Entry {
+                    id: value.to_owned(),
+                    required: true,
+                })
+                .collect();
+            loader.files.insert(format!("minecraft:{path}"), entries);
+        }
+
+        loader
+    }
+
+    /// Adds the tag with the given resource location, parsed from the contents of a tag file. If
+    /// the file sets `replace`, the entries added so far for the same tag are discarded.
+    pub fn add(&mut self, location: &str, json: &str) -> Result<()> {
+        let location = normalize(location)?;
+        let file: TagFile = serde_json::from_str(json)
+            .with_context(|| format!("failed to parse tag `#{location}`"))?;
+        let entries = self.files.entry(location).or_default();
+
+        if file.replace {
+            entries.clear();
+        }
+
+        entries.extend(file.values.into_iter().map(|entry| match entry {
+            TagFileEntry::Id(id) => Entry { id, required: true },
+            TagFileEntry::Object { id, required } => Entry { id, required },
+        }));
+
+        Ok(())
+    }
+
+    /// Adds the block tags of the unzipped data pack at the given path, that is all the files
+    /// matching `data/<namespace>/tags/blocks/**/*.json`.
+    pub fn add_data_pack(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let data = path.as_ref().join("data");
+
+        for namespace in read_dir(&data)? {
+            let namespace = namespace?;
+            let root = namespace.path().join("tags").join("blocks");
+
+            if !root.is_dir() {
+                continue;
+            }
+
+            let namespace = namespace.file_name();
+            let namespace = namespace
+                .to_str()
+                .ok_or_else(|| anyhow!("non-UTF-8 namespace in `{}`", data.display()))?;
+
+            self.add_dir(namespace, &root, &root)?;
+        }
+
+        Ok(())
+    }
+
+    fn add_dir(&mut self, namespace: &str, root: &Path, dir: &Path) -> Result<()> {
+        for entry in read_dir(dir)? {
+            let path = entry?.path();
+
+            if path.is_dir() {
+                self.add_dir(namespace, root, &path)?;
+                continue;
+            }
+
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            let name = path
+                .strip_prefix(root)
+                .unwrap()
+                .with_extension("")
+                .to_str()
+                .ok_or_else(|| anyhow!("non-UTF-8 path `{}`", path.display()))?
+                .replace(std::path::MAIN_SEPARATOR, "/");
+            let json = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read `{}`", path.display()))?;
+
+            self.add(&format!("{namespace}:{name}"), &json)?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the tags, including references to other tags.
+    pub fn build(&self) -> Result<Registry> {
+        let mut tags = HashMap::with_capacity(self.files.len());
+
+        for location in self.files.keys() {
+            self.resolve(location, &mut tags, &mut Vec::new())?;
+        }
+
+        Ok(Registry { tags })
+    }
+
+    fn resolve(
+        &self,
+        location: &str,
+        tags: &mut HashMap<String, Tag>,
+        stack: &mut Vec<String>,
+    ) -> Result<()> {
+        if tags.contains_key(location) {
+            return Ok(());
+        }
+
+        if stack.iter().any(|other| other == location) {
+            bail!("tag `#{location}` references itself");
+        }
+
+        let entries = &self.files[location];
+        let mut tag = Tag::new();
+        stack.push(location.to_owned());
+
+        for entry in entries {
+            if let Some(reference) = entry.id.strip_prefix('#') {
+                let reference = normalize(reference)?;
+
+                if !self.files.contains_key(&reference) {
+                    if entry.required {
+                        bail!("tag `#{location}` references unknown tag `#{reference}`");
+                    }
+
+                    continue;
+                }
+
+                self.resolve(&reference, tags, stack)?;
+                tag.extend(&tags[&reference]);
+            } else {
+                let id = resource::Location::new(&entry.id)
+                    .map_err(anyhow::Error::from)
+                    .and_then(BlockId::try_from);
+
+                match id {
+                    Ok(id) => tag.insert(id),
+                    Err(err) if entry.required => {
+                        return Err(err.context(format!("invalid entry in tag `#{location}`")));
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+
+        stack.pop();
+        tags.insert(location.to_owned(), tag);
+
+        Ok(())
+    }
+}
+
+pub(crate) fn read_dir(path: &Path) -> Result<fs::ReadDir> {
+    fs::read_dir(path).with_context(|| format!("failed to read `{}`", path.display()))
+}
+
+/// Converts the given resource location to the `namespace:path` form.
+pub(crate) fn normalize(location: &str) -> Result<String> {
+    let (namespace, path) = resource::Location::new(location)?.as_parts();
+
+    Ok(format!("{namespace}:{path}"))
+}
+
+/// The vanilla 1.18.2 block tags, in the `minecraft` namespace.
+const VANILLA: &[(&str, &[&str])] = &[
+    (
+        "acacia_logs",
+        &[
+            "acacia_log",
+            "acacia_wood",
+            "stripped_acacia_log",
+            "stripped_acacia_wood",
+        ],
+    ),
+    ("animals_spawnable_on", &["grass_block"]),
+    ("anvil", &["anvil", "chipped_anvil", "damaged_anvil"]),
+    ("axolotls_spawnable_on", &["clay"]),
+    (
+        "azalea_grows_on",
+        &["#dirt", "#sand", "#terracotta", "snow_block", "powder_snow"],
+    ),
+    (
+        "azalea_root_replaceable",
+        &[
+            "#base_stone_overworld",
+            "#dirt",
+            "#terracotta",
+            "red_sand",
+            "clay",
+            "gravel",
+            "sand",
+            "snow_block",
+            "powder_snow",
+        ],
+    ),
+    (
+        "bamboo_plantable_on",
+        &["#sand", "#dirt", "bamboo", "bamboo_sapling", "gravel"],
+    ),
+    (
+        "banners",
+        &[
+            "white_banner",
+            "orange_banner",
+            "magenta_banner",
+            "light_blue_banner",
+            "yellow_banner",
+            "lime_banner",
+            "pink_banner",
+            "gray_banner",
+            "light_gray_banner",
+            "cyan_banner",
+            "purple_banner",
+            "blue_banner",
+            "brown_banner",
+            "green_banner",
+            "red_banner",
+            "black_banner",
+            "white_wall_banner",
+            "orange_wall_banner",
+            "magenta_wall_banner",
+            "light_blue_wall_banner",
+            "yellow_wall_banner",
+            "lime_wall_banner",
+            "pink_wall_banner",
+            "gray_wall_banner",
+            "light_gray_wall_banner",
+            "cyan_wall_banner",
+            "purple_wall_banner",
+            "blue_wall_banner",
+            "brown_wall_banner",
+            "green_wall_banner",
+            "red_wall_banner",
+            "black_wall_banner",
+        ],
+    ),
+    ("base_stone_nether", &["netherrack", "basalt", "blackstone"]),
+    (
+        "base_stone_overworld",
+        &[
+            "stone",
+            "granite",
+            "diorite",
+            "andesite",
+            "tuff",
+            "deepslate",
+        ],
+    ),
+    (
+        "beacon_base_blocks",
+        &[
+            "netherite_block",
+            "emerald_block",
+            "diamond_block",
+            "gold_block",
+            "iron_block",
+        ],
+    ),
+    (
+        "beds",
+        &[
+            "white_bed",
+            "orange_bed",
+            "magenta_bed",
+            "light_blue_bed",
+            "yellow_bed",
+            "lime_bed",
+            "pink_bed",
+            "gray_bed",
+            "light_gray_bed",
+            "cyan_bed",
+            "purple_bed",
+            "blue_bed",
+            "brown_bed",
+            "green_bed",
+            "red_bed",
+            "black_bed",
+        ],
+    ),
+    (
+        "bee_growables",
+        &[
+            "#crops",
+            "sweet_berry_bush",
+            "cave_vines",
+            "cave_vines_plant",
+        ],
+    ),
+    ("beehives", &["bee_nest", "beehive"]),
+    (
+        "big_dripleaf_placeable",
+        &["#small_dripleaf_placeable", "#dirt", "farmland"],
+    ),
+    (
+        "birch_logs",
+        &[
+            "birch_log",
+            "birch_wood",
+            "stripped_birch_log",
+            "stripped_birch_wood",
+        ],
+    ),
+    (
+        "buttons",
+        &[
+            "#wooden_buttons",
+            "stone_button",
+            "polished_blackstone_button",
+        ],
+    ),
+    ("campfires", &["campfire", "soul_campfire"]),
+    (
+        "candle_cakes",
+        &[
+            "candle_cake",
+            "white_candle_cake",
+            "orange_candle_cake",
+            "magenta_candle_cake",
+            "light_blue_candle_cake",
+            "yellow_candle_cake",
+            "lime_candle_cake",
+            "pink_candle_cake",
+            "gray_candle_cake",
+            "light_gray_candle_cake",
+            "cyan_candle_cake",
+            "purple_candle_cake",
+            "blue_candle_cake",
+            "brown_candle_cake",
+            "green_candle_cake",
+            "red_candle_cake",
+            "black_candle_cake",
+        ],
+    ),
+    (
+        "candles",
+        &[
+            "candle",
+            "white_candle",
+            "orange_candle",
+            "magenta_candle",
+            "light_blue_candle",
+            "yellow_candle",
+            "lime_candle",
+            "pink_candle",
+            "gray_candle",
+            "light_gray_candle",
+            "cyan_candle",
+            "purple_candle",
+            "blue_candle",
+            "brown_candle",
+            "green_candle",
+            "red_candle",
+            "black_candle",
+        ],
+    ),
+    (
+        "carpets",
+        &[
+            "white_carpet",
+            "orange_carpet",
+            "magenta_carpet",
+            "light_blue_carpet",
+            "yellow_carpet",
+            "lime_carpet",
+            "pink_carpet",
+            "gray_carpet",
+            "light_gray_carpet",
+            "cyan_carpet",
+            "purple_carpet",
+            "blue_carpet",
+            "brown_carpet",
+            "green_carpet",
+            "red_carpet",
+            "black_carpet",
+        ],
+    ),
+    (
+        "cauldrons",
+        &[
+            "cauldron",
+            "water_cauldron",
+            "lava_cauldron",
+            "powder_snow_cauldron",
+        ],
+    ),
+    ("cave_vines", &["cave_vines_plant", "cave_vines"]),
+    (
+        "climbable",
+        &[
+            "ladder",
+            "vine",
+            "scaffolding",
+            "weeping_vines",
+            "weeping_vines_plant",
+            "twisting_vines",
+            "twisting_vines_plant",
+            "cave_vines",
+            "cave_vines_plant",
+        ],
+    ),
+    ("coal_ores", &["coal_ore", "deepslate_coal_ore"]),
+    ("copper_ores", &["copper_ore", "deepslate_copper_ore"]),
+    (
+        "coral_blocks",
+        &[
+            "tube_coral_block",
+            "brain_coral_block",
+            "bubble_coral_block",
+            "fire_coral_block",
+            "horn_coral_block",
+        ],
+    ),
+    (
+        "coral_plants",
+        &[
+            "tube_coral",
+            "brain_coral",
+            "bubble_coral",
+            "fire_coral",
+            "horn_coral",
+        ],
+    ),
+    (
+        "corals",
+        &[
+            "#coral_plants",
+            "tube_coral_fan",
+            "brain_coral_fan",
+            "bubble_coral_fan",
+            "fire_coral_fan",
+            "horn_coral_fan",
+        ],
+    ),
+    (
+        "crimson_stems",
+        &[
+            "crimson_stem",
+            "stripped_crimson_stem",
+            "crimson_hyphae",
+            "stripped_crimson_hyphae",
+        ],
+    ),
+    (
+        "crops",
+        &[
+            "beetroots",
+            "carrots",
+            "potatoes",
+            "wheat",
+            "melon_stem",
+            "pumpkin_stem",
+        ],
+    ),
+    (
+        "crystal_sound_blocks",
+        &["amethyst_block", "budding_amethyst"],
+    ),
+    (
+        "dark_oak_logs",
+        &[
+            "dark_oak_log",
+            "dark_oak_wood",
+            "stripped_dark_oak_log",
+            "stripped_dark_oak_wood",
+        ],
+    ),
+    ("deepslate_ore_replaceables", &["deepslate", "tuff"]),
+    ("diamond_ores", &["diamond_ore", "deepslate_diamond_ore"]),
+    (
+        "dirt",
+        &[
+            "dirt",
+            "grass_block",
+            "podzol",
+            "coarse_dirt",
+            "mycelium",
+            "rooted_dirt",
+            "moss_block",
+        ],
+    ),
+    ("doors", &["#wooden_doors", "iron_door"]),
+    (
+        "dragon_immune",
+        &[
+            "barrier",
+            "bedrock",
+            "end_portal",
+            "end_portal_frame",
+            "end_gateway",
+            "command_block",
+            "repeating_command_block",
+            "chain_command_block",
+            "structure_block",
+            "jigsaw",
+            "moving_piston",
+            "obsidian",
+            "crying_obsidian",
+            "end_stone",
+            "iron_bars",
+            "respawn_anchor",
+        ],
+    ),
+    ("dripstone_replaceable_blocks", &["#base_stone_overworld"]),
+    ("emerald_ores", &["emerald_ore", "deepslate_emerald_ore"]),
+    (
+        "enderman_holdable",
+        &[
+            "#small_flowers",
+            "#dirt",
+            "sand",
+            "red_sand",
+            "gravel",
+            "brown_mushroom",
+            "red_mushroom",
+            "tnt",
+            "cactus",
+            "clay",
+            "pumpkin",
+            "carved_pumpkin",
+            "melon",
+            "crimson_fungus",
+            "crimson_nylium",
+            "crimson_roots",
+            "warped_fungus",
+            "warped_nylium",
+            "warped_roots",
+        ],
+    ),
+    (
+        "features_cannot_replace",
+        &["bedrock", "spawner", "chest", "end_portal_frame"],
+    ),
+    (
+        "fence_gates",
+        &[
+            "oak_fence_gate",
+            "spruce_fence_gate",
+            "birch_fence_gate",
+            "jungle_fence_gate",
+            "acacia_fence_gate",
+            "dark_oak_fence_gate",
+            "crimson_fence_gate",
+            "warped_fence_gate",
+        ],
+    ),
+    ("fences", &["#wooden_fences", "nether_brick_fence"]),
+    ("fire", &["fire", "soul_fire"]),
+    (
+        "flower_pots",
+        &[
+            "flower_pot",
+            "potted_acacia_sapling",
+            "potted_allium",
+            "potted_azalea_bush",
+            "potted_azure_bluet",
+            "potted_bamboo",
+            "potted_birch_sapling",
+            "potted_blue_orchid",
+            "potted_brown_mushroom",
+            "potted_cactus",
+            "potted_cornflower",
+            "potted_crimson_fungus",
+            "potted_crimson_roots",
+            "potted_dandelion",
+            "potted_dark_oak_sapling",
+            "potted_dead_bush",
+            "potted_fern",
+            "potted_flowering_azalea_bush",
+            "potted_jungle_sapling",
+            "potted_lily_of_the_valley",
+            "potted_oak_sapling",
+            "potted_orange_tulip",
+            "potted_oxeye_daisy",
+            "potted_pink_tulip",
+            "potted_poppy",
+            "potted_red_mushroom",
+            "potted_red_tulip",
+            "potted_spruce_sapling",
+            "potted_warped_fungus",
+            "potted_warped_roots",
+            "potted_white_tulip",
+            "potted_wither_rose",
+        ],
+    ),
+    (
+        "flowers",
+        &[
+            "#small_flowers",
+            "#tall_flowers",
+            "flowering_azalea_leaves",
+            "flowering_azalea",
+        ],
+    ),
+    (
+        "foxes_spawnable_on",
+        &["grass_block", "snow", "snow_block", "podzol", "coarse_dirt"],
+    ),
+    (
+        "geode_invalid_blocks",
+        &["bedrock", "water", "lava", "ice", "packed_ice", "blue_ice"],
+    ),
+    (
+        "goats_spawnable_on",
+        &[
+            "#animals_spawnable_on",
+            "stone",
+            "snow",
+            "snow_block",
+            "packed_ice",
+            "gravel",
+        ],
+    ),
+    (
+        "gold_ores",
+        &["gold_ore", "nether_gold_ore", "deepslate_gold_ore"],
+    ),
+    (
+        "guarded_by_piglins",
+        &[
+            "gold_block",
+            "barrel",
+            "chest",
+            "ender_chest",
+            "gilded_blackstone",
+            "trapped_chest",
+            "raw_gold_block",
+            "#shulker_boxes",
+            "#gold_ores",
+        ],
+    ),
+    (
+        "hoglin_repellents",
+        &[
+            "warped_fungus",
+            "potted_warped_fungus",
+            "nether_portal",
+            "respawn_anchor",
+        ],
+    ),
+    ("ice", &["ice", "packed_ice", "blue_ice", "frosted_ice"]),
+    (
+        "impermeable",
+        &[
+            "glass",
+            "white_stained_glass",
+            "orange_stained_glass",
+            "magenta_stained_glass",
+            "light_blue_stained_glass",
+            "yellow_stained_glass",
+            "lime_stained_glass",
+            "pink_stained_glass",
+            "gray_stained_glass",
+            "light_gray_stained_glass",
+            "cyan_stained_glass",
+            "purple_stained_glass",
+            "blue_stained_glass",
+            "brown_stained_glass",
+            "green_stained_glass",
+            "red_stained_glass",
+            "black_stained_glass",
+            "tinted_glass",
+        ],
+    ),
+    ("infiniburn_end", &["#infiniburn_overworld", "bedrock"]),
+    ("infiniburn_nether", &["#infiniburn_overworld"]),
+    ("infiniburn_overworld", &["netherrack", "magma_block"]),
+    ("inside_step_sound_blocks", &["snow", "powder_snow"]),
+    ("iron_ores", &["iron_ore", "deepslate_iron_ore"]),
+    (
+        "jungle_logs",
+        &[
+            "jungle_log",
+            "jungle_wood",
+            "stripped_jungle_log",
+            "stripped_jungle_wood",
+        ],
+    ),
+    ("lapis_ores", &["lapis_ore", "deepslate_lapis_ore"]),
+    (
+        "lava_pool_stone_cannot_replace",
+        &["#features_cannot_replace", "#leaves", "#logs"],
+    ),
+    (
+        "leaves",
+        &[
+            "oak_leaves",
+            "spruce_leaves",
+            "birch_leaves",
+            "jungle_leaves",
+            "acacia_leaves",
+            "dark_oak_leaves",
+            "azalea_leaves",
+            "flowering_azalea_leaves",
+        ],
+    ),
+    (
+        "logs",
+        &["#logs_that_burn", "#crimson_stems", "#warped_stems"],
+    ),
+    (
+        "logs_that_burn",
+        &[
+            "#oak_logs",
+            "#spruce_logs",
+            "#birch_logs",
+            "#jungle_logs",
+            "#acacia_logs",
+            "#dark_oak_logs",
+        ],
+    ),
+    (
+        "lush_ground_replaceable",
+        &["#moss_replaceable", "clay", "gravel", "sand"],
+    ),
+    (
+        "mineable/axe",
+        &[
+            "note_block",
+            "attached_melon_stem",
+            "attached_pumpkin_stem",
+            "azalea",
+            "bamboo",
+            "barrel",
+            "bee_nest",
+            "beehive",
+            "beetroots",
+            "big_dripleaf_stem",
+            "big_dripleaf",
+            "bookshelf",
+            "brown_mushroom_block",
+            "brown_mushroom",
+            "campfire",
+            "carrots",
+            "cartography_table",
+            "carved_pumpkin",
+            "cave_vines_plant",
+            "cave_vines",
+            "chest",
+            "chorus_flower",
+            "chorus_plant",
+            "cocoa",
+            "composter",
+            "crafting_table",
+            "crimson_fungus",
+            "daylight_detector",
+            "dead_bush",
+            "fern",
+            "fletching_table",
+            "glow_lichen",
+            "grass",
+            "hanging_roots",
+            "jack_o_lantern",
+            "jukebox",
+            "ladder",
+            "large_fern",
+            "lectern",
+            "lily_pad",
+            "loom",
+            "melon_stem",
+            "melon",
+            "mushroom_stem",
+            "nether_wart",
+            "potatoes",
+            "pumpkin_stem",
+            "pumpkin",
+            "red_mushroom_block",
+            "red_mushroom",
+            "scaffolding",
+            "small_dripleaf",
+            "smithing_table",
+            "soul_campfire",
+            "spore_blossom",
+            "sugar_cane",
+            "sweet_berry_bush",
+            "tall_grass",
+            "trapped_chest",
+            "twisting_vines_plant",
+            "twisting_vines",
+            "vine",
+            "warped_fungus",
+            "weeping_vines_plant",
+            "weeping_vines",
+            "wheat",
+            "#banners",
+            "#fence_gates",
+            "#logs",
+            "#planks",
+            "#signs",
+            "#wooden_buttons",
+            "#wooden_doors",
+            "#wooden_fences",
+            "#wooden_pressure_plates",
+            "#wooden_slabs",
+            "#wooden_stairs",
+            "#wooden_trapdoors",
+        ],
+    ),
+    (
+        "mineable/hoe",
+        &[
+            "nether_wart_block",
+            "warped_wart_block",
+            "hay_block",
+            "dried_kelp_block",
+            "target",
+            "shroomlight",
+            "sponge",
+            "wet_sponge",
+            "jungle_leaves",
+            "oak_leaves",
+            "spruce_leaves",
+            "dark_oak_leaves",
+            "acacia_leaves",
+            "birch_leaves",
+            "azalea_leaves",
+            "flowering_azalea_leaves",
+            "sculk_sensor",
+            "moss_block",
+            "moss_carpet",
+        ],
+    ),
+    (
+        "mineable/pickaxe",
+        &[
+            "amethyst_block",
+            "amethyst_cluster",
+            "ancient_debris",
+            "andesite",
+            "andesite_slab",
+            "andesite_stairs",
+            "andesite_wall",
+            "anvil",
+            "basalt",
+            "bell",
+            "black_concrete",
+            "black_glazed_terracotta",
+            "black_shulker_box",
+            "black_terracotta",
+            "blackstone",
+            "blackstone_slab",
+            "blackstone_stairs",
+            "blackstone_wall",
+            "blast_furnace",
+            "blue_concrete",
+            "blue_glazed_terracotta",
+            "blue_ice",
+            "blue_shulker_box",
+            "blue_terracotta",
+            "bone_block",
+            "brain_coral_block",
+            "brewing_stand",
+            "brick_slab",
+            "brick_stairs",
+            "brick_wall",
+            "bricks",
+            "brown_concrete",
+            "brown_glazed_terracotta",
+            "brown_shulker_box",
+            "brown_terracotta",
+            "bubble_coral_block",
+            "budding_amethyst",
+            "calcite",
+            "cauldron",
+            "chain",
+            "chipped_anvil",
+            "chiseled_deepslate",
+            "chiseled_nether_bricks",
+            "chiseled_polished_blackstone",
+            "chiseled_quartz_block",
+            "chiseled_red_sandstone",
+            "chiseled_sandstone",
+            "chiseled_stone_bricks",
+            "coal_block",
+            "coal_ore",
+            "cobbled_deepslate",
+            "cobbled_deepslate_slab",
+            "cobbled_deepslate_stairs",
+            "cobbled_deepslate_wall",
+            "cobblestone",
+            "cobblestone_slab",
+            "cobblestone_stairs",
+            "cobblestone_wall",
+            "copper_block",
+            "copper_ore",
+            "cracked_deepslate_bricks",
+            "cracked_deepslate_tiles",
+            "cracked_nether_bricks",
+            "cracked_polished_blackstone_bricks",
+            "cracked_stone_bricks",
+            "crimson_nylium",
+            "crying_obsidian",
+            "cut_copper",
+            "cut_copper_slab",
+            "cut_copper_stairs",
+            "cut_red_sandstone",
+            "cut_red_sandstone_slab",
+            "cut_sandstone",
+            "cut_sandstone_slab",
+            "cyan_concrete",
+            "cyan_glazed_terracotta",
+            "cyan_shulker_box",
+            "cyan_terracotta",
+            "damaged_anvil",
+            "dark_prismarine",
+            "dark_prismarine_slab",
+            "dark_prismarine_stairs",
+            "dead_brain_coral_block",
+            "dead_bubble_coral_block",
+            "dead_fire_coral_block",
+            "dead_horn_coral_block",
+            "dead_tube_coral_block",
+            "deepslate",
+            "deepslate_brick_slab",
+            "deepslate_brick_stairs",
+            "deepslate_brick_wall",
+            "deepslate_bricks",
+            "deepslate_coal_ore",
+            "deepslate_copper_ore",
+            "deepslate_diamond_ore",
+            "deepslate_emerald_ore",
+            "deepslate_gold_ore",
+            "deepslate_iron_ore",
+            "deepslate_lapis_ore",
+            "deepslate_redstone_ore",
+            "deepslate_tile_slab",
+            "deepslate_tile_stairs",
+            "deepslate_tile_wall",
+            "deepslate_tiles",
+            "diamond_block",
+            "diamond_ore",
+            "diorite",
+            "diorite_slab",
+            "diorite_stairs",
+            "diorite_wall",
+            "dispenser",
+            "dripstone_block",
+            "dropper",
+            "emerald_block",
+            "emerald_ore",
+            "enchanting_table",
+            "end_stone",
+            "end_stone_brick_slab",
+            "end_stone_brick_stairs",
+            "end_stone_brick_wall",
+            "end_stone_bricks",
+            "ender_chest",
+            "exposed_copper",
+            "exposed_cut_copper",
+            "exposed_cut_copper_slab",
+            "exposed_cut_copper_stairs",
+            "fire_coral_block",
+            "furnace",
+            "gilded_blackstone",
+            "gold_block",
+            "gold_ore",
+            "granite",
+            "granite_slab",
+            "granite_stairs",
+            "granite_wall",
+            "gray_concrete",
+            "gray_glazed_terracotta",
+            "gray_shulker_box",
+            "gray_terracotta",
+            "green_concrete",
+            "green_glazed_terracotta",
+            "green_shulker_box",
+            "green_terracotta",
+            "grindstone",
+            "heavy_weighted_pressure_plate",
+            "hopper",
+            "horn_coral_block",
+            "iron_bars",
+            "iron_block",
+            "iron_door",
+            "iron_ore",
+            "iron_trapdoor",
+            "lantern",
+            "lapis_block",
+            "lapis_ore",
+            "large_amethyst_bud",
+            "lava_cauldron",
+            "light_blue_concrete",
+            "light_blue_glazed_terracotta",
+            "light_blue_shulker_box",
+            "light_blue_terracotta",
+            "light_gray_concrete",
+            "light_gray_glazed_terracotta",
+            "light_gray_shulker_box",
+            "light_gray_terracotta",
+            "light_weighted_pressure_plate",
+            "lightning_rod",
+            "lime_concrete",
+            "lime_glazed_terracotta",
+            "lime_shulker_box",
+            "lime_terracotta",
+            "lodestone",
+            "magenta_concrete",
+            "magenta_glazed_terracotta",
+            "magenta_shulker_box",
+            "magenta_terracotta",
+            "magma_block",
+            "medium_amethyst_bud",
+            "mossy_cobblestone",
+            "mossy_cobblestone_slab",
+            "mossy_cobblestone_stairs",
+            "mossy_cobblestone_wall",
+            "mossy_stone_brick_slab",
+            "mossy_stone_brick_stairs",
+            "mossy_stone_brick_wall",
+            "mossy_stone_bricks",
+            "nether_brick_fence",
+            "nether_brick_slab",
+            "nether_brick_stairs",
+            "nether_brick_wall",
+            "nether_bricks",
+            "nether_gold_ore",
+            "nether_quartz_ore",
+            "netherite_block",
+            "netherrack",
+            "observer",
+            "obsidian",
+            "orange_concrete",
+            "orange_glazed_terracotta",
+            "orange_shulker_box",
+            "orange_terracotta",
+            "oxidized_copper",
+            "oxidized_cut_copper",
+            "oxidized_cut_copper_slab",
+            "oxidized_cut_copper_stairs",
+            "packed_ice",
+            "petrified_oak_slab",
+            "pink_concrete",
+            "pink_glazed_terracotta",
+            "pink_shulker_box",
+            "pink_terracotta",
+            "pointed_dripstone",
+            "polished_andesite",
+            "polished_andesite_slab",
+            "polished_andesite_stairs",
+            "polished_basalt",
+            "polished_blackstone",
+            "polished_blackstone_brick_slab",
+            "polished_blackstone_brick_stairs",
+            "polished_blackstone_brick_wall",
+            "polished_blackstone_bricks",
+            "polished_blackstone_pressure_plate",
+            "polished_blackstone_slab",
+            "polished_blackstone_stairs",
+            "polished_blackstone_wall",
+            "polished_deepslate",
+            "polished_deepslate_slab",
+            "polished_deepslate_stairs",
+            "polished_deepslate_wall",
+            "polished_diorite",
+            "polished_diorite_slab",
+            "polished_diorite_stairs",
+            "polished_granite",
+            "polished_granite_slab",
+            "polished_granite_stairs",
+            "powder_snow_cauldron",
+            "prismarine",
+            "prismarine_brick_slab",
+            "prismarine_brick_stairs",
+            "prismarine_bricks",
+            "prismarine_slab",
+            "prismarine_stairs",
+            "prismarine_wall",
+            "purple_concrete",
+            "purple_glazed_terracotta",
+            "purple_shulker_box",
+            "purple_terracotta",
+            "purpur_block",
+            "purpur_pillar",
+            "purpur_slab",
+            "purpur_stairs",
+            "quartz_block",
+            "quartz_bricks",
+            "quartz_pillar",
+            "quartz_slab",
+            "quartz_stairs",
+            "raw_copper_block",
+            "raw_gold_block",
+            "raw_iron_block",
+            "red_concrete",
+            "red_glazed_terracotta",
+            "red_nether_brick_slab",
+            "red_nether_brick_stairs",
+            "red_nether_brick_wall",
+            "red_nether_bricks",
+            "red_sandstone",
+            "red_sandstone_slab",
+            "red_sandstone_stairs",
+            "red_sandstone_wall",
+            "red_shulker_box",
+            "red_terracotta",
+            "redstone_block",
+            "redstone_ore",
+            "respawn_anchor",
+            "sandstone",
+            "sandstone_slab",
+            "sandstone_stairs",
+            "sandstone_wall",
+            "shulker_box",
+            "small_amethyst_bud",
+            "smoker",
+            "smooth_basalt",
+            "smooth_quartz",
+            "smooth_quartz_slab",
+            "smooth_quartz_stairs",
+            "smooth_red_sandstone",
+            "smooth_red_sandstone_slab",
+            "smooth_red_sandstone_stairs",
+            "smooth_sandstone",
+            "smooth_sandstone_slab",
+            "smooth_sandstone_stairs",
+            "smooth_stone",
+            "smooth_stone_slab",
+            "soul_lantern",
+            "spawner",
+            "stone",
+            "stone_brick_slab",
+            "stone_brick_stairs",
+            "stone_brick_wall",
+            "stone_bricks",
+            "stone_pressure_plate",
+            "stone_slab",
+            "stone_stairs",
+            "stonecutter",
+            "terracotta",
+            "tube_coral_block",
+            "tuff",
+            "warped_nylium",
+            "water_cauldron",
+            "waxed_copper_block",
+            "waxed_cut_copper",
+            "waxed_cut_copper_slab",
+            "waxed_cut_copper_stairs",
+            "waxed_exposed_copper",
+            "waxed_exposed_cut_copper",
+            "waxed_exposed_cut_copper_slab",
+            "waxed_exposed_cut_copper_stairs",
+            "waxed_oxidized_copper",
+            "waxed_oxidized_cut_copper",
+            "waxed_oxidized_cut_copper_slab",
+            "waxed_oxidized_cut_copper_stairs",
+            "waxed_weathered_copper",
+            "waxed_weathered_cut_copper",
+            "waxed_weathered_cut_copper_slab",
+            "waxed_weathered_cut_copper_stairs",
+            "weathered_copper",
+            "weathered_cut_copper",
+            "weathered_cut_copper_slab",
+            "weathered_cut_copper_stairs",
+            "white_concrete",
+            "white_glazed_terracotta",
+            "white_shulker_box",
+            "white_terracotta",
+            "yellow_concrete",
+            "yellow_glazed_terracotta",
+            "yellow_shulker_box",
+            "yellow_terracotta",
+            "ice",
+            "piston",
+            "sticky_piston",
+            "piston_head",
+            "rail",
+            "powered_rail",
+            "detector_rail",
+            "activator_rail",
+            "stone_button",
+            "polished_blackstone_button",
+            "conduit",
+        ],
+    ),
+    (
+        "mineable/shovel",
+        &[
+            "clay",
+            "dirt",
+            "coarse_dirt",
+            "podzol",
+            "farmland",
+            "grass_block",
+            "gravel",
+            "mycelium",
+            "sand",
+            "red_sand",
+            "snow_block",
+            "snow",
+            "soul_sand",
+            "dirt_path",
+            "white_concrete_powder",
+            "orange_concrete_powder",
+            "magenta_concrete_powder",
+            "light_blue_concrete_powder",
+            "yellow_concrete_powder",
+            "lime_concrete_powder",
+            "pink_concrete_powder",
+            "gray_concrete_powder",
+            "light_gray_concrete_powder",
+            "cyan_concrete_powder",
+            "purple_concrete_powder",
+            "blue_concrete_powder",
+            "brown_concrete_powder",
+            "green_concrete_powder",
+            "red_concrete_powder",
+            "black_concrete_powder",
+            "soul_soil",
+            "rooted_dirt",
+            "powder_snow",
+        ],
+    ),
+    ("mooshrooms_spawnable_on", &["mycelium"]),
+    (
+        "moss_replaceable",
+        &["#base_stone_overworld", "#cave_vines", "#dirt"],
+    ),
+    (
+        "mushroom_grow_block",
+        &["mycelium", "podzol", "crimson_nylium", "warped_nylium"],
+    ),
+    (
+        "needs_diamond_tool",
+        &[
+            "obsidian",
+            "crying_obsidian",
+            "netherite_block",
+            "respawn_anchor",
+            "ancient_debris",
+        ],
+    ),
+    (
+        "needs_iron_tool",
+        &[
+            "diamond_block",
+            "diamond_ore",
+            "deepslate_diamond_ore",
+            "emerald_ore",
+            "deepslate_emerald_ore",
+            "emerald_block",
+            "gold_block",
+            "raw_gold_block",
+            "gold_ore",
+            "deepslate_gold_ore",
+            "redstone_ore",
+            "deepslate_redstone_ore",
+        ],
+    ),
+    (
+        "needs_stone_tool",
+        &[
+            "iron_block",
+            "raw_iron_block",
+            "iron_ore",
+            "deepslate_iron_ore",
+            "lapis_block",
+            "lapis_ore",
+            "deepslate_lapis_ore",
+            "copper_block",
+            "copper_ore",
+            "cut_copper",
+            "cut_copper_slab",
+            "cut_copper_stairs",
+            "deepslate_copper_ore",
+            "exposed_copper",
+            "exposed_cut_copper",
+            "exposed_cut_copper_slab",
+            "exposed_cut_copper_stairs",
+            "oxidized_copper",
+            "oxidized_cut_copper",
+            "oxidized_cut_copper_slab",
+            "oxidized_cut_copper_stairs",
+            "raw_copper_block",
+            "waxed_copper_block",
+            "waxed_cut_copper",
+            "waxed_cut_copper_slab",
+            "waxed_cut_copper_stairs",
+            "waxed_exposed_copper",
+            "waxed_exposed_cut_copper",
+            "waxed_exposed_cut_copper_slab",
+            "waxed_exposed_cut_copper_stairs",
+            "waxed_oxidized_copper",
+            "waxed_oxidized_cut_copper",
+            "waxed_oxidized_cut_copper_slab",
+            "waxed_oxidized_cut_copper_stairs",
+            "waxed_weathered_copper",
+            "waxed_weathered_cut_copper",
+            "waxed_weathered_cut_copper_slab",
+            "waxed_weathered_cut_copper_stairs",
+            "weathered_copper",
+            "weathered_cut_copper",
+            "weathered_cut_copper_slab",
+            "weathered_cut_copper_stairs",
+            "lightning_rod",
+        ],
+    ),
+    (
+        "non_flammable_wood",
+        &[
+            "warped_stem",
+            "warped_hyphae",
+            "crimson_stem",
+            "crimson_hyphae",
+            "stripped_warped_stem",
+            "stripped_warped_hyphae",
+            "stripped_crimson_stem",
+            "stripped_crimson_hyphae",
+            "crimson_planks",
+            "warped_planks",
+            "crimson_slab",
+            "warped_slab",
+            "crimson_pressure_plate",
+            "warped_pressure_plate",
+            "crimson_fence",
+            "warped_fence",
+            "crimson_trapdoor",
+            "warped_trapdoor",
+            "crimson_fence_gate",
+            "warped_fence_gate",
+            "crimson_stairs",
+            "warped_stairs",
+            "crimson_button",
+            "warped_button",
+            "crimson_door",
+            "warped_door",
+            "crimson_sign",
+            "warped_sign",
+            "crimson_wall_sign",
+            "warped_wall_sign",
+        ],
+    ),
+    ("nylium", &["crimson_nylium", "warped_nylium"]),
+    (
+        "oak_logs",
+        &[
+            "oak_log",
+            "oak_wood",
+            "stripped_oak_log",
+            "stripped_oak_wood",
+        ],
+    ),
+    ("occludes_vibration_signals", &["#wool"]),
+    (
+        "parrots_spawnable_on",
+        &["grass_block", "air", "#leaves", "#logs"],
+    ),
+    (
+        "piglin_repellents",
+        &[
+            "soul_fire",
+            "soul_torch",
+            "soul_lantern",
+            "soul_wall_torch",
+            "soul_campfire",
+        ],
+    ),
+    (
+        "planks",
+        &[
+            "oak_planks",
+            "spruce_planks",
+            "birch_planks",
+            "jungle_planks",
+            "acacia_planks",
+            "dark_oak_planks",
+            "crimson_planks",
+            "warped_planks",
+        ],
+    ),
+    ("polar_bears_spawnable_on_in_frozen_ocean", &["ice"]),
+    ("portals", &["nether_portal", "end_portal", "end_gateway"]),
+    (
+        "pressure_plates",
+        &[
+            "light_weighted_pressure_plate",
+            "heavy_weighted_pressure_plate",
+            "#wooden_pressure_plates",
+            "#stone_pressure_plates",
+        ],
+    ),
+    ("prevent_mob_spawning_inside", &["#rails"]),
+    (
+        "rabbits_spawnable_on",
+        &["grass_block", "snow", "snow_block", "sand"],
+    ),
+    (
+        "rails",
+        &["rail", "powered_rail", "detector_rail", "activator_rail"],
+    ),
+    ("redstone_ores", &["redstone_ore", "deepslate_redstone_ore"]),
+    (
+        "replaceable_plants",
+        &[
+            "grass",
+            "fern",
+            "dead_bush",
+            "vine",
+            "glow_lichen",
+            "sunflower",
+            "lilac",
+            "rose_bush",
+            "peony",
+            "tall_grass",
+            "large_fern",
+            "hanging_roots",
+        ],
+    ),
+    ("sand", &["sand", "red_sand"]),
+    (
+        "saplings",
+        &[
+            "oak_sapling",
+            "spruce_sapling",
+            "birch_sapling",
+            "jungle_sapling",
+            "acacia_sapling",
+            "dark_oak_sapling",
+            "azalea",
+            "flowering_azalea",
+        ],
+    ),
+    (
+        "shulker_boxes",
+        &[
+            "shulker_box",
+            "white_shulker_box",
+            "orange_shulker_box",
+            "magenta_shulker_box",
+            "light_blue_shulker_box",
+            "yellow_shulker_box",
+            "lime_shulker_box",
+            "pink_shulker_box",
+            "gray_shulker_box",
+            "light_gray_shulker_box",
+            "cyan_shulker_box",
+            "purple_shulker_box",
+            "blue_shulker_box",
+            "brown_shulker_box",
+            "green_shulker_box",
+            "red_shulker_box",
+            "black_shulker_box",
+        ],
+    ),
+    ("signs", &["#standing_signs", "#wall_signs"]),
+    (
+        "slabs",
+        &[
+            "#wooden_slabs",
+            "andesite_slab",
+            "blackstone_slab",
+            "brick_slab",
+            "cobbled_deepslate_slab",
+            "cobblestone_slab",
+            "cut_copper_slab",
+            "cut_red_sandstone_slab",
+            "cut_sandstone_slab",
+            "dark_prismarine_slab",
+            "deepslate_brick_slab",
+            "deepslate_tile_slab",
+            "diorite_slab",
+            "end_stone_brick_slab",
+            "exposed_cut_copper_slab",
+            "granite_slab",
+            "mossy_cobblestone_slab",
+            "mossy_stone_brick_slab",
+            "nether_brick_slab",
+            "oxidized_cut_copper_slab",
+            "petrified_oak_slab",
+            "polished_andesite_slab",
+            "polished_blackstone_brick_slab",
+            "polished_blackstone_slab",
+            "polished_deepslate_slab",
+            "polished_diorite_slab",
+            "polished_granite_slab",
+            "prismarine_brick_slab",
+            "prismarine_slab",
+            "purpur_slab",
+            "quartz_slab",
+            "red_nether_brick_slab",
+            "red_sandstone_slab",
+            "sandstone_slab",
+            "smooth_quartz_slab",
+            "smooth_red_sandstone_slab",
+            "smooth_sandstone_slab",
+            "smooth_stone_slab",
+            "stone_brick_slab",
+            "stone_slab",
+            "waxed_cut_copper_slab",
+            "waxed_exposed_cut_copper_slab",
+            "waxed_oxidized_cut_copper_slab",
+            "waxed_weathered_cut_copper_slab",
+            "weathered_cut_copper_slab",
+        ],
+    ),
+    ("small_dripleaf_placeable", &["clay", "moss_block"]),
+    (
+        "small_flowers",
+        &[
+            "dandelion",
+            "poppy",
+            "blue_orchid",
+            "allium",
+            "azure_bluet",
+            "red_tulip",
+            "orange_tulip",
+            "white_tulip",
+            "pink_tulip",
+            "oxeye_daisy",
+            "cornflower",
+            "lily_of_the_valley",
+            "wither_rose",
+        ],
+    ),
+    ("snow", &["snow", "snow_block", "powder_snow"]),
+    ("soul_fire_base_blocks", &["soul_sand", "soul_soil"]),
+    ("soul_speed_blocks", &["soul_sand", "soul_soil"]),
+    (
+        "spruce_logs",
+        &[
+            "spruce_log",
+            "spruce_wood",
+            "stripped_spruce_log",
+            "stripped_spruce_wood",
+        ],
+    ),
+    (
+        "stairs",
+        &[
+            "#wooden_stairs",
+            "andesite_stairs",
+            "blackstone_stairs",
+            "brick_stairs",
+            "cobbled_deepslate_stairs",
+            "cobblestone_stairs",
+            "cut_copper_stairs",
+            "dark_prismarine_stairs",
+            "deepslate_brick_stairs",
+            "deepslate_tile_stairs",
+            "diorite_stairs",
+            "end_stone_brick_stairs",
+            "exposed_cut_copper_stairs",
+            "granite_stairs",
+            "mossy_cobblestone_stairs",
+            "mossy_stone_brick_stairs",
+            "nether_brick_stairs",
+            "oxidized_cut_copper_stairs",
+            "polished_andesite_stairs",
+            "polished_blackstone_brick_stairs",
+            "polished_blackstone_stairs",
+            "polished_deepslate_stairs",
+            "polished_diorite_stairs",
+            "polished_granite_stairs",
+            "prismarine_brick_stairs",
+            "prismarine_stairs",
+            "purpur_stairs",
+            "quartz_stairs",
+            "red_nether_brick_stairs",
+            "red_sandstone_stairs",
+            "sandstone_stairs",
+            "smooth_quartz_stairs",
+            "smooth_red_sandstone_stairs",
+            "smooth_sandstone_stairs",
+            "stone_brick_stairs",
+            "stone_stairs",
+            "waxed_cut_copper_stairs",
+            "waxed_exposed_cut_copper_stairs",
+            "waxed_oxidized_cut_copper_stairs",
+            "waxed_weathered_cut_copper_stairs",
+            "weathered_cut_copper_stairs",
+        ],
+    ),
+    (
+        "standing_signs",
+        &[
+            "oak_sign",
+            "spruce_sign",
+            "birch_sign",
+            "jungle_sign",
+            "acacia_sign",
+            "dark_oak_sign",
+            "crimson_sign",
+            "warped_sign",
+        ],
+    ),
+    (
+        "stone_bricks",
+        &[
+            "stone_bricks",
+            "mossy_stone_bricks",
+            "cracked_stone_bricks",
+            "chiseled_stone_bricks",
+        ],
+    ),
+    (
+        "stone_ore_replaceables",
+        &["stone", "granite", "diorite", "andesite"],
+    ),
+    (
+        "stone_pressure_plates",
+        &["stone_pressure_plate", "polished_blackstone_pressure_plate"],
+    ),
+    ("strider_warm_blocks", &["lava"]),
+    (
+        "tall_flowers",
+        &["sunflower", "lilac", "peony", "rose_bush"],
+    ),
+    (
+        "terracotta",
+        &[
+            "terracotta",
+            "white_terracotta",
+            "orange_terracotta",
+            "magenta_terracotta",
+            "light_blue_terracotta",
+            "yellow_terracotta",
+            "lime_terracotta",
+            "pink_terracotta",
+            "gray_terracotta",
+            "light_gray_terracotta",
+            "cyan_terracotta",
+            "purple_terracotta",
+            "blue_terracotta",
+            "brown_terracotta",
+            "green_terracotta",
+            "red_terracotta",
+            "black_terracotta",
+        ],
+    ),
+    ("trapdoors", &["#wooden_trapdoors", "iron_trapdoor"]),
+    (
+        "underwater_bonemeals",
+        &["seagrass", "#corals", "#wall_corals"],
+    ),
+    ("unstable_bottom_center", &["#fence_gates"]),
+    ("valid_spawn", &["grass_block", "podzol"]),
+    (
+        "wall_corals",
+        &[
+            "tube_coral_wall_fan",
+            "brain_coral_wall_fan",
+            "bubble_coral_wall_fan",
+            "fire_coral_wall_fan",
+            "horn_coral_wall_fan",
+        ],
+    ),
+    (
+        "wall_post_override",
+        &[
+            "torch",
+            "soul_torch",
+            "redstone_torch",
+            "tripwire",
+            "#signs",
+            "#banners",
+            "#pressure_plates",
+        ],
+    ),
+    (
+        "wall_signs",
+        &[
+            "oak_wall_sign",
+            "spruce_wall_sign",
+            "birch_wall_sign",
+            "jungle_wall_sign",
+            "acacia_wall_sign",
+            "dark_oak_wall_sign",
+            "crimson_wall_sign",
+            "warped_wall_sign",
+        ],
+    ),
+    (
+        "walls",
+        &[
+            "andesite_wall",
+            "blackstone_wall",
+            "brick_wall",
+            "cobbled_deepslate_wall",
+            "cobblestone_wall",
+            "deepslate_brick_wall",
+            "deepslate_tile_wall",
+            "diorite_wall",
+            "end_stone_brick_wall",
+            "granite_wall",
+            "mossy_cobblestone_wall",
+            "mossy_stone_brick_wall",
+            "nether_brick_wall",
+            "polished_blackstone_brick_wall",
+            "polished_blackstone_wall",
+            "polished_deepslate_wall",
+            "prismarine_wall",
+            "red_nether_brick_wall",
+            "red_sandstone_wall",
+            "sandstone_wall",
+            "stone_brick_wall",
+        ],
+    ),
+    (
+        "warped_stems",
+        &[
+            "warped_stem",
+            "stripped_warped_stem",
+            "warped_hyphae",
+            "stripped_warped_hyphae",
+        ],
+    ),
+    ("wart_blocks", &["nether_wart_block", "warped_wart_block"]),
+    (
+        "wither_immune",
+        &[
+            "barrier",
+            "bedrock",
+            "end_portal",
+            "end_portal_frame",
+            "end_gateway",
+            "command_block",
+            "repeating_command_block",
+            "chain_command_block",
+            "structure_block",
+            "jigsaw",
+            "moving_piston",
+        ],
+    ),
+    ("wither_summon_base_blocks", &["soul_sand", "soul_soil"]),
+    (
+        "wolves_spawnable_on",
+        &["grass_block", "snow", "snow_block"],
+    ),
+    (
+        "wooden_buttons",
+        &[
+            "oak_button",
+            "spruce_button",
+            "birch_button",
+            "jungle_button",
+            "acacia_button",
+            "dark_oak_button",
+            "crimson_button",
+            "warped_button",
+        ],
+    ),
+    (
+        "wooden_doors",
+        &[
+            "oak_door",
+            "spruce_door",
+            "birch_door",
+            "jungle_door",
+            "acacia_door",
+            "dark_oak_door",
+            "crimson_door",
+            "warped_door",
+        ],
+    ),
+    (
+        "wooden_fences",
+        &[
+            "oak_fence",
+            "spruce_fence",
+            "birch_fence",
+            "jungle_fence",
+            "acacia_fence",
+            "dark_oak_fence",
+            "crimson_fence",
+            "warped_fence",
+        ],
+    ),
+    (
+        "wooden_pressure_plates",
+        &[
+            "oak_pressure_plate",
+            "spruce_pressure_plate",
+            "birch_pressure_plate",
+            "jungle_pressure_plate",
+            "acacia_pressure_plate",
+            "dark_oak_pressure_plate",
+            "crimson_pressure_plate",
+            "warped_pressure_plate",
+        ],
+    ),
+    (
+        "wooden_slabs",
+        &[
+            "oak_slab",
+            "spruce_slab",
+            "birch_slab",
+            "jungle_slab",
+            "acacia_slab",
+            "dark_oak_slab",
+            "crimson_slab",
+            "warped_slab",
+        ],
+    ),
+    (
+        "wooden_stairs",
+        &[
+            "oak_stairs",
+            "spruce_stairs",
+            "birch_stairs",
+            "jungle_stairs",
+            "acacia_stairs",
+            "dark_oak_stairs",
+            "crimson_stairs",
+            "warped_stairs",
+        ],
+    ),
+    (
+        "wooden_trapdoors",
+        &[
+            "oak_trapdoor",
+            "spruce_trapdoor",
+            "birch_trapdoor",
+            "jungle_trapdoor",
+            "acacia_trapdoor",
+            "dark_oak_trapdoor",
+            "crimson_trapdoor",
+            "warped_trapdoor",
+        ],
+    ),
+    (
+        "wool",
+        &[
+            "white_wool",
+            "orange_wool",
+            "magenta_wool",
+            "light_blue_wool",
+            "yellow_wool",
+            "lime_wool",
+            "pink_wool",
+            "gray_wool",
+            "light_gray_wool",
+            "cyan_wool",
+            "purple_wool",
+            "blue_wool",
+            "brown_wool",
+            "green_wool",
+            "red_wool",
+            "black_wool",
+        ],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the tags added to a new loader, returning the blocks of the tag with the given
+    /// location in the order of their numeric IDs.
+    fn build(files: &[(&str, &str)], location: &str) -> Result<Vec<BlockId>> {
+        let mut loader = Loader::new();
+
+        for &(location, json) in files {
+            loader.add(location, json)?;
+        }
+
+        let registry = loader.build()?;
+        let tag = registry.get(location).expect("the tag should exist");
+
+        Ok(tag.iter().collect())
+    }
+
+    #[test]
+    fn later_files_add_to_tags() {
+        let files = [
+            ("example:ores", r#"{ "values": ["coal_ore"] }"#),
+            ("example:ores", r#"{ "values": ["minecraft:iron_ore"] }"#),
+        ];
+
+        assert_eq!(
+            build(&files, "example:ores").unwrap(),
+            [BlockId::IronOre, BlockId::CoalOre]
+        );
+    }
+
+    #[test]
+    fn replace_discards_earlier_files() {
+        let files = [
+            ("example:ores", r#"{ "values": ["coal_ore"] }"#),
+            (
+                "example:ores",
+                r#"{ "replace": true, "values": ["iron_ore"] }"#,
+            ),
+            ("example:ores", r#"{ "values": ["gold_ore"] }"#),
+        ];
+
+        assert_eq!(
+            build(&files, "example:ores").unwrap(),
+            [BlockId::GoldOre, BlockId::IronOre]
+        );
+
+        // Replacing only affects the tag itself, not the tags it references.
+        let mut loader = Loader::vanilla();
+        loader
+            .add(
+                "minecraft:logs",
+                r##"{ "replace": true, "values": ["#oak_logs"] }"##,
+            )
+            .unwrap();
+        let registry = loader.build().unwrap();
+
+        assert_eq!(registry.get("#logs").unwrap().len(), 4);
+        assert!(registry
+            .get("#logs_that_burn")
+            .unwrap()
+            .contains(BlockId::OakLog));
+        assert!(!registry.get("#logs").unwrap().contains(BlockId::BirchLog));
+    }
+
+    #[test]
+    fn nested_references() {
+        // References without a namespace are in the `minecraft` namespace, like in vanilla.
+        let error = build(&[("example:a", r##"{ "values": ["#a"] }"##)], "example:a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "tag `#example:a` references unknown tag `#minecraft:a`"
+        );
+
+        let files = [
+            ("example:a", r##"{ "values": ["stone", "#example:b"] }"##),
+            ("example:b", r##"{ "values": ["#example:c", "dirt"] }"##),
+            ("example:c", r#"{ "values": ["sand"] }"#),
+        ];
+
+        assert_eq!(
+            build(&files, "#example:a").unwrap(),
+            [BlockId::Stone, BlockId::Dirt, BlockId::Sand]
+        );
+
+        let registry = Registry::vanilla();
+        let logs = registry.get("minecraft:logs").unwrap();
+
+        assert!(logs.contains(BlockId::StrippedJungleWood));
+        assert!(logs.contains(BlockId::CrimsonStem));
+        assert!(registry
+            .get("#minecraft:mineable/pickaxe")
+            .unwrap()
+            .contains(BlockId::Stone));
+    }
+
+    #[test]
+    fn optional_entries() {
+        let files = [(
+            "example:a",
+            r##"{
+                    "values": [
+                        "stone",
+                        { "id": "examplemod:ruby_ore", "required": false },
+                        { "id": "#examplemod:rubies", "required": false },
+                        { "id": "dirt" }
+                    ]
+                }"##,
+        )];
+
+        assert_eq!(
+            build(&files, "example:a").unwrap(),
+            [BlockId::Stone, BlockId::Dirt]
+        );
+
+        let error = build(
+            &[("example:a", r#"{ "values": ["examplemod:ruby_ore"] }"#)],
+            "example:a",
+        )
+        .unwrap_err();
+        assert!(format!("{error:#}").starts_with("invalid entry in tag `#example:a`"));
+
+        let error = build(
+            &[("example:a", r##"{ "values": ["#examplemod:rubies"] }"##)],
+            "example:a",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "tag `#example:a` references unknown tag `#examplemod:rubies`"
+        );
+    }
+
+    #[test]
+    fn cycles() {
+        let error = build(
+            &[("example:a", r##"{ "values": ["#example:a"] }"##)],
+            "example:a",
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "tag `#example:a` references itself");
+
+        // References without a namespace are in the `minecraft` namespace, like in vanilla.
+        let error = build(&[("example:a", r##"{ "values": ["#a"] }"##)], "example:a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "tag `#example:a` references unknown tag `#minecraft:a`"
+        );
+
+        let files = [
+            ("example:a", r##"{ "values": ["stone", "#example:b"] }"##),
+            ("example:b", r##"{ "values": ["#example:c"] }"##),
+            ("example:c", r##"{ "values": ["#example:a"] }"##),
+        ];
+        let error = build(&files, "example:a").unwrap_err();
+        assert!(error.to_string().ends_with("references itself"));
+    }
+
+    #[test]
+    fn invalid_files() {
+        let mut loader = Loader::new();
+
+        assert!(loader.add("example:a", r#"{ "replace": true }"#).is_err());
+        assert!(loader.add("example:a", r#"{ "values": [1] }"#).is_err());
+        assert!(loader.add("Example:a", r#"{ "values": [] }"#).is_err());
+    }
+
+    #[test]
+    fn data_packs() {
+        let root = std::env::temp_dir().join(format!("tag-data-pack-{}", std::process::id()));
+        let dir = root.join("data/example/tags/blocks/mineable");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("hammer.json"),
+            r##"{ "values": ["#minecraft:logs"] }"##,
+        )
+        .unwrap();
+        fs::write(dir.join("notes.txt"), "not a tag").unwrap();
+
+        let mut loader = Loader::vanilla();
+        let result = loader.add_data_pack(&root);
+        fs::remove_dir_all(&root).unwrap();
+        result.unwrap();
+
+        let registry = loader.build().unwrap();
+        let tag = registry.get("#example:mineable/hammer").unwrap();
+
+        assert!(tag.contains(BlockId::OakLog));
+        assert_eq!(tag.len(), registry.get("#logs").unwrap().len());
+    }
+}
@@ -0,0 +1,182 @@
+//! Checks serializing blocks and block IDs to and from their string and compound forms.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use world_block::{Block, BlockId};
+
+fn block(s: &str) -> Block {
+    s.parse().unwrap()
+}
+
+/// A struct with a block and a block ID in each form, like a configuration file would have.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    block: Block,
+    id: BlockId,
+    #[serde(with = "world_block::compound")]
+    block_compound: Block,
+    #[serde(with = "world_block::compound")]
+    id_compound: BlockId,
+}
+
+#[test]
+fn blocks_round_trip_as_strings() {
+    let stairs = block("minecraft:oak_stairs[facing=east,half=top]");
+    let json = serde_json::to_value(stairs).unwrap();
+    assert_eq!(
+        json,
+        json!("minecraft:oak_stairs[facing=east,half=top,shape=straight,waterlogged=false]")
+    );
+    assert_eq!(serde_json::from_value::<Block>(json).unwrap(), stairs);
+
+    // Properties left out take their default values.
+    assert_eq!(
+        serde_json::from_value::<Block>(json!("minecraft:oak_stairs[half=top]")).unwrap(),
+        block("minecraft:oak_stairs[facing=north,half=top]"),
+    );
+
+    for id in [BlockId::Air, BlockId::Stone, BlockId::RedstoneWire] {
+        for block in id.states() {
+            let json = serde_json::to_value(block).unwrap();
+            assert_eq!(serde_json::from_value::<Block>(json).unwrap(), block);
+        }
+    }
+}
+
+#[test]
+fn block_ids_round_trip_as_strings() {
+    let json = serde_json::to_value(BlockId::OakStairs).unwrap();
+    assert_eq!(json, json!("minecraft:oak_stairs"));
+    assert_eq!(
+        serde_json::from_value::<BlockId>(json).unwrap(),
+        BlockId::OakStairs
+    );
+
+    // The namespace defaults to `minecraft`.
+    assert_eq!(
+        serde_json::from_value::<BlockId>(json!("stone")).unwrap(),
+        BlockId::Stone
+    );
+}
+
+#[test]
+fn blocks_and_block_ids_deserialize_from_compounds() {
+    let stairs = json!({
+        "Name": "minecraft:oak_stairs",
+        "Properties": { "facing": "east", "half": "top" },
+    });
+    assert_eq!(
+        serde_json::from_value::<Block>(stairs).unwrap(),
+        block("minecraft:oak_stairs[facing=east,half=top]"),
+    );
+
+    // Booleans and integers are accepted for property values.
+    let leaves = json!({
+        "Name": "minecraft:oak_leaves",
+        "Properties": { "distance": 3, "persistent": true },
+    });
+    assert_eq!(
+        serde_json::from_value::<Block>(leaves).unwrap(),
+        block("minecraft:oak_leaves[distance=3,persistent=true]"),
+    );
+
+    assert_eq!(
+        serde_json::from_value::<Block>(json!({ "Name": "minecraft:stone" })).unwrap(),
+        block("minecraft:stone"),
+    );
+    assert_eq!(
+        serde_json::from_value::<BlockId>(json!({ "Name": "minecraft:stone" })).unwrap(),
+        BlockId::Stone,
+    );
+}
+
+#[test]
+fn fields_with_compound_serialize_to_compounds() {
+    let config = Config {
+        block: block("minecraft:lever[powered=true]"),
+        id: BlockId::Lever,
+        block_compound: block("minecraft:lever[face=floor,facing=south]"),
+        id_compound: BlockId::Stone,
+    };
+
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "block": "minecraft:lever[face=wall,facing=north,powered=true]",
+            "id": "minecraft:lever",
+            "block_compound": {
+                "Name": "minecraft:lever",
+                "Properties": { "face": "floor", "facing": "south", "powered": "false" },
+            },
+            "id_compound": { "Name": "minecraft:stone" },
+        }),
+    );
+    assert_eq!(serde_json::from_value::<Config>(json).unwrap(), config);
+
+    // Blocks without properties have no `Properties`, and fields with compounds still read the
+    // string form.
+    let json = json!({
+        "block": { "Name": "minecraft:lever" },
+        "id": { "Name": "minecraft:lever" },
+        "block_compound": "minecraft:stone",
+        "id_compound": "minecraft:dirt",
+    });
+    let config = serde_json::from_value::<Config>(json).unwrap();
+    assert_eq!(config.block, block("minecraft:lever"));
+    assert_eq!(config.block_compound, block("minecraft:stone"));
+    assert_eq!(config.id_compound, BlockId::Dirt);
+    assert_eq!(
+        serde_json::to_value(&config).unwrap()["block_compound"],
+        json!({ "Name": "minecraft:stone" }),
+    );
+}
+
+#[test]
+fn invalid_blocks() {
+    let invalid = [
+        json!("minecraft:ruby_block"),
+        json!("minecraft:oak_stairs[facing=up]"),
+        json!("minecraft:oak_stairs[color=red]"),
+        json!("not a block"),
+        json!(3),
+        json!({ "Name": "minecraft:ruby_block" }),
+        json!({ "Name": "minecraft:oak_stairs", "Properties": { "facing": "up" } }),
+        json!({ "Name": "minecraft:oak_stairs", "Properties": { "color": "red" } }),
+        json!({ "Name": "minecraft:oak_leaves", "Properties": { "distance": 1.5 } }),
+        json!({ "Properties": { "facing": "east" } }),
+        json!({ "Name": "minecraft:stone", "Color": "red" }),
+    ];
+
+    for json in invalid {
+        assert!(
+            serde_json::from_value::<Block>(json.clone()).is_err(),
+            "{json}"
+        );
+    }
+
+    let error = serde_json::from_value::<Block>(json!({})).unwrap_err();
+    assert_eq!(error.to_string(), "missing field `Name`");
+    let error =
+        serde_json::from_str::<Block>(r#"{ "Name": "minecraft:stone", "Name": "minecraft:dirt" }"#)
+            .unwrap_err();
+    assert!(error.to_string().starts_with("duplicate `Name`"), "{error}");
+}
+
+#[test]
+fn invalid_block_ids() {
+    let invalid = [
+        json!("minecraft:ruby_block"),
+        json!("minecraft:oak_stairs[facing=east]"),
+        json!("Not A Location"),
+        json!({ "Name": "minecraft:ruby_block" }),
+        json!({ "Name": "minecraft:oak_stairs", "Properties": { "facing": "east" } }),
+    ];
+
+    for json in invalid {
+        assert!(
+            serde_json::from_value::<BlockId>(json.clone()).is_err(),
+            "{json}"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Checks the states and shapes of blocks registered at runtime. The registry is global, so every
+//! test registers blocks under its own names.
+
+use world_block::custom::{self, CustomBlock, CustomBlockId};
+use world_block::{state, Block, BlockId};
+
+#[test]
+fn state_ids_round_trip() {
+    let id = custom::register(
+        CustomBlock::new("example:ruby_slab", BlockId::OakSlab)
+            .property("glowing", state::Lit(false)),
+    )
+    .unwrap();
+    let other = custom::register(CustomBlock::new("example:ruby_block", BlockId::Stone)).unwrap();
+
+    // Slabs have 3 types and can be waterlogged.
+    assert_eq!(id.state_count(), 3 * 2 * 2);
+    assert_eq!(id.states().count(), 12);
+    assert!(other.state_ids().start >= id.state_ids().end);
+
+    for (block, state_id) in id.states().zip(id.state_ids()) {
+        assert_eq!(block.custom_id(), Some(id));
+        assert_eq!(block.id(), BlockId::OakSlab);
+        assert_eq!(block.state_id(), state_id);
+        assert_eq!(Block::from_state_id(state_id), Some(block));
+    }
+
+    assert!(id.state_ids().start > Block::from_id(BlockId::Stone).state_id());
+
+    let block = Block::from_custom(other);
+    assert_eq!(Block::from_state_id(block.state_id()), Some(block));
+}
+
+#[test]
+fn unregistered_ids() {
+    assert_eq!(CustomBlockId::from_numeric(u16::MAX), None);
+    assert_eq!(
+        CustomBlockId::from_numeric(BlockId::Stone.to_numeric()),
+        None
+    );
+    assert_eq!(
+        CustomBlockId::from_location("example:unregistered_block"),
+        None
+    );
+    assert!(Block::from_state_id(u32::MAX).is_none());
+    assert!("example:unregistered_block".parse::<Block>().is_err());
+
+    let stone = Block::from_id(BlockId::Stone);
+    assert_eq!(stone.try_id(), Some(BlockId::Stone));
+    assert_eq!(stone.custom_id(), None);
+}
+
+#[test]
+fn shapes_are_those_of_the_base_block() {
+    let id = custom::register(
+        CustomBlock::new("example:ruby_stairs", BlockId::OakStairs)
+            .property("glowing", state::Lit(false)),
+    )
+    .unwrap();
+
+    for block in id.states() {
+        let mut base = Block::from_id(BlockId::OakStairs);
+
+        for (name, property) in block.properties().filter(|&(name, _)| name != "glowing") {
+            let offset = base
+                .state_definition()
+                .properties
+                .iter()
+                .find(|definition| definition.name == name)
+                .unwrap()
+                .offset;
+            base.set_property(property, offset);
+        }
+
+        assert_eq!(block.try_id(), Some(BlockId::OakStairs));
+        assert_eq!(block.outline_shape(), base.outline_shape(), "{block}");
+        assert_eq!(block.collision_shape(), base.collision_shape(), "{block}");
+        assert_eq!(block.occlusion_shape(), base.occlusion_shape(), "{block}");
+    }
+}
+
+#[test]
+fn shapes_follow_the_behavior_properties() {
+    let id = custom::register(
+        CustomBlock::new("example:ghost_slab", BlockId::OakSlab).behavior(|properties| {
+            properties.has_collision = false;
+            properties.can_occlude = false;
+        }),
+    )
+    .unwrap();
+    let block: Block = "example:ghost_slab[type=bottom]".parse().unwrap();
+    let base: Block = "minecraft:oak_slab[type=bottom]".parse().unwrap();
+
+    assert_eq!(block.custom_id(), Some(id));
+    assert_eq!(block.outline_shape(), base.outline_shape());
+    assert!(block.collision_shape().is_empty());
+    assert!(block.occlusion_shape().is_empty());
+    assert!(!base.collision_shape().is_empty());
+}
+
+#[test]
+fn failed_registrations() {
+    let errors = [
+        CustomBlock::new("minecraft:ruby_block", BlockId::Stone),
+        CustomBlock::new("example:wet_slab", BlockId::OakSlab)
+            .property("waterlogged", state::Lit(false)),
+        CustomBlock::new("example:wet_slab", BlockId::Stone)
+            .property("wet", state::Lit(false))
+            .property("wet", state::Lit(true)),
+        (0..14).fold(
+            CustomBlock::new("example:wet_slab", BlockId::OakSlab),
+            |block, i| block.property(&format!("wet_{i}"), state::Lit(false)),
+        ),
+    ];
+
+    for block in errors {
+        assert!(custom::register(block).is_err());
+    }
+
+    // Failed registrations leave nothing behind, so the location can still be registered.
+    assert_eq!(CustomBlockId::from_location("example:wet_slab"), None);
+
+    let id = custom::register((0..13).fold(
+        CustomBlock::new("example:wet_slab", BlockId::OakSlab),
+        |block, i| block.property(&format!("wet_{i}"), state::Lit(false)),
+    ))
+    .unwrap();
+    assert_eq!(CustomBlockId::from_location("example:wet_slab"), Some(id));
+
+    let error = custom::register(CustomBlock::new("example:wet_slab", BlockId::Stone)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "custom block `example:wet_slab` is already registered"
+    );
+    assert_eq!(id.base(), BlockId::OakSlab);
+}
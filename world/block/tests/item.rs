@@ -0,0 +1,108 @@
+//! Checks reading item stacks from their NBT representation in containers.
+
+use quartz_nbt::{compound, NbtCompound, NbtList};
+use world_block::item::{ItemId, ItemStack, Rarity};
+use world_resource::Location;
+
+/// Returns the `Items` list of a chest block entity, as saved in a chunk's `block_entities`.
+fn chest_items() -> NbtList {
+    let block_entity = compound! {
+        "id": "minecraft:chest",
+        "x": 12,
+        "y": 64,
+        "z": -3,
+        "keepPacked": false,
+        "Items": [
+            { "Slot": 0i8, "id": "minecraft:cobblestone", "Count": 64i8 },
+            { "Slot": 13i8, "id": "diamond_pickaxe", "Count": 1i8, "tag": {
+                "Damage": 12,
+                "Enchantments": [
+                    { "id": "minecraft:efficiency", "lvl": 5i16 },
+                    { "id": "minecraft:unbreaking", "lvl": 3i16 },
+                ],
+            }},
+            { "Slot": 26i8, "id": "minecraft:enchanted_book", "Count": 1i8, "tag": {
+                "StoredEnchantments": [{ "id": "minecraft:sharpness", "lvl": 2i16 }],
+            }},
+        ],
+    };
+
+    block_entity.get::<_, &NbtList>("Items").unwrap().clone()
+}
+
+#[test]
+fn chest_items_are_parsed_with_their_slots() {
+    let items = ItemStack::parse_items(&chest_items()).unwrap();
+    let slots: Vec<_> = items.iter().map(|&(slot, _)| slot).collect();
+
+    assert_eq!(slots, [0, 13, 26]);
+    assert_eq!(items[0].1, ItemStack::new(ItemId::Cobblestone, 64));
+
+    let pickaxe = &items[1].1;
+    let efficiency = Location::new("minecraft:efficiency").unwrap();
+    let sharpness = Location::new("sharpness").unwrap();
+
+    assert_eq!(pickaxe.id, ItemId::DiamondPickaxe);
+    assert_eq!(pickaxe.count, 1);
+    assert_eq!(
+        pickaxe
+            .nbt
+            .as_ref()
+            .unwrap()
+            .get::<_, i32>("Damage")
+            .unwrap(),
+        12
+    );
+    assert!(pickaxe.is_enchanted());
+    assert_eq!(pickaxe.enchantment_level(efficiency), 5);
+    assert_eq!(pickaxe.enchantment_level(sharpness), 0);
+    assert_eq!(pickaxe.rarity(), Rarity::Rare);
+
+    // Stored enchantments don't count.
+    let book = &items[2].1;
+    assert!(!book.is_enchanted());
+    assert_eq!(book.enchantment_level(sharpness), 0);
+    assert_eq!(book.rarity(), Rarity::Uncommon);
+}
+
+#[test]
+fn slots_above_127_are_unsigned() {
+    let items = NbtList::from(vec![compound! {
+        "Slot": -106i8,
+        "id": "minecraft:stone",
+        "Count": 1i8,
+    }]);
+
+    assert_eq!(ItemStack::parse_items(&items).unwrap()[0].0, 150);
+}
+
+#[test]
+fn non_positive_counts_are_empty_stacks() {
+    for count in [0i8, -1, -128] {
+        let tag = compound! { "id": "minecraft:stone", "Count": count };
+        let stack = ItemStack::try_from(&tag).unwrap();
+
+        assert_eq!(stack.count, 0);
+        assert!(stack.is_empty());
+    }
+
+    let air = compound! { "id": "minecraft:air", "Count": 1i8 };
+    assert!(ItemStack::try_from(&air).unwrap().is_empty());
+}
+
+#[test]
+fn invalid_stacks() {
+    let cases: [NbtCompound; 4] = [
+        compound! { "id": "examplemod:ruby", "Count": 1i8 },
+        compound! { "id": "Minecraft:Stone", "Count": 1i8 },
+        compound! { "id": "minecraft:stone" },
+        compound! { "id": "minecraft:stone", "Count": "1" },
+    ];
+
+    for tag in &cases {
+        assert!(ItemStack::try_from(tag).is_err(), "{tag}");
+    }
+
+    let without_slot = NbtList::from(vec![compound! { "id": "minecraft:stone", "Count": 1i8 }]);
+    assert!(ItemStack::parse_items(&without_slot).is_err());
+}
@@ -0,0 +1,283 @@
+//! Rolls the vanilla block loot tables with fixed seeds, and checks the drops and their counts.
+
+use quartz_nbt::{compound, NbtCompound, NbtList};
+use world_block::item::{ItemId, ItemStack};
+use world_block::loot::{Context, Registry};
+use world_block::random::Random;
+use world_block::Block;
+
+/// Returns a stack of one of the item with the given enchantment.
+fn enchanted(id: ItemId, enchantment: &str, level: i16) -> ItemStack {
+    let mut enchantments = NbtList::new();
+    enchantments.push(compound! { "id": enchantment, "lvl": level });
+    let mut nbt = NbtCompound::new();
+    nbt.insert("Enchantments", enchantments);
+
+    ItemStack {
+        id,
+        count: 1,
+        nbt: Some(nbt),
+    }
+}
+
+/// Returns the drops of the block broken with the given stack.
+fn drops(block: &str, tool: Option<&ItemStack>, seed: u64) -> Vec<ItemStack> {
+    let block: Block = block.parse().unwrap();
+
+    block.drops(tool, &mut Random::new(seed))
+}
+
+/// Returns 1000 seeds. Like in vanilla, consecutive seeds start with nearly the same numbers, so
+/// they're spread out.
+fn seeds() -> impl Iterator<Item = u64> {
+    (0..1000).map(|i| i * 0x9e37_79b9)
+}
+
+/// Returns the number of the item dropped by the block for each of the seeds.
+fn counts(block: &str, tool: Option<&ItemStack>, item: ItemId) -> Vec<u32> {
+    seeds()
+        .map(|seed| {
+            drops(block, tool, seed)
+                .iter()
+                .filter(|stack| stack.id == item)
+                .map(|stack| u32::from(stack.count))
+                .sum()
+        })
+        .collect()
+}
+
+/// Returns the distinct values of `counts`, sorted, and their mean.
+#[allow(clippy::cast_precision_loss)]
+fn summary(counts: &[u32]) -> (Vec<u32>, f64) {
+    let mut values = counts.to_vec();
+    values.sort_unstable();
+    values.dedup();
+
+    let mean = f64::from(counts.iter().sum::<u32>()) / counts.len() as f64;
+
+    (values, mean)
+}
+
+#[test]
+fn ore_drops() {
+    let pickaxe = ItemStack::new(ItemId::DiamondPickaxe, 1);
+    let diamonds = counts("minecraft:diamond_ore", Some(&pickaxe), ItemId::Diamond);
+    assert!(diamonds.iter().all(|&count| count == 1));
+
+    // Fortune III multiplies the count by 1, 1, 2, 3 or 4 with equal chances, 2.2 on average.
+    let pickaxe = enchanted(ItemId::DiamondPickaxe, "minecraft:fortune", 3);
+    let (values, mean) = summary(&counts(
+        "minecraft:diamond_ore",
+        Some(&pickaxe),
+        ItemId::Diamond,
+    ));
+    assert_eq!(values, [1, 2, 3, 4]);
+    assert!((2.0..2.4).contains(&mean), "{mean}");
+
+    // Copper ore drops 2 to 5 raw copper before the multiplier.
+    let (values, _) = summary(&counts(
+        "minecraft:copper_ore",
+        Some(&pickaxe),
+        ItemId::RawCopper,
+    ));
+    assert_eq!(values.first(), Some(&2));
+    assert_eq!(values.last(), Some(&20));
+}
+
+#[test]
+fn binomial_with_bonus_count() {
+    // Grown wheat drops 1 seed, plus one for each of 3 trials with a chance of 4/7.
+    let (values, mean) = summary(&counts("minecraft:wheat[age=7]", None, ItemId::WheatSeeds));
+    assert_eq!(values, [1, 2, 3, 4]);
+    assert!((2.6..2.85).contains(&mean), "{mean}");
+
+    // Each level of fortune adds a trial.
+    let hoe = enchanted(ItemId::IronHoe, "minecraft:fortune", 3);
+    let (values, mean) = summary(&counts(
+        "minecraft:wheat[age=7]",
+        Some(&hoe),
+        ItemId::WheatSeeds,
+    ));
+    assert_eq!(values, [1, 2, 3, 4, 5, 6, 7]);
+    assert!((4.3..4.55).contains(&mean), "{mean}");
+
+    // Young wheat only drops its seed, whatever the fortune.
+    assert_eq!(
+        drops("minecraft:wheat[age=3]", Some(&hoe), 0),
+        [ItemStack::new(ItemId::WheatSeeds, 1)],
+    );
+}
+
+#[test]
+fn explosion_decay() {
+    let clay: Block = "minecraft:clay".parse().unwrap();
+    let table = Registry::vanilla().get_block(clay.id()).unwrap();
+    let roll = |explosion_radius, seed| {
+        let context = Context {
+            block: clay,
+            tool: None,
+            explosion_radius,
+        };
+
+        table
+            .roll(&context, &mut Random::new(seed))
+            .iter()
+            .map(|stack| u32::from(stack.count))
+            .sum::<u32>()
+    };
+
+    // Each of the 4 clay balls survives an explosion with a chance of one over its radius.
+    assert!((0..100).all(|seed| roll(None, seed) == 4));
+    assert!((0..100).all(|seed| roll(Some(1.0), seed) == 4));
+
+    let counts = seeds()
+        .map(|seed| roll(Some(4.0), seed))
+        .collect::<Vec<_>>();
+    let (values, mean) = summary(&counts);
+    assert_eq!(values, [0, 1, 2, 3, 4]);
+    assert!((0.9..1.1).contains(&mean), "{mean}");
+
+    // Blocks dropping themselves survive as a whole, or not at all.
+    let stone: Block = "minecraft:cobblestone".parse().unwrap();
+    let table = Registry::vanilla().get_block(stone.id()).unwrap();
+    let survived = seeds()
+        .filter(|&seed| {
+            let context = Context {
+                block: stone,
+                tool: None,
+                explosion_radius: Some(2.0),
+            };
+
+            !table.roll(&context, &mut Random::new(seed)).is_empty()
+        })
+        .count();
+    assert!((450..550).contains(&survived), "{survived}");
+}
+
+#[test]
+fn silk_touch_alternatives() {
+    let pickaxe = ItemStack::new(ItemId::DiamondPickaxe, 1);
+    let silk_touch = enchanted(ItemId::DiamondPickaxe, "minecraft:silk_touch", 1);
+    let shovel = ItemStack::new(ItemId::DiamondShovel, 1);
+    let silk_touch_shovel = enchanted(ItemId::DiamondShovel, "minecraft:silk_touch", 1);
+    let shears = ItemStack::new(ItemId::Shears, 1);
+    let one = |id| vec![ItemStack::new(id, 1)];
+
+    assert_eq!(
+        drops("minecraft:stone", Some(&silk_touch), 0),
+        one(ItemId::Stone)
+    );
+    assert_eq!(
+        drops("minecraft:stone", Some(&pickaxe), 0),
+        one(ItemId::Cobblestone)
+    );
+    assert_eq!(drops("minecraft:stone", None, 0), []);
+    assert_eq!(
+        drops("minecraft:glass", Some(&silk_touch), 0),
+        one(ItemId::Glass)
+    );
+    assert_eq!(drops("minecraft:glass", Some(&pickaxe), 0), []);
+    assert_eq!(
+        drops("minecraft:grass_block", Some(&silk_touch_shovel), 0),
+        one(ItemId::GrassBlock),
+    );
+    assert_eq!(
+        drops("minecraft:grass_block", Some(&shovel), 0),
+        one(ItemId::Dirt)
+    );
+
+    // Silk touch takes precedence over fortune.
+    let mut both = silk_touch.clone();
+    let enchantments = both.nbt.as_mut().unwrap();
+    let list = enchantments
+        .get_mut::<_, &mut NbtList>("Enchantments")
+        .unwrap();
+    list.push(compound! { "id": "minecraft:fortune", "lvl": 3i16 });
+    assert!((0..100).all(|seed| {
+        drops("minecraft:diamond_ore", Some(&both), seed) == one(ItemId::DiamondOre)
+    }));
+
+    // Leaves drop themselves with either shears or silk touch, and saplings or sticks otherwise.
+    assert_eq!(
+        drops("minecraft:oak_leaves", Some(&shears), 0),
+        one(ItemId::OakLeaves)
+    );
+    assert_eq!(
+        drops("minecraft:oak_leaves", Some(&silk_touch), 0),
+        one(ItemId::OakLeaves)
+    );
+    assert!((0..100).all(|seed| {
+        drops("minecraft:oak_leaves", None, seed)
+            .iter()
+            .all(|stack| matches!(stack.id, ItemId::OakSapling | ItemId::Stick | ItemId::Apple))
+    }));
+}
+
+#[test]
+fn seeded_drops() {
+    // With a chance of 0.1, gravel drops flint if the first float is below it: about 0.086 for
+    // seed 5000, and 0.73 for seed 0.
+    let shovel = ItemStack::new(ItemId::IronShovel, 1);
+    assert_eq!(
+        drops("minecraft:gravel", Some(&shovel), 5000),
+        [ItemStack::new(ItemId::Flint, 1)],
+    );
+    assert_eq!(
+        drops("minecraft:gravel", Some(&shovel), 0),
+        [ItemStack::new(ItemId::Gravel, 1)],
+    );
+
+    // The floats following seed 0 are 0.73, 0.83 and 0.24, so one of the 3 trials for extra seeds
+    // succeeds.
+    assert_eq!(
+        drops("minecraft:wheat[age=7]", None, 0),
+        [
+            ItemStack::new(ItemId::Wheat, 1),
+            ItemStack::new(ItemId::WheatSeeds, 2),
+        ],
+    );
+
+    for seed in 0..100 {
+        assert_eq!(
+            drops("minecraft:wheat[age=7]", None, seed),
+            drops("minecraft:wheat[age=7]", None, seed),
+        );
+    }
+}
+
+#[test]
+fn data_pack_tables_replace_vanilla_ones() {
+    let mut registry = Registry::vanilla().clone();
+    registry
+        .add(
+            "minecraft:blocks/dirt",
+            r#"{
+                "type": "minecraft:block",
+                "pools": [{
+                    "rolls": 1.0,
+                    "entries": [{
+                        "type": "minecraft:item",
+                        "name": "minecraft:diamond",
+                        "functions": [{ "function": "minecraft:set_count", "count": 3.0 }]
+                    }]
+                }]
+            }"#,
+        )
+        .unwrap();
+
+    let dirt: Block = "minecraft:dirt".parse().unwrap();
+    let context = Context {
+        block: dirt,
+        tool: None,
+        explosion_radius: None,
+    };
+    let table = registry.get_block(dirt.id()).unwrap();
+    assert_eq!(
+        table.roll(&context, &mut Random::new(0)),
+        [ItemStack::new(ItemId::Diamond, 3)],
+    );
+    assert!(registry.get("blocks/stone").is_some());
+    assert!(registry
+        .add("minecraft:blocks/dirt", "{ \"pools\": 1 }")
+        .is_err());
+}
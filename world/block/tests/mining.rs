@@ -0,0 +1,151 @@
+//! Checks how long it takes to break blocks against the times listed for vanilla 1.18.2.
+
+use world_block::item::ItemId;
+use world_block::mining::Conditions;
+use world_block::{Block, BlockId};
+
+/// Returns the number of ticks it takes to break the block with the given item, with otherwise
+/// the given conditions.
+fn ticks(block: &str, tool: Option<ItemId>, conditions: Conditions) -> Option<u32> {
+    let block: Block = block.parse().unwrap();
+
+    block.break_ticks(Conditions { tool, ..conditions })
+}
+
+#[test]
+fn break_ticks() {
+    use ItemId::*;
+
+    let default = Conditions::default();
+    let cases = [
+        // Obsidian takes 9.4 seconds with a diamond pickaxe.
+        ("minecraft:obsidian", Some(DiamondPickaxe), 188),
+        // Harvesting stone by hand isn't possible, so it takes longer: 7.5 seconds.
+        ("minecraft:stone", None, 150),
+        ("minecraft:stone", Some(WoodenPickaxe), 23),
+        ("minecraft:stone", Some(DiamondPickaxe), 6),
+        // Items that aren't the correct tool mine like an empty hand.
+        ("minecraft:stone", Some(DiamondAxe), 150),
+        ("minecraft:stone", Some(Stick), 150),
+        // Pickaxes of a too low tier mine fast, but can't harvest the block.
+        ("minecraft:diamond_ore", Some(StonePickaxe), 75),
+        ("minecraft:diamond_ore", Some(IronPickaxe), 15),
+        ("minecraft:oak_log", None, 60),
+        ("minecraft:oak_log", Some(GoldenAxe), 5),
+        ("minecraft:dirt", Some(NetheriteShovel), 2),
+        // Shears break leaves instantly and swords speed them up.
+        ("minecraft:oak_leaves", Some(Shears), 0),
+        ("minecraft:oak_leaves", Some(IronSword), 4),
+        ("minecraft:cobweb", Some(IronSword), 8),
+        ("minecraft:cobweb", Some(Shears), 8),
+        ("minecraft:cobweb", None, 400),
+        ("minecraft:white_wool", Some(Shears), 5),
+        ("minecraft:white_wool", None, 24),
+        ("minecraft:pumpkin", Some(WoodenSword), 20),
+        ("minecraft:pumpkin", None, 30),
+        ("minecraft:torch", None, 0),
+    ];
+
+    for (block, tool, expected) in cases {
+        assert_eq!(
+            ticks(block, tool, default),
+            Some(expected),
+            "{block} with {tool:?}"
+        );
+    }
+
+    assert_eq!(
+        ticks("minecraft:bedrock", Some(NetheritePickaxe), default),
+        None
+    );
+    assert_eq!(ticks("minecraft:end_portal", None, default), None);
+}
+
+#[test]
+fn efficiency_and_haste() {
+    let tool = Some(ItemId::DiamondPickaxe);
+    let efficiency = Conditions {
+        efficiency: 5,
+        ..Conditions::default()
+    };
+    let haste = Conditions {
+        efficiency: 5,
+        haste: 2,
+        ..Conditions::default()
+    };
+
+    // Efficiency V alone doesn't mine stone instantly, but with Haste II it does.
+    assert_eq!(ticks("minecraft:stone", tool, efficiency), Some(2));
+    assert_eq!(ticks("minecraft:stone", tool, haste), Some(0));
+    assert_eq!(ticks("minecraft:obsidian", tool, efficiency), Some(45));
+    assert_eq!(ticks("minecraft:obsidian", tool, haste), Some(32));
+
+    // Efficiency doesn't help with blocks the tool isn't meant for.
+    assert_eq!(ticks("minecraft:oak_log", tool, efficiency), Some(60));
+    assert_eq!(ticks("minecraft:oak_log", tool, haste), Some(43));
+}
+
+#[test]
+fn mining_fatigue() {
+    let tool = Some(ItemId::DiamondPickaxe);
+    let fatigue = |level| Conditions {
+        mining_fatigue: level,
+        ..Conditions::default()
+    };
+
+    assert_eq!(ticks("minecraft:stone", tool, fatigue(1)), Some(19));
+    assert_eq!(ticks("minecraft:stone", tool, fatigue(2)), Some(63));
+    assert_eq!(ticks("minecraft:stone", tool, fatigue(3)), Some(2084));
+}
+
+#[test]
+fn underwater_and_airborne() {
+    let tool = Some(ItemId::DiamondPickaxe);
+    let underwater = Conditions {
+        underwater: true,
+        ..Conditions::default()
+    };
+    let airborne = Conditions {
+        airborne: true,
+        ..Conditions::default()
+    };
+    let both = Conditions {
+        underwater: true,
+        airborne: true,
+        ..Conditions::default()
+    };
+
+    // Each is 5 times slower, and both together 25 times.
+    assert_eq!(ticks("minecraft:stone", tool, underwater), Some(29));
+    assert_eq!(ticks("minecraft:stone", tool, airborne), Some(29));
+    assert_eq!(ticks("minecraft:stone", tool, both), Some(141));
+}
+
+#[test]
+fn harvesting() {
+    use ItemId::*;
+
+    let cases = [
+        ("minecraft:stone", None, false),
+        ("minecraft:stone", Some(WoodenPickaxe), true),
+        ("minecraft:iron_ore", Some(WoodenPickaxe), false),
+        ("minecraft:iron_ore", Some(StonePickaxe), true),
+        ("minecraft:diamond_ore", Some(StonePickaxe), false),
+        ("minecraft:diamond_ore", Some(IronPickaxe), true),
+        ("minecraft:obsidian", Some(IronPickaxe), false),
+        ("minecraft:obsidian", Some(DiamondPickaxe), true),
+        ("minecraft:obsidian", Some(GoldenPickaxe), false),
+        ("minecraft:oak_log", None, true),
+        ("minecraft:cobweb", None, false),
+        ("minecraft:cobweb", Some(StoneSword), true),
+        ("minecraft:cobweb", Some(Shears), true),
+    ];
+
+    for (block, tool, expected) in cases {
+        let block: Block = block.parse().unwrap();
+
+        assert_eq!(block.can_harvest(tool), expected, "{block} with {tool:?}");
+    }
+
+    assert!(!Block::from_id(BlockId::Stone).can_harvest(Some(DiamondShovel)));
+}
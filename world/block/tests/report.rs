@@ -0,0 +1,87 @@
+//! Checks the block registry against vanilla's `blocks.json` report it's generated from.
+
+use std::sync::LazyLock;
+
+use serde_json::{Map, Value};
+use world_block::{Block, BlockId};
+
+static REPORT: LazyLock<Map<String, Value>> = LazyLock::new(|| {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/generated/reports/blocks.json");
+    let report = std::fs::read_to_string(path).unwrap();
+
+    serde_json::from_str(&report).unwrap()
+});
+
+/// Returns the states of the block with the given name listed in the report.
+fn states(name: &str) -> &'static [Value] {
+    REPORT[name]["states"].as_array().unwrap()
+}
+
+/// Parses a state listed in the report as a block of the given name.
+fn parse_state(name: &str, state: &Value) -> Block {
+    let properties = state
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(name, value)| format!("{name}={}", value.as_str().unwrap()))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default();
+
+    format!("{name}[{properties}]").parse().unwrap()
+}
+
+#[test]
+fn every_block_is_in_the_report() {
+    assert_eq!(REPORT.len(), BlockId::iter().count());
+
+    for id in BlockId::iter() {
+        assert!(
+            REPORT.contains_key(&format!("minecraft:{}", id.to_location())),
+            "{id:?}"
+        );
+    }
+}
+
+#[test]
+fn default_states() {
+    for (name, _) in REPORT.iter() {
+        let default = states(name)
+            .iter()
+            .find(|state| state["default"].as_bool() == Some(true))
+            .unwrap();
+        let id: BlockId = name.strip_prefix("minecraft:").unwrap().parse().unwrap();
+
+        assert_eq!(Block::from_id(id), parse_state(name, default), "{name}");
+    }
+}
+
+#[test]
+fn state_ids() {
+    let mut state_count = 0;
+
+    for (name, _) in REPORT.iter() {
+        for state in states(name) {
+            let block = parse_state(name, state);
+            let state_id = u32::try_from(state["id"].as_u64().unwrap()).unwrap();
+
+            assert_eq!(block.state_id(), state_id, "{block}");
+            assert_eq!(Block::from_state_id(state_id), Some(block), "{state_id}");
+            state_count += 1;
+        }
+    }
+
+    assert_eq!(Block::from_state_id(state_count), None);
+}
+
+#[test]
+fn states_follow_state_ids() {
+    for id in BlockId::iter() {
+        let state_ids = id.states().map(Block::state_id).collect::<Vec<_>>();
+
+        assert_eq!(state_ids, id.state_ids().collect::<Vec<_>>(), "{id:?}");
+    }
+}
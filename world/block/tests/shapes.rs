@@ -0,0 +1,306 @@
+//! Compares the shapes of blocks with the boxes vanilla 1.18.2 declares for them, and the shapes
+//! of every block state with the table in `tests/shapes.json`.
+//!
+//! The table has the outline, collision and occlusion shape of every state in the order of the
+//! global state IDs, as indices into its list of shapes, or a single index if all states of the
+//! block have the same shape. It was dumped from this crate after checking the cases below
+//! against vanilla, as there was no shape dump of vanilla at hand, so outside of these cases it
+//! guards against regressions rather than proving the shapes match vanilla. Replacing it with a
+//! dump of vanilla in the same format checks every state against vanilla.
+
+use std::sync::LazyLock;
+
+use serde_json::Value;
+use world_block::shape::{Aabb, VoxelShape};
+use world_block::state::Facing;
+use world_block::{Block, BlockId};
+
+/// The boxes of the shapes of a block state, in pixels.
+struct Shapes {
+    outline: &'static [[f64; 6]],
+    collision: &'static [[f64; 6]],
+    occlusion: &'static [[f64; 6]],
+}
+
+impl Shapes {
+    /// Shapes that are the same for outline, collision and occlusion.
+    const fn same(boxes: &'static [[f64; 6]]) -> Self {
+        Shapes {
+            outline: boxes,
+            collision: boxes,
+            occlusion: boxes,
+        }
+    }
+}
+
+const FULL: &[[f64; 6]] = &[[0.0, 0.0, 0.0, 16.0, 16.0, 16.0]];
+const BOTTOM_SLAB: [f64; 6] = [0.0, 0.0, 0.0, 16.0, 8.0, 16.0];
+const TOP_SLAB: [f64; 6] = [0.0, 8.0, 0.0, 16.0, 16.0, 16.0];
+
+#[rustfmt::skip]
+const CASES: &[(&str, Shapes)] = &[
+    // Slabs
+    ("oak_slab[type=bottom]", Shapes::same(&[BOTTOM_SLAB])),
+    ("oak_slab[type=top]", Shapes::same(&[TOP_SLAB])),
+    ("oak_slab[type=double]", Shapes::same(FULL)),
+    ("smooth_stone_slab[type=top,waterlogged=true]", Shapes::same(&[TOP_SLAB])),
+
+    // Stairs
+    ("oak_stairs[facing=north,half=bottom,shape=straight]", Shapes::same(&[
+        BOTTOM_SLAB, [0.0, 8.0, 0.0, 16.0, 16.0, 8.0],
+    ])),
+    ("oak_stairs[facing=east,half=bottom,shape=straight]", Shapes::same(&[
+        BOTTOM_SLAB, [8.0, 8.0, 0.0, 16.0, 16.0, 16.0],
+    ])),
+    ("oak_stairs[facing=south,half=top,shape=straight]", Shapes::same(&[
+        TOP_SLAB, [0.0, 0.0, 8.0, 16.0, 8.0, 16.0],
+    ])),
+    ("oak_stairs[facing=west,half=top,shape=straight]", Shapes::same(&[
+        TOP_SLAB, [0.0, 0.0, 0.0, 8.0, 8.0, 16.0],
+    ])),
+    ("oak_stairs[facing=north,half=bottom,shape=inner_left]", Shapes::same(&[
+        BOTTOM_SLAB, [0.0, 8.0, 0.0, 16.0, 16.0, 8.0], [0.0, 8.0, 8.0, 8.0, 16.0, 16.0],
+    ])),
+    ("oak_stairs[facing=north,half=bottom,shape=inner_right]", Shapes::same(&[
+        BOTTOM_SLAB, [0.0, 8.0, 0.0, 16.0, 16.0, 8.0], [8.0, 8.0, 8.0, 16.0, 16.0, 16.0],
+    ])),
+    ("oak_stairs[facing=north,half=bottom,shape=outer_left]", Shapes::same(&[
+        BOTTOM_SLAB, [0.0, 8.0, 0.0, 8.0, 16.0, 8.0],
+    ])),
+    ("oak_stairs[facing=north,half=bottom,shape=outer_right]", Shapes::same(&[
+        BOTTOM_SLAB, [8.0, 8.0, 0.0, 16.0, 16.0, 8.0],
+    ])),
+    ("oak_stairs[facing=east,half=top,shape=inner_left]", Shapes::same(&[
+        TOP_SLAB, [8.0, 0.0, 0.0, 16.0, 8.0, 16.0], [0.0, 0.0, 0.0, 8.0, 8.0, 8.0],
+    ])),
+    ("oak_stairs[facing=south,half=top,shape=outer_right]", Shapes::same(&[
+        TOP_SLAB, [0.0, 0.0, 8.0, 8.0, 8.0, 16.0],
+    ])),
+    ("oak_stairs[facing=west,half=bottom,shape=outer_left]", Shapes::same(&[
+        BOTTOM_SLAB, [0.0, 8.0, 8.0, 8.0, 16.0, 16.0],
+    ])),
+
+    // Fences
+    ("oak_fence[north=false,east=false,south=false,west=false]", Shapes {
+        outline: &[[6.0, 0.0, 6.0, 10.0, 16.0, 10.0]],
+        collision: &[[6.0, 0.0, 6.0, 10.0, 24.0, 10.0]],
+        occlusion: &[[6.0, 0.0, 6.0, 10.0, 16.0, 10.0]],
+    }),
+    ("oak_fence[north=true,east=true,south=false,west=false]", Shapes {
+        outline: &[
+            [6.0, 0.0, 6.0, 10.0, 16.0, 10.0],
+            [6.0, 0.0, 0.0, 10.0, 16.0, 10.0],
+            [6.0, 0.0, 6.0, 16.0, 16.0, 10.0],
+        ],
+        collision: &[
+            [6.0, 0.0, 6.0, 10.0, 24.0, 10.0],
+            [6.0, 0.0, 0.0, 10.0, 24.0, 10.0],
+            [6.0, 0.0, 6.0, 16.0, 24.0, 10.0],
+        ],
+        occlusion: &[
+            [6.0, 0.0, 6.0, 10.0, 16.0, 10.0],
+            [7.0, 6.0, 0.0, 9.0, 15.0, 9.0],
+            [7.0, 6.0, 7.0, 16.0, 15.0, 9.0],
+        ],
+    }),
+    ("nether_brick_fence[north=false,east=false,south=true,west=true]", Shapes {
+        outline: &[
+            [6.0, 0.0, 6.0, 10.0, 16.0, 10.0],
+            [6.0, 0.0, 6.0, 10.0, 16.0, 16.0],
+            [0.0, 0.0, 6.0, 10.0, 16.0, 10.0],
+        ],
+        collision: &[
+            [6.0, 0.0, 6.0, 10.0, 24.0, 10.0],
+            [6.0, 0.0, 6.0, 10.0, 24.0, 16.0],
+            [0.0, 0.0, 6.0, 10.0, 24.0, 10.0],
+        ],
+        occlusion: &[
+            [6.0, 0.0, 6.0, 10.0, 16.0, 10.0],
+            [7.0, 6.0, 7.0, 9.0, 15.0, 16.0],
+            [0.0, 6.0, 7.0, 9.0, 15.0, 9.0],
+        ],
+    }),
+
+    // Walls
+    ("cobblestone_wall[up=true,north=none,east=none,south=none,west=none]", Shapes {
+        outline: &[[4.0, 0.0, 4.0, 12.0, 16.0, 12.0]],
+        collision: &[[4.0, 0.0, 4.0, 12.0, 24.0, 12.0]],
+        occlusion: &[[4.0, 0.0, 4.0, 12.0, 16.0, 12.0]],
+    }),
+    ("cobblestone_wall[up=true,north=low,east=tall,south=none,west=none]", Shapes {
+        outline: &[
+            [4.0, 0.0, 4.0, 12.0, 16.0, 12.0],
+            [5.0, 0.0, 0.0, 11.0, 14.0, 11.0],
+            [5.0, 0.0, 5.0, 16.0, 16.0, 11.0],
+        ],
+        collision: &[
+            [4.0, 0.0, 4.0, 12.0, 24.0, 12.0],
+            [5.0, 0.0, 0.0, 11.0, 24.0, 11.0],
+            [5.0, 0.0, 5.0, 16.0, 24.0, 11.0],
+        ],
+        occlusion: &[
+            [4.0, 0.0, 4.0, 12.0, 16.0, 12.0],
+            [5.0, 0.0, 0.0, 11.0, 14.0, 11.0],
+            [5.0, 0.0, 5.0, 16.0, 16.0, 11.0],
+        ],
+    }),
+    ("stone_brick_wall[up=false,north=none,east=low,south=none,west=low]", Shapes {
+        outline: &[[0.0, 0.0, 5.0, 16.0, 14.0, 11.0]],
+        collision: &[[0.0, 0.0, 5.0, 16.0, 24.0, 11.0]],
+        occlusion: &[[0.0, 0.0, 5.0, 16.0, 14.0, 11.0]],
+    }),
+    ("stone_brick_wall[up=false,north=tall,east=none,south=tall,west=none]", Shapes {
+        outline: &[[5.0, 0.0, 0.0, 11.0, 16.0, 16.0]],
+        collision: &[[5.0, 0.0, 0.0, 11.0, 24.0, 16.0]],
+        occlusion: &[[5.0, 0.0, 0.0, 11.0, 16.0, 16.0]],
+    }),
+
+    // Carpets
+    ("white_carpet", Shapes::same(&[[0.0, 0.0, 0.0, 16.0, 1.0, 16.0]])),
+    ("moss_carpet", Shapes::same(&[[0.0, 0.0, 0.0, 16.0, 1.0, 16.0]])),
+
+    // Blocks without collision, which can't occlude either
+    ("cobweb", Shapes {
+        outline: FULL,
+        collision: &[],
+        occlusion: &[],
+    }),
+    ("dandelion", Shapes {
+        outline: &[[5.0, 0.0, 5.0, 11.0, 10.0, 11.0]],
+        collision: &[],
+        occlusion: &[],
+    }),
+
+    // Snow layers
+    ("snow[layers=1]", Shapes {
+        outline: &[[0.0, 0.0, 0.0, 16.0, 2.0, 16.0]],
+        collision: &[],
+        occlusion: &[[0.0, 0.0, 0.0, 16.0, 2.0, 16.0]],
+    }),
+    ("snow[layers=2]", Shapes {
+        outline: &[[0.0, 0.0, 0.0, 16.0, 4.0, 16.0]],
+        collision: &[[0.0, 0.0, 0.0, 16.0, 2.0, 16.0]],
+        occlusion: &[[0.0, 0.0, 0.0, 16.0, 4.0, 16.0]],
+    }),
+    ("snow[layers=5]", Shapes {
+        outline: &[[0.0, 0.0, 0.0, 16.0, 10.0, 16.0]],
+        collision: &[[0.0, 0.0, 0.0, 16.0, 8.0, 16.0]],
+        occlusion: &[[0.0, 0.0, 0.0, 16.0, 10.0, 16.0]],
+    }),
+    ("snow[layers=8]", Shapes {
+        outline: FULL,
+        collision: &[[0.0, 0.0, 0.0, 16.0, 14.0, 16.0]],
+        occlusion: FULL,
+    }),
+];
+
+/// Asserts that the shape covers exactly the same space as the other, which may be split up into
+/// boxes differently.
+fn assert_shape(block: Block, kind: &str, actual: &VoxelShape, expected: &VoxelShape) {
+    assert!(
+        actual.subtract(expected).is_empty() && expected.subtract(actual).is_empty(),
+        "{kind} shape of {block}:\n  actual: {:?}\nexpected: {:?}",
+        actual.boxes(),
+        expected.boxes(),
+    );
+}
+
+/// Returns the union of the given boxes in pixels.
+fn pixels(boxes: &[[f64; 6]]) -> VoxelShape {
+    VoxelShape::new(
+        boxes
+            .iter()
+            .map(|&[min_x, min_y, min_z, max_x, max_y, max_z]| {
+                Aabb::from_pixels(min_x, min_y, min_z, max_x, max_y, max_z)
+            }),
+    )
+}
+
+#[test]
+fn vanilla_shapes() {
+    for (state, shapes) in CASES {
+        let block: Block = format!("minecraft:{state}").parse().unwrap();
+
+        let kinds = [
+            ("outline", block.outline_shape(), shapes.outline),
+            ("collision", block.collision_shape(), shapes.collision),
+            ("occlusion", block.occlusion_shape(), shapes.occlusion),
+        ];
+
+        for (kind, actual, expected) in kinds {
+            assert_shape(block, kind, actual, &pixels(expected));
+        }
+    }
+}
+
+#[test]
+fn blocks_without_collision_do_not_occlude() {
+    let stone = Block::from_id(BlockId::Stone);
+
+    for neighbor in ["minecraft:cobweb", "minecraft:dandelion", "minecraft:glass"] {
+        let neighbor: Block = neighbor.parse().unwrap();
+
+        assert!(
+            !stone.is_face_occluded_by(Facing::East, neighbor),
+            "{neighbor}"
+        );
+    }
+
+    assert!(stone.is_face_occluded_by(Facing::East, stone));
+}
+
+/// The table of the shapes of every block state.
+static TABLE: LazyLock<Value> = LazyLock::new(|| {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/shapes.json");
+
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+});
+
+/// Returns the shape in the table with the given index.
+fn table_shape(index: &Value) -> VoxelShape {
+    let index = usize::try_from(index.as_u64().unwrap()).unwrap();
+
+    VoxelShape::new(
+        TABLE["shapes"][index]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|aabb| {
+                let c: Vec<f64> = aabb
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|c| c.as_f64().unwrap())
+                    .collect();
+
+                Aabb::new([c[0], c[1], c[2]], [c[3], c[4], c[5]])
+            }),
+    )
+}
+
+#[test]
+fn every_state() {
+    let blocks = TABLE["blocks"].as_object().unwrap();
+    assert_eq!(blocks.len(), BlockId::iter().count());
+
+    for id in BlockId::iter() {
+        let shapes = &blocks[&format!("minecraft:{}", id.to_location())];
+
+        for (index, block) in id.states().enumerate() {
+            let kinds = [
+                ("outline", block.outline_shape()),
+                ("collision", block.collision_shape()),
+                ("occlusion", block.occlusion_shape()),
+            ];
+
+            for (kind, actual) in kinds {
+                let expected = match &shapes[kind] {
+                    Value::Array(indices) => &indices[index],
+                    index => index,
+                };
+
+                assert_shape(block, kind, actual, &table_shape(expected));
+            }
+        }
+    }
+}
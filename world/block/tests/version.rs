@@ -0,0 +1,261 @@
+//! Checks reading and writing blocks in the representations of different versions.
+
+use quartz_nbt::{compound, NbtCompound};
+use world_block::version::Version;
+use world_block::{Block, BlockId};
+
+#[test]
+fn from_data_version() {
+    let cases = [
+        (2860, Version::V1_18_2),
+        (2975, Version::V1_18_2),
+        (3120, Version::V1_18_2),
+        (3337, Version::V1_19_4),
+        (3464, Version::V1_19_4),
+        (3465, Version::V1_20_1),
+        (3578, Version::V1_20_1),
+        (3700, Version::V1_20_4),
+        (9999, Version::V1_20_4),
+    ];
+
+    for (data_version, version) in cases {
+        assert_eq!(
+            Version::from_data_version(data_version).unwrap(),
+            version,
+            "{data_version}"
+        );
+    }
+
+    assert!(Version::from_data_version(2859).is_err());
+    assert!(Version::from_data_version(1343).is_err());
+}
+
+#[test]
+fn data_versions_are_their_own() {
+    for version in Version::ALL {
+        assert_eq!(
+            Version::from_data_version(version.data_version()).unwrap(),
+            version
+        );
+        assert_eq!(version.name().parse::<Version>().unwrap(), version);
+    }
+}
+
+#[test]
+fn round_trips() {
+    for version in Version::ALL {
+        for id in BlockId::iter() {
+            for block in id.states() {
+                let tag = version.block_to_nbt(block);
+
+                assert_eq!(
+                    version.block_from_nbt(&tag).unwrap(),
+                    block,
+                    "{version} {tag}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn round_trips_across_versions() {
+    for from in Version::ALL {
+        for to in Version::ALL {
+            for id in BlockId::iter() {
+                for block in id.states() {
+                    let tag = from.convert_nbt(&from.block_to_nbt(block), to).unwrap();
+
+                    assert_eq!(tag, to.block_to_nbt(block), "{from} to {to}");
+                }
+            }
+        }
+    }
+}
+
+/// Returns the NBT representation of the block with the given name and properties.
+fn nbt(name: &str, properties: &[(&str, &str)]) -> NbtCompound {
+    let mut tag = compound! { "Name": name };
+
+    if !properties.is_empty() {
+        let mut properties_tag = NbtCompound::new();
+
+        for &(name, value) in properties {
+            properties_tag.insert(name, value);
+        }
+
+        tag.insert("Properties", properties_tag);
+    }
+
+    tag
+}
+
+#[test]
+fn renamed_blocks() {
+    let grass = Block::from_id(BlockId::Grass);
+
+    assert_eq!(
+        Version::V1_20_1.block_to_nbt(grass),
+        nbt("minecraft:grass", &[])
+    );
+    assert_eq!(
+        Version::V1_20_4.block_to_nbt(grass),
+        nbt("minecraft:short_grass", &[])
+    );
+    assert_eq!(
+        Version::V1_20_4
+            .block_from_nbt(&nbt("minecraft:short_grass", &[]))
+            .unwrap(),
+        grass
+    );
+    assert!(!Version::V1_20_1.has_block("minecraft:short_grass"));
+    assert!(!Version::V1_20_4.has_block("minecraft:grass"));
+}
+
+#[test]
+fn added_properties() {
+    let skull: Block = "minecraft:skeleton_skull[rotation=4]".parse().unwrap();
+
+    assert_eq!(
+        Version::V1_19_4.block_to_nbt(skull),
+        nbt("minecraft:skeleton_skull", &[("rotation", "4")])
+    );
+    assert_eq!(
+        Version::V1_20_1.block_to_nbt(skull),
+        nbt(
+            "minecraft:skeleton_skull",
+            &[("powered", "false"), ("rotation", "4")]
+        )
+    );
+
+    // The added property is dropped when reading blocks of later versions, as long as it has the
+    // value older versions imply.
+    let unpowered = nbt(
+        "minecraft:skeleton_skull",
+        &[("powered", "false"), ("rotation", "4")],
+    );
+    assert_eq!(Version::V1_20_1.block_from_nbt(&unpowered).unwrap(), skull);
+
+    let powered = nbt(
+        "minecraft:skeleton_skull",
+        &[("powered", "true"), ("rotation", "4")],
+    );
+    assert!(Version::V1_20_1.block_from_nbt(&powered).is_err());
+    assert!(Version::V1_19_4.block_from_nbt(&unpowered).is_err());
+}
+
+#[test]
+fn waterlogged_leaves() {
+    let leaves: Block = "minecraft:azalea_leaves[distance=3,persistent=true]"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        Version::V1_18_2.block_to_nbt(leaves),
+        nbt(
+            "minecraft:azalea_leaves",
+            &[("distance", "3"), ("persistent", "true")]
+        )
+    );
+
+    for version in [Version::V1_19_4, Version::V1_20_1, Version::V1_20_4] {
+        let dry = nbt(
+            "minecraft:azalea_leaves",
+            &[
+                ("distance", "3"),
+                ("persistent", "true"),
+                ("waterlogged", "false"),
+            ],
+        );
+        assert_eq!(version.block_to_nbt(leaves), dry, "{version}");
+        assert_eq!(version.block_from_nbt(&dry).unwrap(), leaves, "{version}");
+
+        // Leaves of 1.18.2 can't be waterlogged, so they can't be read.
+        let waterlogged = nbt(
+            "minecraft:azalea_leaves",
+            &[
+                ("distance", "3"),
+                ("persistent", "true"),
+                ("waterlogged", "true"),
+            ],
+        );
+        assert!(version.block_from_nbt(&waterlogged).is_err(), "{version}");
+    }
+}
+
+#[test]
+fn unknown_properties() {
+    let error = Version::V1_20_4
+        .block_from_nbt(&nbt("minecraft:stone", &[("polished", "true")]))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "block `minecraft:stone` of 1.20.4 has property `polished`, which it doesn't have in \
+         1.18.2"
+    );
+
+    assert!(Version::V1_18_2
+        .block_from_nbt(&nbt("minecraft:oak_leaves", &[("waterlogged", "false")]))
+        .is_err());
+    assert!(Version::V1_18_2
+        .block_from_nbt(&nbt("minecraft:oak_log", &[("axis", "w")]))
+        .is_err());
+}
+
+#[test]
+fn sculk_sensors_cool_down_in_every_version() {
+    let sensor: Block = "minecraft:sculk_sensor[sculk_sensor_phase=cooldown,power=7]"
+        .parse()
+        .unwrap();
+
+    for version in Version::ALL {
+        let tag = version.block_to_nbt(sensor);
+        assert_eq!(
+            tag,
+            nbt(
+                "minecraft:sculk_sensor",
+                &[
+                    ("power", "7"),
+                    ("sculk_sensor_phase", "cooldown"),
+                    ("waterlogged", "false"),
+                ],
+            ),
+            "{version}"
+        );
+        assert_eq!(version.block_from_nbt(&tag).unwrap(), sensor, "{version}");
+    }
+}
+
+#[test]
+fn added_property_values() {
+    let note_block = nbt(
+        "minecraft:note_block",
+        &[
+            ("instrument", "zombie"),
+            ("note", "3"),
+            ("powered", "false"),
+        ],
+    );
+    let harp: Block = "minecraft:note_block[instrument=harp,note=3,powered=false]"
+        .parse()
+        .unwrap();
+
+    assert_eq!(Version::V1_19_4.block_from_nbt(&note_block).unwrap(), harp);
+    assert!(Version::V1_18_2.block_from_nbt(&note_block).is_err());
+}
+
+#[test]
+fn blocks_added_since() {
+    for version in Version::ALL {
+        assert!(!version.has_block("minecraft:mangrove_log"));
+        assert!(!version.has_block("examplemod:ruby_block"));
+    }
+
+    let error = Version::V1_19_4
+        .block_from_nbt(&nbt("minecraft:mangrove_log", &[("axis", "y")]))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "block `minecraft:mangrove_log` of 1.19.4 doesn't exist in 1.18.2"
+    );
+}
@@ -0,0 +1,75 @@
+//! Blocks that don't exist in 1.18.2, like modded blocks or blocks added in later versions, which
+//! are kept in their NBT representation so that they can be written back unchanged.
+
+use std::fmt;
+
+use anyhow::Result;
+use quartz_nbt::NbtCompound;
+
+/// A block that doesn't exist in 1.18.2, with the name and properties it was read with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnknownBlock {
+    tag: NbtCompound,
+}
+
+impl UnknownBlock {
+    /// Reads an unknown block from its NBT representation, with a `Name` and optionally
+    /// `Properties`. Nothing but the name is checked, so that the block is written back as it was
+    /// read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the block has no name.
+    pub fn from_nbt(tag: &NbtCompound) -> Result<Self> {
+        tag.get::<_, &str>("Name")?;
+
+        Ok(UnknownBlock { tag: tag.clone() })
+    }
+
+    /// Returns the NBT representation the block was read from.
+    #[must_use]
+    pub fn to_nbt(&self) -> NbtCompound {
+        self.tag.clone()
+    }
+
+    /// Returns the resource location of the block, like `create:cogwheel`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.tag
+            .get::<_, &str>("Name")
+            .expect("the name should have been checked")
+    }
+
+    /// Returns an iterator over the names and values of the properties of the block that are
+    /// strings, which all properties written by vanilla are, in no particular order.
+    pub fn properties(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.tag
+            .get::<_, &NbtCompound>("Properties")
+            .into_iter()
+            .flat_map(NbtCompound::inner)
+            .filter_map(|(name, value)| Some((name.as_str(), <&str>::try_from(value).ok()?)))
+    }
+}
+
+/// Formats the block using the block state syntax used by commands, e.g.
+/// `create:cogwheel[axis=y]`. The properties are sorted by name, as the NBT representation
+/// doesn't keep their order.
+impl fmt::Display for UnknownBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())?;
+
+        let mut properties = self.properties().collect::<Vec<_>>();
+        properties.sort_unstable();
+
+        for (i, (name, value)) in properties.iter().enumerate() {
+            let separator = if i == 0 { '[' } else { ',' };
+            write!(f, "{separator}{name}={value}")?;
+        }
+
+        if !properties.is_empty() {
+            f.write_str("]")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,408 @@
+//! Block registries of different versions of the game. Blocks are always represented as they are
+//! in vanilla 1.18.2, whose report the block list is generated from, and the registries of later
+//! versions are described by how their blocks differ from it: blocks that were renamed, gained
+//! properties or gained property values. This is enough to read and write blocks of those versions
+//! by name, but not by global state ID.
+//!
+//! These are deliberately not full registries of every version, and there are gaps:
+//!
+//! - Blocks added after 1.18.2 can't be represented at all. [`Version::block_from_nbt`] fails for
+//!   them, and worlds keep them as [`UnknownBlock`](crate::unknown::UnknownBlock)s instead when
+//!   loading chunks.
+//! - Blocks with a property older versions lack, like waterlogged leaves, can't be represented
+//!   either unless the property has the value older versions imply, and fail to be read too.
+//! - Only the changes listed in `CHANGES` are known. They're picked by hand rather than generated
+//!   from the reports of the later versions, so other changes to existing blocks are missed.
+//! - The global state IDs of later versions aren't known, so neither are their network protocols.
+
+use std::{fmt, str};
+
+use anyhow::{anyhow, bail, Context, Result};
+use quartz_nbt::NbtCompound;
+
+use crate::custom::CustomBlockId;
+use crate::{resource, state, Block, BlockId};
+
+/// A version of the game with its own block registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Version {
+    V1_18_2,
+    V1_19_4,
+    V1_20_1,
+    V1_20_4,
+}
+
+impl Version {
+    /// All versions, from oldest to newest.
+    pub const ALL: [Version; 4] = [
+        Version::V1_18_2,
+        Version::V1_19_4,
+        Version::V1_20_1,
+        Version::V1_20_4,
+    ];
+
+    /// The version blocks are represented in, whose global state IDs [`Block::state_id`] returns.
+    pub const NATIVE: Version = Version::V1_18_2;
+
+    /// The oldest data version whose chunks store blocks the way 1.18.2 does, which is that of
+    /// the 1.18 release.
+    const MIN_DATA_VERSION: i32 = 2860;
+
+    /// Returns the name of the version, e.g. `1.18.2`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Version::V1_18_2 => "1.18.2",
+            Version::V1_19_4 => "1.19.4",
+            Version::V1_20_1 => "1.20.1",
+            Version::V1_20_4 => "1.20.4",
+        }
+    }
+
+    /// Returns the data version of the version, which vanilla saves in the `DataVersion` tag of
+    /// chunks and of `level.dat`.
+    #[must_use]
+    pub const fn data_version(self) -> i32 {
+        match self {
+            Version::V1_18_2 => 2975,
+            Version::V1_19_4 => 3337,
+            Version::V1_20_1 => 3465,
+            Version::V1_20_4 => 3700,
+        }
+    }
+
+    /// Returns the version whose block registry data of the given data version was saved with,
+    /// which is the newest version that isn't newer than the data. Data from versions newer than
+    /// all known ones is read like data of the newest one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the data is older than 1.18, which stored blocks differently.
+    pub fn from_data_version(data_version: i32) -> Result<Version> {
+        if data_version < Self::MIN_DATA_VERSION {
+            bail!("data version `{data_version}` is older than 1.18 and unsupported");
+        }
+
+        Ok(Self::ALL
+            .into_iter()
+            .rev()
+            .find(|version| version.data_version() <= data_version)
+            .unwrap_or(Version::V1_18_2))
+    }
+
+    /// Reads a block in the NBT representation of this version, with a `Name` and optionally
+    /// `Properties`, like in the palettes of chunk sections. Properties that are missing are left
+    /// at their default value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the block doesn't exist in 1.18.2, like blocks added in later versions, or
+    /// if it has properties or property values the block doesn't have in 1.18.2.
+    pub fn block_from_nbt(self, tag: &NbtCompound) -> Result<Block> {
+        let name = tag.get::<_, &str>("Name")?;
+        let mut properties = match tag.get::<_, &NbtCompound>("Properties") {
+            Ok(properties) => properties
+                .inner()
+                .iter()
+                .map(|(name, value)| {
+                    let value = <&str>::try_from(value)
+                        .with_context(|| format!("property `{name}` is not a string"))?;
+
+                    Ok((name.clone(), value.to_owned()))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            Err(_) => Vec::new(),
+        };
+        let mut block = match self.native_id(name, &mut properties) {
+            Some(block_id) => Block::from_id(block_id),
+            None => CustomBlockId::from_location(name)
+                .map(Block::from_custom)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "block `{name}` of {self} doesn't exist in {}",
+                        Version::NATIVE,
+                    )
+                })?,
+        };
+
+        let definitions = block.state_definition().properties;
+
+        for (property_name, value) in &properties {
+            let &state::PropertyDefinition { id, offset, .. } = definitions
+                .iter()
+                .find(|definition| definition.name == property_name)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "block `{name}` of {self} has property `{property_name}`, which it doesn't \
+                         have in {}",
+                        Version::NATIVE,
+                    )
+                })?;
+            let property = state::Property::from_str(id, value)
+                .with_context(|| format!("failed to parse property `{property_name}`"))?;
+            block.set_property(property, offset);
+        }
+
+        Ok(block)
+    }
+
+    /// Returns `true` if the block with the given name in this version exists in 1.18.2 or is a
+    /// registered custom block, and can thus be read by [`Version::block_from_nbt`] unless it has
+    /// properties it doesn't have in 1.18.2. Other modded blocks and blocks added since don't.
+    #[must_use]
+    pub fn has_block(self, name: &str) -> bool {
+        self.native_id(name, &mut Vec::new()).is_some()
+            || CustomBlockId::from_location(name).is_some()
+    }
+
+    /// Returns the ID of the block with the given name in this version in 1.18.2, reverting the
+    /// changes made to the block since, including to its properties.
+    fn native_id(self, name: &str, properties: &mut Vec<(String, String)>) -> Option<BlockId> {
+        let location = resource::Location::new(name).ok()?;
+        let ("minecraft", path) = location.as_parts() else {
+            return None;
+        };
+        let mut path = path.to_owned();
+
+        for (version, change) in CHANGES.iter().rev() {
+            if *version <= self && !change.downgrade(&mut path, properties) {
+                return None;
+            }
+        }
+
+        BlockId::from_location(&path)
+    }
+
+    /// Writes the block in the NBT representation of this version, with a `Name` and, if the block
+    /// has any, `Properties`.
+    #[must_use]
+    pub fn block_to_nbt(self, block: Block) -> NbtCompound {
+        // Custom blocks are the same in all versions.
+        if block.custom_id().is_some() {
+            return block.to_nbt();
+        }
+
+        let mut name = block.id().to_location().to_owned();
+        let mut properties = block
+            .properties()
+            .map(|(name, value)| (name.to_owned(), value.to_string()))
+            .collect::<Vec<_>>();
+
+        for (version, change) in CHANGES {
+            if *version <= self {
+                change.upgrade(&mut name, &mut properties);
+            }
+        }
+
+        let mut tag = NbtCompound::new();
+        tag.insert("Name", format!("minecraft:{name}"));
+
+        if !properties.is_empty() {
+            let mut properties_tag = NbtCompound::new();
+
+            for (name, value) in properties {
+                properties_tag.insert(name, value);
+            }
+
+            tag.insert("Properties", properties_tag);
+        }
+
+        tag
+    }
+
+    /// Converts a block in the NBT representation of this version to that of the given version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the block doesn't exist in 1.18.2.
+    pub fn convert_nbt(self, tag: &NbtCompound, to: Version) -> Result<NbtCompound> {
+        Ok(to.block_to_nbt(self.block_from_nbt(tag)?))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl str::FromStr for Version {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|version| version.name() == s)
+            .ok_or_else(|| anyhow!("unknown version `{s}`"))
+    }
+}
+
+/// A change to the blocks of the registry of a version, compared to the previous version.
+enum Change {
+    /// The block was renamed.
+    Renamed {
+        old: &'static str,
+        new: &'static str,
+    },
+    /// The blocks gained a property, which takes the given value for blocks of older versions.
+    /// Blocks with other values can't be represented in older versions.
+    PropertyAdded {
+        blocks: &'static [&'static str],
+        name: &'static str,
+        value: &'static str,
+    },
+    /// The property of the blocks gained values, which older versions read as the given value.
+    ValuesAdded {
+        blocks: &'static [&'static str],
+        name: &'static str,
+        values: &'static [&'static str],
+        fallback: &'static str,
+    },
+}
+
+impl Change {
+    /// Applies the change to a block of the previous version.
+    fn upgrade(&self, block_name: &mut String, properties: &mut Vec<(String, String)>) {
+        match *self {
+            Change::Renamed { old, new } => {
+                if block_name == old {
+                    new.clone_into(block_name);
+                }
+            }
+            Change::PropertyAdded {
+                blocks,
+                name,
+                value,
+            } => {
+                if blocks.contains(&block_name.as_str()) {
+                    properties.push((name.to_owned(), value.to_owned()));
+                    properties.sort();
+                }
+            }
+            Change::ValuesAdded { .. } => {}
+        }
+    }
+
+    /// Reverts the change to a block of the version, for the previous version. Returns `false` if
+    /// the block doesn't exist in the version, because it's the old name of a renamed block, or
+    /// can't be represented in the previous one, because an added property doesn't have the value
+    /// it takes there.
+    fn downgrade(&self, block_name: &mut String, properties: &mut Vec<(String, String)>) -> bool {
+        match *self {
+            Change::Renamed { old, new } => {
+                if block_name == old {
+                    return false;
+                }
+
+                if block_name == new {
+                    old.clone_into(block_name);
+                }
+            }
+            Change::PropertyAdded {
+                blocks,
+                name,
+                value,
+            } => {
+                if blocks.contains(&block_name.as_str()) {
+                    if properties.iter().any(|(n, v)| n == name && v != value) {
+                        return false;
+                    }
+
+                    properties.retain(|(n, _)| n != name);
+                }
+            }
+            Change::ValuesAdded {
+                blocks,
+                name,
+                values,
+                fallback,
+            } => {
+                if blocks.contains(&block_name.as_str()) {
+                    for (_, value) in properties.iter_mut().filter(|(n, _)| n == name) {
+                        if values.contains(&value.as_str()) {
+                            fallback.clone_into(value);
+                        }
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// The leaves of trees and azaleas.
+const LEAVES: &[&str] = &[
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "azalea_leaves",
+    "flowering_azalea_leaves",
+];
+
+/// The heads and skulls of mobs, on the floor and on walls.
+const HEADS: &[&str] = &[
+    "skeleton_skull",
+    "skeleton_wall_skull",
+    "wither_skeleton_skull",
+    "wither_skeleton_wall_skull",
+    "zombie_head",
+    "zombie_wall_head",
+    "player_head",
+    "player_wall_head",
+    "creeper_head",
+    "creeper_wall_head",
+    "dragon_head",
+    "dragon_wall_head",
+];
+
+/// The changes to the blocks that existed in 1.18.2, by the version that made them, from oldest
+/// to newest. Blocks added since don't exist in 1.18.2 and can't be read.
+const CHANGES: &[(Version, Change)] = &[
+    // Note blocks play the sound of the head on top of them.
+    (
+        Version::V1_19_4,
+        Change::ValuesAdded {
+            blocks: &["note_block"],
+            name: "instrument",
+            values: &[
+                "zombie",
+                "skeleton",
+                "creeper",
+                "dragon",
+                "wither_skeleton",
+                "piglin",
+                "custom_head",
+            ],
+            fallback: "harp",
+        },
+    ),
+    // Leaves can be waterlogged.
+    (
+        Version::V1_19_4,
+        Change::PropertyAdded {
+            blocks: LEAVES,
+            name: "waterlogged",
+            value: "false",
+        },
+    ),
+    // Heads move when powered.
+    (
+        Version::V1_20_1,
+        Change::PropertyAdded {
+            blocks: HEADS,
+            name: "powered",
+            value: "false",
+        },
+    ),
+    (
+        Version::V1_20_4,
+        Change::Renamed {
+            old: "grass",
+            new: "short_grass",
+        },
+    ),
+];
@@ -0,0 +1,370 @@
+//! Fire, which spreads to the flammable blocks around it and burns them away on its own scheduled
+//! ticks, and the lava that sets fire to its surroundings on random ticks. There are no biomes, so
+//! fire spreads as in a dry biome where it rains, like plains, and on the normal difficulty.
+
+use strum::IntoEnumIterator;
+
+use crate::block::state::{self, Integer};
+use crate::block::tag::vanilla;
+use crate::tick::Priority;
+use crate::update::{is_face_sturdy, Updates};
+use crate::{Block, BlockId, BlockPos, Direction, World};
+
+/// The highest age of fire, at which it may burn out.
+const MAX_AGE: u32 = 15;
+
+/// The ID of the difficulty the fire spreads on, which is normal.
+const DIFFICULTY_ID: i32 = 2;
+
+/// Whether fire spreads and burns out, like vanilla's `doFireTick` game rule.
+pub(crate) struct FireTicks {
+    enabled: bool,
+}
+
+impl Default for FireTicks {
+    fn default() -> Self {
+        FireTicks { enabled: true }
+    }
+}
+
+impl World {
+    /// Returns `true` if fire spreads and burns out, which is the case by default.
+    #[must_use]
+    pub fn do_fire_tick(&self) -> bool {
+        self.fire_ticks.enabled
+    }
+
+    /// Sets whether fire spreads and burns out, like vanilla's `doFireTick` game rule. Lava
+    /// doesn't set fire to its surroundings either when disabled.
+    pub fn set_do_fire_tick(&mut self, enabled: bool) {
+        self.fire_ticks.enabled = enabled;
+    }
+
+    /// Returns `true` if it's raining, which isn't the case by default.
+    #[must_use]
+    pub fn is_raining(&self) -> bool {
+        self.raining
+    }
+
+    /// Sets whether it's raining. Rain puts out fire and keeps it from spreading under the sky.
+    pub fn set_raining(&mut self, raining: bool) {
+        self.raining = raining;
+    }
+
+    /// Schedules the next tick of the fire at the given position, 30 to 39 game ticks later.
+    pub(crate) fn schedule_fire_tick(&mut self, pos: BlockPos) {
+        let delay = 30 + self.random.next_i32_below(10) as u64;
+        self.schedule_block_tick(pos, BlockId::Fire, delay, Priority::Normal);
+    }
+
+    /// Ages the fire at the given position, puts it out if it can't burn anymore or rain falls on
+    /// it, and lets it burn the blocks around it and spread. Fire only stays without anything to
+    /// burn around it if it's young and on a sturdy block, and fire on netherrack or magma blocks
+    /// burns forever.
+    pub(crate) fn tick_fire(&mut self, pos: BlockPos, mut fire: Block) {
+        self.schedule_fire_tick(pos);
+
+        if !self.fire_ticks.enabled {
+            return;
+        }
+
+        if !self.can_fire_survive(pos) {
+            self.replace_block(pos, Block::from_id(BlockId::Air), Updates::All, 0);
+            return;
+        }
+
+        let below = self.neighbor_block(pos, Direction::Down);
+        let burns_forever = below.is_in(vanilla("infiniburn_overworld"));
+        let age = fire_age(fire);
+
+        if !burns_forever
+            && self.raining
+            && self.is_near_rain(pos)
+            && self.random.next_f32() < 0.2 + f32::from(age as u16) * 0.03
+        {
+            self.replace_block(pos, Block::from_id(BlockId::Air), Updates::All, 0);
+            return;
+        }
+
+        let aged = (age + self.random.next_i32_below(3) as u32 / 2).min(MAX_AGE);
+
+        if aged != age {
+            fire.set(state::Age15(
+                Integer::new(aged).expect("the age should be in range"),
+            ));
+            self.replace_block(pos, fire, Updates::Shapes, 0);
+        }
+
+        if !burns_forever {
+            if !self.is_valid_fire_location(pos) {
+                if !is_face_sturdy(below, Direction::Up) || age > 3 {
+                    self.replace_block(pos, Block::from_id(BlockId::Air), Updates::All, 0);
+                }
+
+                return;
+            }
+
+            if age == MAX_AGE && self.random.next_i32_below(4) == 0 && !below.can_burn() {
+                self.replace_block(pos, Block::from_id(BlockId::Air), Updates::All, 0);
+                return;
+            }
+        }
+
+        for (direction, chance) in [
+            (Direction::East, 300),
+            (Direction::West, 300),
+            (Direction::Down, 250),
+            (Direction::Up, 250),
+            (Direction::North, 300),
+            (Direction::South, 300),
+        ] {
+            if let Some(neighbor_pos) = pos.neighbor(direction) {
+                self.burn_out(neighbor_pos, chance, age);
+            }
+        }
+
+        for x in -1..=1 {
+            for z in -1..=1 {
+                for y in -1..=4 {
+                    if x == 0 && y == 0 && z == 0 {
+                        continue;
+                    }
+
+                    let Ok(target) = BlockPos::new(pos.0 + glam::ivec3(x, y, z)) else {
+                        continue;
+                    };
+
+                    self.spread_fire(target, y, age);
+                }
+            }
+        }
+    }
+
+    /// Lets fire of the given age burn the block at the given position, which then either catches
+    /// fire itself or burns away. The lower the chance, the more likely the block is to burn.
+    /// TNT burns away without exploding, as the world has no entities.
+    fn burn_out(&mut self, pos: BlockPos, chance: i32, age: u32) {
+        let flammability = self
+            .block(pos)
+            .unwrap_or_default()
+            .flammability()
+            .flammability;
+
+        if self.random.next_i32_below(chance) >= i32::from(flammability) {
+            return;
+        }
+
+        if self.random.next_i32_below(age as i32 + 10) < 5 && !self.is_raining_at(pos) {
+            let aged = (age + self.random.next_i32_below(5) as u32 / 4).min(MAX_AGE);
+            let fire = self.fire_state(pos, aged);
+            self.replace_block(pos, fire, Updates::All, 0);
+        } else {
+            self.replace_block(pos, Block::from_id(BlockId::Air), Updates::All, 0);
+        }
+    }
+
+    /// Lets fire of the given age spread to the given position, `height` blocks above or below
+    /// it. Fire spreads more easily to spaces next to blocks that catch fire easily, and less so
+    /// upwards and as it ages.
+    fn spread_fire(&mut self, pos: BlockPos, height: i32, age: u32) {
+        let encouragement = self.fire_encouragement(pos);
+
+        if encouragement == 0 {
+            return;
+        }
+
+        let chance = if height > 1 { height * 100 } else { 100 };
+        let odds = (encouragement + 40 + DIFFICULTY_ID * 7) / (age as i32 + 30);
+
+        if odds > 0
+            && self.random.next_i32_below(chance) <= odds
+            && !(self.raining && self.is_near_rain(pos))
+        {
+            let aged = (age + self.random.next_i32_below(5) as u32 / 4).min(MAX_AGE);
+            let fire = self.fire_state(pos, aged);
+            self.replace_block(pos, fire, Updates::All, 0);
+        }
+    }
+
+    /// Returns how likely fire is to spread to the given position, which is the encouragement
+    /// of the most flammable block next to it, or 0 if the position isn't empty.
+    fn fire_encouragement(&self, pos: BlockPos) -> i32 {
+        if !self.block(pos).is_some_and(is_air) {
+            return 0;
+        }
+
+        Direction::iter()
+            .map(|direction| {
+                i32::from(
+                    self.neighbor_block(pos, direction)
+                        .flammability()
+                        .encouragement,
+                )
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Lets the lava at the given position set fire to the flammable blocks around it, either by
+    /// rising through the air above it, or straight next to it.
+    pub(crate) fn spread_lava_fire(&mut self, pos: BlockPos) {
+        if !self.fire_ticks.enabled {
+            return;
+        }
+
+        let steps = self.random.next_i32_below(3);
+
+        if steps > 0 {
+            let mut target = pos;
+
+            for _ in 0..steps {
+                let x = self.random.next_i32_below(3) - 1;
+                let z = self.random.next_i32_below(3) - 1;
+
+                let Ok(next) = BlockPos::new(target.0 + glam::ivec3(x, 1, z)) else {
+                    return;
+                };
+                target = next;
+
+                let Some(block) = self.block(target) else {
+                    return;
+                };
+
+                if is_air(block) {
+                    if Direction::iter().any(|direction| self.is_flammable(target, direction)) {
+                        let fire = self.fire_state(target, 0);
+                        self.replace_block(target, fire, Updates::All, 0);
+                        return;
+                    }
+                } else if block.blocks_motion() {
+                    return;
+                }
+            }
+        } else {
+            for _ in 0..3 {
+                let x = self.random.next_i32_below(3) - 1;
+                let z = self.random.next_i32_below(3) - 1;
+
+                let Ok(target) = BlockPos::new(pos.0 + glam::ivec3(x, 0, z)) else {
+                    return;
+                };
+
+                if self.block(target).is_none() {
+                    return;
+                }
+
+                let Some(above) = target.neighbor(Direction::Up) else {
+                    continue;
+                };
+
+                if self.block(above).is_some_and(is_air)
+                    && self
+                        .block(target)
+                        .is_some_and(|block| block.behavior().material.flammable)
+                {
+                    // Like in vanilla, the fire takes the shape it would have in place of the
+                    // block that catches fire.
+                    let fire = self.fire_state(target, 0);
+                    self.replace_block(above, fire, Updates::All, 0);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the material of the block next to the given position in the given
+    /// direction is flammable, which is what lava looks for.
+    fn is_flammable(&self, pos: BlockPos, direction: Direction) -> bool {
+        pos.neighbor(direction)
+            .and_then(|neighbor_pos| self.block(neighbor_pos))
+            .is_some_and(|block| block.behavior().material.flammable)
+    }
+
+    /// Returns the fire placed at the given position with the given age, which climbs the sides
+    /// of the blocks that can burn around it when it doesn't stand on a block. Fire on soul sand
+    /// or soul soil is soul fire instead, which doesn't age.
+    pub(crate) fn fire_state(&self, pos: BlockPos, age: u32) -> Block {
+        let below = self.neighbor_block(pos, Direction::Down);
+
+        if below.is_in(vanilla("soul_fire_base_blocks")) {
+            return Block::from_id(BlockId::SoulFire);
+        }
+
+        let mut fire = Block::from_id(BlockId::Fire);
+        fire.set(state::Age15(
+            Integer::new(age).expect("the age should be in range"),
+        ));
+
+        if below.can_burn() || is_face_sturdy(below, Direction::Up) {
+            return fire;
+        }
+
+        for direction in Direction::iter() {
+            let burns = self.neighbor_block(pos, direction).can_burn();
+
+            match direction {
+                Direction::Down => false,
+                Direction::Up => fire.set(state::Up(burns)),
+                Direction::North => fire.set(state::North(burns)),
+                Direction::South => fire.set(state::South(burns)),
+                Direction::West => fire.set(state::West(burns)),
+                Direction::East => fire.set(state::East(burns)),
+            };
+        }
+
+        fire
+    }
+
+    /// Returns `true` if fire can stay at the given position, on a sturdy block or next to a block
+    /// that can burn.
+    pub(crate) fn can_fire_survive(&self, pos: BlockPos) -> bool {
+        is_face_sturdy(self.neighbor_block(pos, Direction::Down), Direction::Up)
+            || self.is_valid_fire_location(pos)
+    }
+
+    /// Returns `true` if any block next to the given position can burn.
+    fn is_valid_fire_location(&self, pos: BlockPos) -> bool {
+        Direction::iter().any(|direction| self.neighbor_block(pos, direction).can_burn())
+    }
+
+    /// Returns `true` if rain falls on the given position or on any position next to it on the
+    /// same level.
+    fn is_near_rain(&self, pos: BlockPos) -> bool {
+        self.is_raining_at(pos)
+            || Direction::HORIZONTAL.into_iter().any(|direction| {
+                pos.neighbor(direction)
+                    .is_some_and(|neighbor_pos| self.is_raining_at(neighbor_pos))
+            })
+    }
+
+    /// Returns `true` if rain falls on the given position, which it does when it's raining and
+    /// no block at or above the position blocks motion or holds a fluid.
+    fn is_raining_at(&self, pos: BlockPos) -> bool {
+        if !self.raining {
+            return false;
+        }
+
+        let mut next_pos = Some(pos);
+
+        while let Some(pos) = next_pos {
+            let Some(block) = self.block(pos) else {
+                break;
+            };
+
+            if block.blocks_motion() || !block.fluid_state().is_empty() {
+                return false;
+            }
+
+            next_pos = pos.neighbor(Direction::Up);
+        }
+
+        true
+    }
+}
+
+pub(crate) fn fire_age(fire: Block) -> u32 {
+    fire.get::<state::Age15>().map_or(0, |age| age.0.get())
+}
+
+fn is_air(block: Block) -> bool {
+    block.behavior().is_air
+}
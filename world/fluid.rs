@@ -0,0 +1,682 @@
+//! Queries about fluids that depend on the neighbors of the block holding them, and the
+//! simulation of how fluids spread.
+
+use enum_map::EnumMap;
+use strum::IntoEnumIterator;
+
+use crate::block::fluid::{Fluid, FluidState};
+use crate::block::material::Material;
+use crate::block::state;
+use crate::block::tag;
+use crate::{Block, BlockId, BlockPos, Direction, World};
+
+impl World {
+    /// Returns the state of the fluid at the given position. Blocks in chunks that aren't loaded
+    /// are treated as air.
+    #[must_use]
+    pub fn fluid_state(&self, pos: BlockPos) -> FluidState {
+        self.block(pos).unwrap_or_default().fluid_state()
+    }
+
+    /// Returns the height of the fluid surface at the given position, relative to the bottom of
+    /// the block. Fluid with the same fluid above it fills the whole block.
+    #[must_use]
+    pub fn fluid_height(&self, pos: BlockPos) -> f32 {
+        let fluid_state = self.fluid_state(pos);
+
+        if !fluid_state.is_empty() && self.has_same_fluid_above(pos, fluid_state.fluid()) {
+            1.0
+        } else {
+            fluid_state.own_height()
+        }
+    }
+
+    /// Returns the heights of the fluid surface at the north-west, north-east, south-east and
+    /// south-west corners of the block at the given position, in that order, as rendered by
+    /// vanilla. The heights are averaged with the fluid in the neighboring blocks.
+    #[must_use]
+    pub fn fluid_corner_heights(&self, pos: BlockPos) -> [f32; 4] {
+        let fluid = self.fluid_state(pos).fluid();
+
+        if fluid == Fluid::Empty {
+            return [0.0; 4];
+        }
+
+        let height = self.corner_height(Some(pos), fluid);
+
+        if height >= 1.0 {
+            return [1.0; 4];
+        }
+
+        let north = self.corner_height(offset(pos, 0, -1), fluid);
+        let south = self.corner_height(offset(pos, 0, 1), fluid);
+        let east = self.corner_height(offset(pos, 1, 0), fluid);
+        let west = self.corner_height(offset(pos, -1, 0), fluid);
+
+        [
+            self.average_height(fluid, height, north, west, offset(pos, -1, -1)),
+            self.average_height(fluid, height, north, east, offset(pos, 1, -1)),
+            self.average_height(fluid, height, south, east, offset(pos, 1, 1)),
+            self.average_height(fluid, height, south, west, offset(pos, -1, 1)),
+        ]
+    }
+
+    /// Returns the normalized direction in which the fluid at the given position flows, which is
+    /// zero for still fluid.
+    #[must_use]
+    pub fn fluid_flow(&self, pos: BlockPos) -> glam::Vec3 {
+        let fluid_state = self.fluid_state(pos);
+        let fluid = fluid_state.fluid();
+
+        if fluid == Fluid::Empty {
+            return glam::Vec3::ZERO;
+        }
+
+        let affects_flow = |other: FluidState| other.is_empty() || other.fluid() == fluid;
+        let mut flow = glam::Vec3::ZERO;
+
+        for direction in Direction::HORIZONTAL {
+            let Some(neighbor_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+            let neighbor = self.fluid_state(neighbor_pos);
+
+            if !affects_flow(neighbor) {
+                continue;
+            }
+
+            let mut height = neighbor.own_height();
+            let mut difference = 0.0;
+
+            if height == 0.0 {
+                let blocks_motion = self
+                    .block(neighbor_pos)
+                    .unwrap_or_default()
+                    .id()
+                    .properties()
+                    .material
+                    .blocks_motion;
+
+                if !blocks_motion {
+                    let below = neighbor_pos
+                        .neighbor(Direction::Down)
+                        .map(|pos| self.fluid_state(pos))
+                        .unwrap_or_default();
+
+                    if affects_flow(below) {
+                        height = below.own_height();
+
+                        if height > 0.0 {
+                            difference = fluid_state.own_height() - (height - 8.0 / 9.0);
+                        }
+                    }
+                }
+            } else {
+                difference = fluid_state.own_height() - height;
+            }
+
+            flow += direction.to_unit_vec().as_vec3() * difference;
+        }
+
+        if fluid_state.is_falling() {
+            let next_to_solid_face = Direction::HORIZONTAL.into_iter().any(|direction| {
+                pos.neighbor(direction).is_some_and(|neighbor_pos| {
+                    self.is_solid_face(neighbor_pos, direction, fluid)
+                        || neighbor_pos
+                            .neighbor(Direction::Up)
+                            .is_some_and(|above| self.is_solid_face(above, direction, fluid))
+                })
+            });
+
+            if next_to_solid_face {
+                flow = flow.normalize_or_zero() + glam::vec3(0.0, -6.0, 0.0);
+            }
+        }
+
+        flow.normalize_or_zero()
+    }
+
+    /// Schedules ticks for the fluids at and next to the given position, for them to react to a
+    /// change of the block there. Lava next to water or above soul soil next to blue ice
+    /// solidifies right away.
+    ///
+    /// Fluids then spread as the world ticks, following the vanilla rules of the overworld: water
+    /// spreads 7 blocks every 5 ticks and lava 3 blocks every 30 ticks, both towards the nearest
+    /// drop within 4 and 2 blocks respectively. Blocks washed away by fluids don't drop anything.
+    /// Fluids only spread within loaded chunks.
+    pub fn update_fluids_around(&mut self, pos: BlockPos) {
+        self.update_fluid(pos);
+
+        for direction in Direction::iter() {
+            if let Some(neighbor_pos) = pos.neighbor(direction) {
+                self.update_fluid(neighbor_pos);
+            }
+        }
+    }
+
+    fn update_fluid(&mut self, pos: BlockPos) {
+        let block = self.simulated_block(Some(pos));
+        let fluid = block.fluid_state().fluid();
+
+        if fluid == Fluid::Empty || (block.id() == BlockId::Lava && self.solidify_lava(pos)) {
+            return;
+        }
+
+        self.schedule_fluid_tick(pos, fluid, tick_delay(fluid));
+    }
+
+    /// Turns the lava at the given position into obsidian, cobblestone or basalt if it touches
+    /// water or blue ice, returning `true` if it did.
+    fn solidify_lava(&mut self, pos: BlockPos) -> bool {
+        let above_soul_soil =
+            self.simulated_block(pos.neighbor(Direction::Down)).id() == BlockId::SoulSoil;
+        let directions = [
+            Direction::Up,
+            Direction::North,
+            Direction::South,
+            Direction::West,
+            Direction::East,
+        ];
+
+        for direction in directions {
+            let neighbor = self.simulated_block(pos.neighbor(direction));
+
+            let id = if neighbor.fluid_state().fluid() == Fluid::Water {
+                if self.fluid_state(pos).is_source() {
+                    BlockId::Obsidian
+                } else {
+                    BlockId::Cobblestone
+                }
+            } else if above_soul_soil && neighbor.id() == BlockId::BlueIce {
+                BlockId::Basalt
+            } else {
+                continue;
+            };
+
+            self.set_simulated_block(pos, Block::from_id(id));
+
+            return true;
+        }
+
+        false
+    }
+
+    /// Sets a block changed by the simulation, which only ever changes blocks in loaded chunks.
+    fn set_simulated_block(&mut self, pos: BlockPos, block: Block) {
+        self.set_block_and_update(pos, block)
+            .expect("the simulation should only change loaded blocks");
+    }
+
+    /// Returns the block at the given position for the purpose of the simulation, which treats
+    /// positions out of the world or in chunks that aren't loaded as barriers.
+    fn simulated_block(&self, pos: Option<BlockPos>) -> Block {
+        pos.and_then(|pos| self.block(pos))
+            .unwrap_or(Block::from_id(BlockId::Barrier))
+    }
+
+    pub(crate) fn tick_fluid(&mut self, pos: BlockPos) {
+        let mut fluid_state = self.fluid_state(pos);
+
+        if !fluid_state.is_source() {
+            let block = self.simulated_block(Some(pos));
+            let new_state = self.new_fluid_state(pos, block, fluid_state.fluid());
+
+            if new_state.is_empty() {
+                fluid_state = new_state;
+                self.set_simulated_block(pos, Block::from_id(BlockId::Air));
+            } else if new_state != fluid_state {
+                let delay = self.spread_delay(pos, fluid_state, new_state);
+                self.schedule_fluid_tick(pos, new_state.fluid(), delay);
+
+                fluid_state = new_state;
+                self.set_simulated_block(pos, new_state.to_block());
+            }
+
+            // The fluid may have solidified when updated.
+            if self.fluid_state(pos).fluid() != fluid_state.fluid() {
+                return;
+            }
+        }
+
+        self.spread(pos, fluid_state);
+    }
+
+    /// Returns the state the given fluid should have at the given position, based on the fluid
+    /// next to it and above it.
+    fn new_fluid_state(&self, pos: BlockPos, block: Block, fluid: Fluid) -> FluidState {
+        let mut amount = 0;
+        let mut source_count = 0;
+
+        for direction in Direction::HORIZONTAL {
+            let neighbor = self.simulated_block(pos.neighbor(direction));
+            let neighbor_state = neighbor.fluid_state();
+
+            if neighbor_state.fluid() == fluid && can_pass_through_wall(direction, block, neighbor)
+            {
+                if neighbor_state.is_source() {
+                    source_count += 1;
+                }
+
+                amount = amount.max(neighbor_state.amount());
+            }
+        }
+
+        // Water between two sources becomes a source itself, as long as it's supported.
+        if fluid == Fluid::Water && source_count >= 2 {
+            let below = self.simulated_block(pos.neighbor(Direction::Down));
+            let below_state = below.fluid_state();
+
+            if below.is_solid() || (below_state.fluid() == fluid && below_state.is_source()) {
+                return FluidState::source(fluid);
+            }
+        }
+
+        let above = self.simulated_block(pos.neighbor(Direction::Up));
+
+        if above.fluid_state().fluid() == fluid
+            && can_pass_through_wall(Direction::Up, block, above)
+        {
+            return FluidState::flowing(fluid, 8, true);
+        }
+
+        match amount.saturating_sub(drop_off(fluid)) {
+            0 => FluidState::EMPTY,
+            amount => FluidState::flowing(fluid, amount, false),
+        }
+    }
+
+    /// Returns the number of ticks before the fluid changing from `old` to `new` spreads again.
+    /// Rising lava usually waits longer.
+    fn spread_delay(&mut self, pos: BlockPos, old: FluidState, new: FluidState) -> u64 {
+        let delay = tick_delay(old.fluid());
+
+        if old.fluid() == Fluid::Lava
+            && !old.is_falling()
+            && !new.is_falling()
+            && !self.has_same_fluid_above(pos, Fluid::Lava)
+            && new.own_height() > old.own_height()
+            && self.random.next_i32_below(4) != 0
+        {
+            delay * 4
+        } else {
+            delay
+        }
+    }
+
+    fn spread(&mut self, pos: BlockPos, fluid_state: FluidState) {
+        if fluid_state.is_empty() {
+            return;
+        }
+
+        let fluid = fluid_state.fluid();
+        let block = self.simulated_block(Some(pos));
+        let below_pos = pos.neighbor(Direction::Down);
+        let below = self.simulated_block(below_pos);
+
+        if let Some(below_pos) = below_pos {
+            let new_state = self.new_fluid_state(below_pos, below, fluid);
+
+            if self.can_spread_to(block, Direction::Down, below_pos, below, new_state) {
+                self.spread_to(below_pos, below, Direction::Down, new_state);
+
+                if self.source_neighbor_count(pos, fluid) >= 3 {
+                    self.spread_to_sides(pos, fluid_state, block);
+                }
+
+                return;
+            }
+        }
+
+        if fluid_state.is_source() || !is_hole(fluid, block, below) {
+            self.spread_to_sides(pos, fluid_state, block);
+        }
+    }
+
+    fn source_neighbor_count(&self, pos: BlockPos, fluid: Fluid) -> usize {
+        Direction::HORIZONTAL
+            .into_iter()
+            .filter(|&direction| {
+                let neighbor = self.simulated_block(pos.neighbor(direction)).fluid_state();
+
+                neighbor.fluid() == fluid && neighbor.is_source()
+            })
+            .count()
+    }
+
+    fn spread_to_sides(&mut self, pos: BlockPos, fluid_state: FluidState, block: Block) {
+        let fluid = fluid_state.fluid();
+
+        if !fluid_state.is_falling() && fluid_state.amount() <= drop_off(fluid) {
+            return;
+        }
+
+        for (direction, new_state) in self.spread_directions(pos, block, fluid) {
+            let (Some(new_state), Some(neighbor_pos)) = (new_state, pos.neighbor(direction)) else {
+                continue;
+            };
+            let neighbor = self.simulated_block(Some(neighbor_pos));
+
+            if self.can_spread_to(block, direction, neighbor_pos, neighbor, new_state) {
+                self.spread_to(neighbor_pos, neighbor, direction, new_state);
+            }
+        }
+    }
+
+    /// Returns the horizontal directions in which the fluid spreads, along with the state of the
+    /// fluid it spreads. The fluid only spreads towards the nearest drops within its slope find
+    /// distance, or in all directions if there are none.
+    fn spread_directions(
+        &self,
+        pos: BlockPos,
+        block: Block,
+        fluid: Fluid,
+    ) -> EnumMap<Direction, Option<FluidState>> {
+        let mut directions = EnumMap::default();
+        let mut min_distance = u32::MAX;
+
+        for direction in Direction::HORIZONTAL {
+            let Some(neighbor_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+            let neighbor = self.simulated_block(Some(neighbor_pos));
+            let new_state = self.new_fluid_state(neighbor_pos, neighbor, fluid);
+
+            if !can_pass_through(fluid, block, direction, neighbor, new_state) {
+                continue;
+            }
+
+            let below = self.simulated_block(neighbor_pos.neighbor(Direction::Down));
+            let distance = if is_hole(fluid, neighbor, below) {
+                0
+            } else {
+                self.slope_distance(fluid, neighbor_pos, neighbor, 1, direction.opposite())
+            };
+
+            if distance < min_distance {
+                directions = EnumMap::default();
+            }
+
+            if distance <= min_distance {
+                directions[direction] = Some(new_state);
+                min_distance = distance;
+            }
+        }
+
+        directions
+    }
+
+    /// Returns the distance from the given position to the nearest drop the fluid could flow
+    /// into, without going back in the direction it came `from`.
+    fn slope_distance(
+        &self,
+        fluid: Fluid,
+        pos: BlockPos,
+        block: Block,
+        distance: u32,
+        from: Direction,
+    ) -> u32 {
+        let flowing = FluidState::flowing(fluid, 1, false);
+        let mut min_distance = u32::MAX;
+
+        for direction in Direction::HORIZONTAL {
+            if direction == from {
+                continue;
+            }
+
+            let Some(neighbor_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+            let neighbor = self.simulated_block(Some(neighbor_pos));
+
+            if !can_pass_through(fluid, block, direction, neighbor, flowing) {
+                continue;
+            }
+
+            let below = self.simulated_block(neighbor_pos.neighbor(Direction::Down));
+
+            if is_hole(fluid, neighbor, below) {
+                return distance;
+            }
+
+            if distance < slope_find_distance(fluid) {
+                let distance = self.slope_distance(
+                    fluid,
+                    neighbor_pos,
+                    neighbor,
+                    distance + 1,
+                    direction.opposite(),
+                );
+                min_distance = min_distance.min(distance);
+            }
+        }
+
+        min_distance
+    }
+
+    fn can_spread_to(
+        &self,
+        from: Block,
+        direction: Direction,
+        pos: BlockPos,
+        block: Block,
+        new_state: FluidState,
+    ) -> bool {
+        let can_be_replaced = match block.fluid_state().fluid() {
+            Fluid::Empty => true,
+            Fluid::Water => direction == Direction::Down && new_state.fluid() != Fluid::Water,
+            Fluid::Lava => self.fluid_height(pos) >= 4.0 / 9.0 && new_state.fluid() == Fluid::Water,
+        };
+
+        can_be_replaced
+            && can_pass_through_wall(direction, from, block)
+            && can_hold_fluid(block, new_state)
+    }
+
+    fn spread_to(
+        &mut self,
+        pos: BlockPos,
+        block: Block,
+        direction: Direction,
+        new_state: FluidState,
+    ) {
+        // Lava flowing down into water turns it into stone.
+        if new_state.fluid() == Fluid::Lava
+            && direction == Direction::Down
+            && block.fluid_state().fluid() == Fluid::Water
+        {
+            if block.id() == BlockId::Water {
+                self.set_simulated_block(pos, Block::from_id(BlockId::Stone));
+            }
+
+            return;
+        }
+
+        let mut waterlogged = block;
+
+        if waterlogged.set(state::Waterlogged(true)) {
+            self.set_simulated_block(pos, waterlogged);
+        } else {
+            self.set_simulated_block(pos, new_state.to_block());
+        }
+    }
+
+    fn has_same_fluid_above(&self, pos: BlockPos, fluid: Fluid) -> bool {
+        pos.neighbor(Direction::Up)
+            .is_some_and(|above| self.fluid_state(above).fluid() == fluid)
+    }
+
+    /// Returns the height of the given fluid at the given position for the purpose of averaging
+    /// the corner heights, which is -1 for solid blocks without that fluid.
+    fn corner_height(&self, pos: Option<BlockPos>, fluid: Fluid) -> f32 {
+        let Some(pos) = pos else {
+            return 0.0;
+        };
+        let block = self.block(pos).unwrap_or_default();
+        let fluid_state = block.fluid_state();
+
+        if fluid_state.fluid() == fluid {
+            if self.has_same_fluid_above(pos, fluid) {
+                1.0
+            } else {
+                fluid_state.own_height()
+            }
+        } else if block.is_solid() {
+            -1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn average_height(
+        &self,
+        fluid: Fluid,
+        height: f32,
+        side1: f32,
+        side2: f32,
+        corner: Option<BlockPos>,
+    ) -> f32 {
+        if side1 >= 1.0 || side2 >= 1.0 {
+            return 1.0;
+        }
+
+        // Heights close to full weigh more, so that the surface stays level next to sources.
+        let mut sum = 0.0;
+        let mut weight = 0.0;
+        let mut add = |height: f32| {
+            if height >= 0.8 {
+                sum += height * 10.0;
+                weight += 10.0;
+            } else if height >= 0.0 {
+                sum += height;
+                weight += 1.0;
+            }
+        };
+
+        if side1 > 0.0 || side2 > 0.0 {
+            let corner = self.corner_height(corner, fluid);
+
+            if corner >= 1.0 {
+                return 1.0;
+            }
+
+            add(corner);
+        }
+
+        add(height);
+        add(side1);
+        add(side2);
+
+        sum / weight
+    }
+
+    /// Returns `true` if the face of the block at the given position in the given direction stops
+    /// falling fluid from spreading sideways.
+    fn is_solid_face(&self, pos: BlockPos, direction: Direction, fluid: Fluid) -> bool {
+        let block = self.block(pos).unwrap_or_default();
+
+        if block.fluid_state().fluid() == fluid {
+            false
+        } else if direction == Direction::Up {
+            true
+        } else if matches!(block.id(), BlockId::Ice | BlockId::FrostedIce) {
+            false
+        } else {
+            block.collision_shape().is_face_full(direction.into())
+        }
+    }
+}
+
+/// Returns the number of ticks between two spreads of the fluid.
+fn tick_delay(fluid: Fluid) -> u64 {
+    match fluid {
+        Fluid::Empty => 0,
+        Fluid::Water => 5,
+        Fluid::Lava => 30,
+    }
+}
+
+/// Returns by how much the amount of fluid decreases each block it spreads.
+fn drop_off(fluid: Fluid) -> u8 {
+    match fluid {
+        Fluid::Lava => 2,
+        _ => 1,
+    }
+}
+
+/// Returns how far the fluid looks for a drop to flow towards.
+fn slope_find_distance(fluid: Fluid) -> u32 {
+    match fluid {
+        Fluid::Lava => 2,
+        _ => 4,
+    }
+}
+
+/// Returns `true` if the given fluid can flow down from `block` into the block `below`.
+fn is_hole(fluid: Fluid, block: Block, below: Block) -> bool {
+    can_pass_through_wall(Direction::Down, block, below)
+        && (below.fluid_state().fluid() == fluid
+            || can_hold_fluid(below, FluidState::flowing(fluid, 1, false)))
+}
+
+/// Returns `true` if the given fluid can flow from `from` into the neighboring block `to` in the
+/// given direction, becoming `new_state`.
+fn can_pass_through(
+    fluid: Fluid,
+    from: Block,
+    direction: Direction,
+    to: Block,
+    new_state: FluidState,
+) -> bool {
+    let to_state = to.fluid_state();
+
+    !(to_state.fluid() == fluid && to_state.is_source())
+        && can_hold_fluid(to, new_state)
+        && can_pass_through_wall(direction, from, to)
+}
+
+/// Returns `true` if fluid can flow between the two neighboring blocks, meaning that their faces
+/// between them don't cover the whole face together.
+fn can_pass_through_wall(direction: Direction, from: Block, to: Block) -> bool {
+    !from
+        .collision_shape()
+        .face(direction.into())
+        .union(&to.collision_shape().face(direction.opposite().into()))
+        .is_full()
+}
+
+/// Returns `true` if the fluid can flow into the block, either replacing it or, for blocks that
+/// can be waterlogged, only by water sources. Like vanilla's `FlowingFluid::canHoldFluid`, this
+/// rules out a few blocks by name and goes by the material of the rest: fluids don't flow into
+/// portals, structure voids, water plants, or anything that blocks motion.
+fn can_hold_fluid(block: Block, fluid_state: FluidState) -> bool {
+    use BlockId::*;
+
+    if let Some(state::Waterlogged(waterlogged)) = block.get() {
+        return !waterlogged && fluid_state.fluid() == Fluid::Water && fluid_state.is_source();
+    }
+
+    let tags = tag::Registry::vanilla();
+    let is_in = |location| {
+        tags.get(location)
+            .is_some_and(|tag: &tag::Tag| block.id().is_in(tag))
+    };
+
+    if is_in("doors") || is_in("signs") || matches!(block.id(), Ladder | SugarCane | BubbleColumn) {
+        return false;
+    }
+
+    let material = &block.behavior().material;
+
+    ![
+        Material::PORTAL,
+        Material::STRUCTURAL_AIR,
+        Material::WATER_PLANT,
+        Material::REPLACEABLE_WATER_PLANT,
+    ]
+    .contains(material)
+        && !material.blocks_motion
+}
+
+fn offset(pos: BlockPos, x: i32, z: i32) -> Option<BlockPos> {
+    BlockPos::new(pos.to_inner() + glam::ivec3(x, 0, z)).ok()
+}
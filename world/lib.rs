@@ -7,10 +7,11 @@
 )]
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{Seek, SeekFrom};
 use std::path::{Path, PathBuf};
-use std::{fmt, ops};
+use std::{fmt, mem, ops};
 
 use anyhow::{anyhow, bail, Context, Error, Result};
 use byteorder::{BigEndian, ReadBytesExt};
@@ -19,14 +20,37 @@ use quartz_nbt::io::{read_nbt, Flavor};
 use quartz_nbt::{NbtCompound, NbtList, NbtTag};
 use rustc_hash::FxHashMap;
 use strum::EnumIter;
+use world_block::unknown::UnknownBlock;
+use world_block::version::Version;
 pub use world_block::{self as block, Block, BlockId};
 pub use world_resource as resource;
 
+pub use self::piston::PistonStructure;
+
+mod fire;
+mod fluid;
+mod light;
+pub mod pathfind;
+mod piston;
+mod random_tick;
+mod redstone;
+mod tick;
+mod update;
+
 #[derive(Default)]
 pub struct World {
     save_path: Option<PathBuf>,
     loaded_chunks: FxHashMap<ChunkPos, Chunk>,
     loaded_regions: FxHashMap<RegionPos, File>,
+    time: u64,
+    random: block::random::Random,
+    block_ticks: tick::Ticks<BlockId>,
+    fluid_ticks: tick::Ticks<block::fluid::Fluid>,
+    random_ticks: random_tick::RandomTicks,
+    fire_ticks: fire::FireTicks,
+    raining: bool,
+    block_events: Vec<piston::BlockEvent>,
+    redstone: redstone::Redstone,
 }
 
 impl World {
@@ -62,6 +86,66 @@ impl World {
         self.loaded_chunks.get(&chunk_pos)
     }
 
+    /// Returns the block at the given position, or `None` if its chunk isn't loaded.
+    #[must_use]
+    pub fn block(&self, pos: BlockPos) -> Option<Block> {
+        let (chunk_pos, chunk_block_pos) = pos.to_chunk_pos();
+
+        self.loaded_chunk(chunk_pos)
+            .map(|chunk| chunk[chunk_block_pos])
+    }
+
+    /// Sets the block at the given position, without updating its neighbors. Fails if the chunk
+    /// of the block isn't loaded.
+    pub fn set_block(&mut self, pos: BlockPos, block: Block) -> Result<()> {
+        let (chunk_pos, chunk_block_pos) = pos.to_chunk_pos();
+        let chunk = self
+            .loaded_chunks
+            .get_mut(&chunk_pos)
+            .with_context(|| format!("chunk at position {chunk_pos} is not loaded"))?;
+        let (section_y, section_block_pos) = chunk_block_pos.to_section_pos();
+
+        chunk[section_y].set(section_block_pos, block);
+        chunk.unknown_blocks.remove(&pos);
+
+        Ok(())
+    }
+
+    /// Returns the unknown block at the given position, which was loaded as
+    /// [`Chunk::UNKNOWN_BLOCK_PLACEHOLDER`], or `None` if there's none or its chunk isn't loaded.
+    #[must_use]
+    pub fn unknown_block(&self, pos: BlockPos) -> Option<&UnknownBlock> {
+        let (chunk_pos, _) = pos.to_chunk_pos();
+
+        self.loaded_chunk(chunk_pos)?.unknown_block(pos)
+    }
+
+    /// Returns the number of unknown blocks with each name in the loaded chunks, like modded
+    /// blocks or blocks added in later versions.
+    #[must_use]
+    pub fn unknown_block_summary(&self) -> BTreeMap<&str, usize> {
+        let mut summary = BTreeMap::new();
+
+        for chunk in self.loaded_chunks() {
+            for (_, unknown_block) in chunk.unknown_blocks() {
+                *summary.entry(unknown_block.name()).or_default() += 1;
+            }
+        }
+
+        summary
+    }
+
+    /// Adds a chunk to the loaded chunks, replacing the chunk loaded at the same position if any.
+    /// This allows building worlds that aren't backed by a save.
+    pub fn insert_chunk(&mut self, mut chunk: Chunk) {
+        // The blocks of the chunk may have been changed through indexing.
+        for section in &mut chunk.sections {
+            section.count_random_ticking();
+        }
+
+        self.loaded_chunks.insert(chunk.pos, chunk);
+    }
+
     pub fn load_chunk(&mut self, chunk_pos: ChunkPos) -> Result<()> {
         let region_pos = RegionPos(chunk_pos.0 >> Self::REGION_SIZE as i32);
         let region_chunk_pos = (chunk_pos.0 & Self::REGION_MASK).as_uvec2();
@@ -103,7 +187,7 @@ impl World {
             n => bail!("unknown compression type `{n}`"),
         };
         let (chunk_tag, _) = read_nbt(region, flavor)?;
-        let chunk = Self::read_chunk(&chunk_tag)
+        let chunk = Chunk::from_nbt(&chunk_tag)
             .with_context(|| format!("failed to load chunk at position {chunk_pos}"))?;
 
         if chunk.pos != chunk_pos {
@@ -114,31 +198,9 @@ impl World {
 
         Ok(())
     }
-
-    fn read_chunk(chunk_tag: &NbtCompound) -> Result<Chunk> {
-        let chunk_pos = ChunkPos(glam::ivec2(
-            chunk_tag.get::<_, i32>("xPos")?,
-            chunk_tag.get::<_, i32>("zPos")?,
-        ));
-        let sections_tag = chunk_tag.get::<_, &NbtList>("sections")?;
-
-        if sections_tag.len() != Chunk::SECTION_COUNT {
-            log::warn!("Chunk at position {chunk_pos} has missing sections");
-        }
-
-        let mut chunk = Chunk::from_block(chunk_pos, Block::from_id(BlockId::Air));
-
-        for (i, section_tag) in sections_tag.iter().enumerate() {
-            chunk
-                .load_section(section_tag.try_into()?)
-                .with_context(|| format!("failed to load section at index `{i}`"))?;
-        }
-
-        Ok(chunk)
-    }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct BlockPos(glam::IVec3);
 
 impl BlockPos {
@@ -224,21 +286,92 @@ impl fmt::Display for RegionPos {
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct Chunk {
     pos: ChunkPos,
     sections: [Section; Self::SECTION_COUNT],
+    /// The distinct unknown blocks of the chunk, which vanilla would replace with air.
+    unknown_palette: Vec<UnknownBlock>,
+    /// The index in the unknown palette of each unknown block, by position. Unknown blocks are
+    /// placeholders in the sections, and forgotten once another block is set in their place.
+    unknown_blocks: FxHashMap<BlockPos, usize>,
 }
 
 impl Chunk {
     const SECTION_COUNT: usize = 24;
 
+    /// The block unknown blocks are loaded as. Like most modded blocks, it's solid, and it can't
+    /// be moved or destroyed, so that the simulation leaves the unknown blocks in place.
+    pub const UNKNOWN_BLOCK_PLACEHOLDER: BlockId = BlockId::Bedrock;
+
     #[must_use]
     pub fn from_block(pos: ChunkPos, block: Block) -> Self {
         Chunk {
             pos,
             sections: [Section::from_block(block); Self::SECTION_COUNT],
+            unknown_palette: Vec::new(),
+            unknown_blocks: FxHashMap::default(),
+        }
+    }
+
+    /// Reads the chunk from its NBT representation, as saved in region files. Only the blocks
+    /// are read, with the block registry of the version the chunk was saved with. Blocks that
+    /// can't be represented in 1.18.2 are loaded as [`Chunk::UNKNOWN_BLOCK_PLACEHOLDER`] and kept
+    /// as unknown blocks.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the chunk is malformed or was saved by a version older than 1.18.
+    pub fn from_nbt(chunk_tag: &NbtCompound) -> Result<Chunk> {
+        let chunk_pos = ChunkPos(glam::ivec2(
+            chunk_tag.get::<_, i32>("xPos")?,
+            chunk_tag.get::<_, i32>("zPos")?,
+        ));
+        let data_version = chunk_tag.get::<_, i32>("DataVersion")?;
+        let version = Version::from_data_version(data_version)?;
+        let sections_tag = chunk_tag.get::<_, &NbtList>("sections")?;
+
+        if sections_tag.len() != Chunk::SECTION_COUNT {
+            log::warn!("Chunk at position {chunk_pos} has missing sections");
         }
+
+        let mut chunk = Chunk::from_block(chunk_pos, Block::from_id(BlockId::Air));
+
+        for (i, section_tag) in sections_tag.iter().enumerate() {
+            chunk
+                .load_section(section_tag.try_into()?, version)
+                .with_context(|| format!("failed to load section at index `{i}`"))?;
+        }
+
+        if !chunk.unknown_blocks.is_empty() {
+            log::warn!(
+                "Chunk at position {chunk_pos} has {} unknown blocks, which are loaded as {}",
+                chunk.unknown_blocks.len(),
+                Block::from_id(Chunk::UNKNOWN_BLOCK_PLACEHOLDER),
+            );
+        }
+
+        Ok(chunk)
+    }
+
+    /// Writes the chunk in its NBT representation of the given version, with the tags that
+    /// [`Chunk::from_nbt`] reads: the position, the data version and the blocks of each section.
+    /// Unknown blocks are written back the way they were read.
+    #[must_use]
+    pub fn to_nbt(&self, version: Version) -> NbtCompound {
+        let mut chunk_tag = NbtCompound::new();
+        chunk_tag.insert("xPos", self.pos.0.x);
+        chunk_tag.insert("zPos", self.pos.0.y);
+        chunk_tag.insert("DataVersion", version.data_version());
+        chunk_tag.insert(
+            "sections",
+            self.sections()
+                .map(|(section_y, _)| self.save_section(section_y, version))
+                .map(NbtTag::Compound)
+                .collect::<NbtList>(),
+        );
+
+        chunk_tag
     }
 
     #[must_use]
@@ -256,6 +389,23 @@ impl Chunk {
         })
     }
 
+    /// Returns the unknown block at the given position, which was loaded as
+    /// [`Chunk::UNKNOWN_BLOCK_PLACEHOLDER`], or `None` if there's none. Unknown blocks keep the
+    /// NBT representation they were read from, so that they can be written back unchanged.
+    #[must_use]
+    pub fn unknown_block(&self, pos: BlockPos) -> Option<&UnknownBlock> {
+        let &index = self.unknown_blocks.get(&pos)?;
+
+        Some(&self.unknown_palette[index])
+    }
+
+    /// Returns an iterator over the unknown blocks of the chunk and their positions.
+    pub fn unknown_blocks(&self) -> impl '_ + Iterator<Item = (BlockPos, &UnknownBlock)> {
+        self.unknown_blocks
+            .iter()
+            .map(|(&pos, &index)| (pos, &self.unknown_palette[index]))
+    }
+
     fn sections(&self) -> impl Iterator<Item = (SectionY, &Section)> {
         self.sections
             .iter()
@@ -263,20 +413,42 @@ impl Chunk {
             .map(|(section_index, section)| (SectionY::from_index(section_index), section))
     }
 
-    fn load_section(&mut self, section_tag: &NbtCompound) -> Result<()> {
+    /// Loads the section from its NBT representation, reading the blocks of its palette with the
+    /// block registry of the given version. Blocks that can't be represented in 1.18.2, like
+    /// blocks added since or waterlogged leaves, are loaded as
+    /// [`Chunk::UNKNOWN_BLOCK_PLACEHOLDER`] and kept as unknown blocks.
+    fn load_section(&mut self, section_tag: &NbtCompound, version: Version) -> Result<()> {
         let section_y = section_tag.get::<_, SectionY>("Y")?;
         let block_states_tag = section_tag.get::<_, &NbtCompound>("block_states")?;
         let palette_tag = block_states_tag.get::<_, &NbtList>("palette")?;
 
+        // The index in the unknown palette of each unknown block of the palette.
+        let mut unknown_indices = Vec::with_capacity(palette_tag.len());
         let palette = palette_tag
             .iter()
             .map(|tag| {
-                Block::try_from(<&NbtCompound>::try_from(tag)?)
-                    .context("failed to read block from its NBT representation")
+                let tag = <&NbtCompound>::try_from(tag)?;
+
+                if let Ok(block) = version.block_from_nbt(tag) {
+                    unknown_indices.push(None);
+
+                    return Ok(block);
+                }
+
+                unknown_indices.push(Some(self.unknown_palette.len()));
+                self.unknown_palette.push(UnknownBlock::from_nbt(tag)?);
+
+                Ok(Block::from_id(Self::UNKNOWN_BLOCK_PLACEHOLDER))
             })
             .collect::<Result<Vec<Block>, Error>>()?;
 
         if palette.len() == 1 {
+            if let Some(index) = unknown_indices[0] {
+                for block_index in 0..Section::BLOCK_COUNT {
+                    self.insert_unknown_block(section_y, block_index, index);
+                }
+            }
+
             if palette[0].id() != BlockId::Air {
                 self[section_y] = Section::from_block(palette[0]);
             }
@@ -314,10 +486,87 @@ impl Chunk {
                         palette.len(),
                     )
                 })?;
+
+            if let Some(index) = unknown_indices[palette_index as usize] {
+                self.insert_unknown_block(section_y, block_index, index);
+            }
         }
 
+        self[section_y].count_random_ticking();
+
         Ok(())
     }
+
+    /// Writes the section in its NBT representation of the given version, with a palette of the
+    /// distinct blocks of the section and their indices packed into longs.
+    fn save_section(&self, section_y: SectionY, version: Version) -> NbtCompound {
+        /// A block of the palette.
+        #[derive(PartialEq, Eq, Hash)]
+        enum Entry {
+            Known(Block),
+            Unknown(usize),
+        }
+
+        let section = &self[section_y];
+        let mut palette = Vec::new();
+        let mut palette_indices = FxHashMap::default();
+        let indices = section
+            .blocks()
+            .map(|(section_block_pos, block)| {
+                let pos = section_block_pos + section_y + self.pos;
+                let entry = match self.unknown_blocks.get(&pos) {
+                    Some(&index) if block.id() == Self::UNKNOWN_BLOCK_PLACEHOLDER => {
+                        Entry::Unknown(index)
+                    }
+                    _ => Entry::Known(block),
+                };
+
+                *palette_indices.entry(entry).or_insert_with_key(|entry| {
+                    palette.push(match *entry {
+                        Entry::Known(block) => version.block_to_nbt(block),
+                        Entry::Unknown(index) => self.unknown_palette[index].to_nbt(),
+                    });
+
+                    palette.len() as u64 - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut block_states_tag = NbtCompound::new();
+
+        // Like vanilla, sections of a single block have no data.
+        if palette.len() > 1 {
+            let index_bits = u32::max(usize::BITS - (palette.len() - 1).leading_zeros(), 4);
+            let index_packing = (i64::BITS / index_bits) as usize;
+            let data = indices
+                .chunks(index_packing)
+                .map(|indices| {
+                    indices
+                        .iter()
+                        .enumerate()
+                        .fold(0, |packed, (packed_index, &palette_index)| {
+                            packed | palette_index << (packed_index as u32 * index_bits)
+                        }) as i64
+                })
+                .collect::<Vec<_>>();
+
+            block_states_tag.insert("data", data);
+        }
+
+        block_states_tag.insert("palette", NbtList::from(palette));
+
+        let mut section_tag = NbtCompound::new();
+        section_tag.insert("Y", section_y.0 as i8);
+        section_tag.insert("block_states", block_states_tag);
+
+        section_tag
+    }
+
+    fn insert_unknown_block(&mut self, section_y: SectionY, block_index: usize, index: usize) {
+        let chunk_block_pos = SectionBlockPos::from_index(block_index) + section_y;
+        self.unknown_blocks
+            .insert(chunk_block_pos + self.pos, index);
+    }
 }
 
 impl ops::Index<ChunkBlockPos> for Chunk {
@@ -430,6 +679,8 @@ impl TryFrom<&NbtTag> for SectionY {
 #[derive(Clone, Copy, Debug)]
 struct Section {
     blocks: [Block; Self::BLOCK_COUNT],
+    /// Number of blocks that tick randomly, so that sections without any can be skipped.
+    random_ticking_count: u16,
 }
 
 impl Section {
@@ -442,11 +693,37 @@ impl Section {
     const BLOCK_COUNT: usize = 1 << (3 * Self::SIZE);
 
     fn from_block(block: Block) -> Self {
-        Section {
+        let mut section = Section {
             blocks: [block; Self::BLOCK_COUNT],
+            random_ticking_count: 0,
+        };
+        section.count_random_ticking();
+
+        section
+    }
+
+    /// Sets the block at the given position, keeping track of the blocks that tick randomly.
+    fn set(&mut self, pos: SectionBlockPos, block: Block) {
+        let old = mem::replace(&mut self[pos], block);
+
+        if random_tick::is_randomly_ticking(old) {
+            self.random_ticking_count -= 1;
+        }
+
+        if random_tick::is_randomly_ticking(block) {
+            self.random_ticking_count += 1;
         }
     }
 
+    #[allow(clippy::cast_possible_truncation)]
+    fn count_random_ticking(&mut self) {
+        self.random_ticking_count = self
+            .blocks
+            .iter()
+            .filter(|&&block| random_tick::is_randomly_ticking(block))
+            .count() as u16;
+    }
+
     fn blocks(&self) -> impl '_ + Iterator<Item = (SectionBlockPos, Block)> {
         self.blocks
             .iter()
@@ -523,6 +800,9 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// The horizontal directions, clockwise starting from north as seen from above.
+    pub const HORIZONTAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
     #[must_use]
     #[rustfmt::skip]
     pub const fn to_unit_vec(self) -> glam::IVec3 {
@@ -536,6 +816,49 @@ impl Direction {
         }
     }
 
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Down => Self::Up,
+            Self::Up => Self::Down,
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::West => Self::East,
+            Self::East => Self::West,
+        }
+    }
+
+    /// Returns the next horizontal direction clockwise as seen from above, or the direction itself
+    /// if it's vertical.
+    #[must_use]
+    pub const fn clockwise(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+            vertical => vertical,
+        }
+    }
+
+    /// Returns the next horizontal direction counter-clockwise as seen from above, or the
+    /// direction itself if it's vertical.
+    #[must_use]
+    pub const fn counter_clockwise(self) -> Self {
+        self.clockwise().opposite()
+    }
+
+    /// Returns `true` if both directions are along the same axis.
+    #[must_use]
+    pub fn is_parallel_to(self, other: Self) -> bool {
+        self == other || self == other.opposite()
+    }
+
+    #[must_use]
+    pub const fn is_horizontal(self) -> bool {
+        !matches!(self, Self::Down | Self::Up)
+    }
+
     #[allow(clippy::match_same_arms)]
     #[must_use]
     #[rustfmt::skip]
@@ -564,3 +887,54 @@ impl Direction {
         }
     }
 }
+
+impl From<block::state::Facing> for Direction {
+    fn from(facing: block::state::Facing) -> Self {
+        match facing {
+            block::state::Facing::Down => Direction::Down,
+            block::state::Facing::Up => Direction::Up,
+            block::state::Facing::North => Direction::North,
+            block::state::Facing::South => Direction::South,
+            block::state::Facing::West => Direction::West,
+            block::state::Facing::East => Direction::East,
+        }
+    }
+}
+
+impl From<block::state::HorizontalFacing> for Direction {
+    fn from(facing: block::state::HorizontalFacing) -> Self {
+        match facing {
+            block::state::HorizontalFacing::North => Direction::North,
+            block::state::HorizontalFacing::South => Direction::South,
+            block::state::HorizontalFacing::West => Direction::West,
+            block::state::HorizontalFacing::East => Direction::East,
+        }
+    }
+}
+
+impl From<Direction> for block::state::Facing {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Down => block::state::Facing::Down,
+            Direction::Up => block::state::Facing::Up,
+            Direction::North => block::state::Facing::North,
+            Direction::South => block::state::Facing::South,
+            Direction::West => block::state::Facing::West,
+            Direction::East => block::state::Facing::East,
+        }
+    }
+}
+
+impl TryFrom<Direction> for block::state::HorizontalFacing {
+    type Error = Error;
+
+    fn try_from(direction: Direction) -> Result<Self> {
+        match direction {
+            Direction::North => Ok(block::state::HorizontalFacing::North),
+            Direction::South => Ok(block::state::HorizontalFacing::South),
+            Direction::West => Ok(block::state::HorizontalFacing::West),
+            Direction::East => Ok(block::state::HorizontalFacing::East),
+            _ => bail!("direction `{direction:?}` is not horizontal"),
+        }
+    }
+}
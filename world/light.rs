@@ -0,0 +1,98 @@
+//! Light levels, which decide whether plants grow and ice melts. The world has no light engine, so
+//! light levels are estimated from the blocks around, as at noon under a clear sky.
+
+use std::collections::VecDeque;
+
+use rustc_hash::FxHashSet;
+use strum::IntoEnumIterator;
+
+use crate::{BlockPos, Direction, World};
+
+/// The highest light level.
+const MAX_LIGHT: u8 = 15;
+
+impl World {
+    /// Returns the light level at the given position, which is the brightest of its sky light and
+    /// its block light, like vanilla's `getRawBrightness` during the day.
+    pub(crate) fn brightness(&self, pos: BlockPos) -> u8 {
+        let sky_light = self.sky_light(pos);
+
+        if sky_light == MAX_LIGHT {
+            sky_light
+        } else {
+            sky_light.max(self.block_light(pos))
+        }
+    }
+
+    /// Returns the sky light at the given position, estimated from the blocks straight above it,
+    /// which each dim the sunlight by how much light they block. Unlike in vanilla, sunlight
+    /// doesn't spread sideways under overhangs.
+    pub(crate) fn sky_light(&self, pos: BlockPos) -> u8 {
+        let mut light = MAX_LIGHT;
+        let mut next_pos = Some(pos);
+
+        while let Some(pos) = next_pos {
+            let Some(block) = self.block(pos) else {
+                break;
+            };
+
+            light = light.saturating_sub(block.light_block());
+
+            if light == 0 {
+                break;
+            }
+
+            next_pos = pos.neighbor(Direction::Up);
+        }
+
+        light
+    }
+
+    /// Returns the block light at the given position, from the light-emitting blocks around it.
+    /// Light gets dimmer by one level with each block it travels through, and doesn't go through
+    /// opaque blocks.
+    pub(crate) fn block_light(&self, pos: BlockPos) -> u8 {
+        let Some(block) = self.block(pos) else {
+            return 0;
+        };
+        let mut light = block.light_emission();
+
+        if block.light_block() >= MAX_LIGHT {
+            return light;
+        }
+
+        let mut visited = FxHashSet::from_iter([pos]);
+        let mut queue = VecDeque::from([(pos, 0)]);
+
+        while let Some((pos, distance)) = queue.pop_front() {
+            let reached = distance + 1;
+
+            // Blocks further away can't be brighter.
+            if MAX_LIGHT.saturating_sub(reached) <= light {
+                break;
+            }
+
+            for direction in Direction::iter() {
+                let Some(neighbor_pos) = pos.neighbor(direction) else {
+                    continue;
+                };
+
+                if !visited.insert(neighbor_pos) {
+                    continue;
+                }
+
+                let Some(neighbor) = self.block(neighbor_pos) else {
+                    continue;
+                };
+
+                light = light.max(neighbor.light_emission().saturating_sub(reached));
+
+                if neighbor.light_block() < MAX_LIGHT {
+                    queue.push_back((neighbor_pos, reached));
+                }
+            }
+        }
+
+        light
+    }
+}
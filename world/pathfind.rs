@@ -0,0 +1,534 @@
+//! Pathfinding for entities walking through the world, which finds the cheapest path between two
+//! positions with A*. Like vanilla's `WalkNodeEvaluator`, entities stand on the collision shapes
+//! of blocks, step up half blocks, jump up to their jump height, swim through water, avoid blocks
+//! that hurt them and only fall as far as they're willing to take fall damage for. Unlike in
+//! vanilla, entities only move along the axes, not diagonally, and paths never lead through
+//! unloaded chunks.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::BinaryHeap;
+
+use rustc_hash::FxHashMap;
+
+use crate::block::fluid::Fluid;
+use crate::block::shape::Aabb;
+use crate::block::state;
+use crate::block::tag::vanilla;
+use crate::{Block, BlockId, BlockPos, Direction, World};
+
+/// How high entities step up without jumping, like vanilla's `maxUpStep` of most entities.
+const STEP_HEIGHT: f64 = 0.6;
+
+/// How far entities fall without taking damage, in blocks.
+const SAFE_FALL_DISTANCE: f32 = 3.0;
+
+/// The cost of moving one block through water, instead of 1.
+const SWIM_COST: f32 = 2.0;
+
+/// The cost added for jumping up onto a block.
+const JUMP_COST: f32 = 0.5;
+
+/// The cost added for opening a door.
+const DOOR_COST: f32 = 1.0;
+
+/// The cost added for each point of fall damage taken.
+const FALL_DAMAGE_COST: f32 = 2.0;
+
+/// What an entity following a path can do, and how long to search for a path.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capabilities {
+    /// The width of the entity, in blocks. Entities walk through the middle of blocks.
+    pub width: f64,
+    /// The height of the entity, in blocks.
+    pub height: f64,
+    /// How high the entity jumps onto blocks, in blocks. Entities step up half blocks even if
+    /// this is lower.
+    pub jump_height: f64,
+    /// The most damage the entity takes from a single fall, in half hearts. Entities take no
+    /// damage falling up to 3 blocks or into water, and less landing on hay bales, honey blocks,
+    /// slime blocks and beds.
+    pub max_fall_damage: f32,
+    /// Whether the entity swims through water. Entities that don't only wade through water with
+    /// ground under their feet.
+    pub can_swim: bool,
+    /// Whether the entity opens wooden doors that are in the way. Iron doors can't be opened by
+    /// hand.
+    pub can_open_doors: bool,
+    /// How many positions to visit before giving up, like vanilla's `maxVisitedNodes`.
+    pub max_visited_nodes: usize,
+}
+
+/// The capabilities of a player who doesn't want to take fall damage.
+impl Default for Capabilities {
+    fn default() -> Self {
+        Capabilities {
+            width: 0.6,
+            height: 1.8,
+            jump_height: 1.25,
+            max_fall_damage: 0.0,
+            can_swim: true,
+            can_open_doors: true,
+            max_visited_nodes: 10_000,
+        }
+    }
+}
+
+/// A path found by [`World::find_path`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path {
+    /// The positions the feet of the entity are in along the path, from the start to the goal.
+    /// Each position is next to the previous one, or above or below a block next to it when the
+    /// entity steps up, jumps or falls.
+    pub positions: Vec<BlockPos>,
+    /// The cost of the path. Walking a block on normal ground costs 1, and walking on slow blocks,
+    /// swimming, jumping, opening doors and taking fall damage cost more.
+    pub cost: f32,
+}
+
+impl World {
+    /// Finds the cheapest path for an entity with the given capabilities from one position to
+    /// another, which are the positions of the blocks its feet are in. Returns `None` if the
+    /// entity can't stand at either position, or if there is no path within the number of
+    /// positions the capabilities allow to visit.
+    #[must_use]
+    pub fn find_path(
+        &self,
+        from: BlockPos,
+        to: BlockPos,
+        capabilities: &Capabilities,
+    ) -> Option<Path> {
+        Pathfinder {
+            world: self,
+            capabilities,
+        }
+        .find(from, to)
+    }
+}
+
+/// A position an entity can stand at.
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    pos: BlockPos,
+    /// The height the feet of the entity are at, which is the top of the collision shape of the
+    /// block below, like vanilla's `getFloorLevel`.
+    floor: f64,
+    in_water: bool,
+}
+
+/// What an entity finds at a position.
+enum Spot {
+    /// The entity can stand there, opening a door if `opens_door` is `true`.
+    Node { node: Node, opens_door: bool },
+    /// The entity fits there, but has no ground to stand on.
+    Falling { in_water: bool },
+    /// The entity doesn't fit there, or would get hurt.
+    Blocked,
+}
+
+struct Pathfinder<'a> {
+    world: &'a World,
+    capabilities: &'a Capabilities,
+}
+
+impl Pathfinder<'_> {
+    fn find(&self, from: BlockPos, to: BlockPos) -> Option<Path> {
+        let Spot::Node { node: start, .. } = self.spot(from) else {
+            return None;
+        };
+
+        if !matches!(self.spot(to), Spot::Node { .. }) {
+            return None;
+        }
+
+        let mut open = BinaryHeap::new();
+        let mut visited = FxHashMap::default();
+        let mut visited_count = 0;
+
+        open.push(Open {
+            estimate: estimate(from, to),
+            cost: 0.0,
+            node: start,
+        });
+        visited.insert(
+            from,
+            Visit {
+                cost: 0.0,
+                parent: None,
+            },
+        );
+
+        while let Some(Open { cost, node, .. }) = open.pop() {
+            if node.pos == to {
+                return Some(path(&visited, to, cost));
+            }
+
+            // The position may have been reached more cheaply since this entry was pushed.
+            if cost > visited[&node.pos].cost {
+                continue;
+            }
+
+            visited_count += 1;
+
+            if visited_count > self.capabilities.max_visited_nodes {
+                return None;
+            }
+
+            for (neighbor, step_cost) in self.neighbors(node) {
+                let cost = cost + step_cost;
+                let visit = Visit {
+                    cost,
+                    parent: Some(node.pos),
+                };
+
+                match visited.entry(neighbor.pos) {
+                    Entry::Occupied(entry) if entry.get().cost <= cost => continue,
+                    Entry::Occupied(mut entry) => *entry.get_mut() = visit,
+                    Entry::Vacant(entry) => {
+                        entry.insert(visit);
+                    }
+                }
+
+                open.push(Open {
+                    estimate: cost + estimate(neighbor.pos, to),
+                    cost,
+                    node: neighbor,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Returns the positions the entity can move to from the given one, along with the cost of
+    /// moving there.
+    fn neighbors(&self, node: Node) -> Vec<(Node, f32)> {
+        let mut neighbors = Vec::new();
+
+        for direction in Direction::HORIZONTAL {
+            if let Some(neighbor) = node
+                .pos
+                .neighbor(direction)
+                .and_then(|pos| self.walk(node, pos))
+            {
+                neighbors.push(neighbor);
+            }
+        }
+
+        if node.in_water && self.capabilities.can_swim {
+            for direction in [Direction::Up, Direction::Down] {
+                if let Some(Spot::Node {
+                    node: neighbor,
+                    opens_door,
+                }) = node.pos.neighbor(direction).map(|pos| self.spot(pos))
+                {
+                    let door_cost = if opens_door { DOOR_COST } else { 0.0 };
+                    neighbors.push((neighbor, SWIM_COST + door_cost));
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    /// Moves the entity into the column of blocks next to it, stepping up, jumping or falling to
+    /// the position it can stand at in that column.
+    #[allow(clippy::cast_possible_truncation)]
+    fn walk(&self, node: Node, column: BlockPos) -> Option<(Node, f32)> {
+        let max_rise = self.capabilities.jump_height.max(STEP_HEIGHT);
+
+        for rise in 0..=max_rise.ceil() as i32 {
+            let pos = BlockPos::new(column.to_inner() + glam::IVec3::Y * rise).ok()?;
+
+            match self.spot(pos) {
+                Spot::Node {
+                    node: neighbor,
+                    opens_door,
+                } => return self.step(node, neighbor, opens_door, false),
+                Spot::Falling { .. } if rise == 0 => return self.fall(node, pos),
+                Spot::Falling { .. } | Spot::Blocked => {}
+            }
+        }
+
+        None
+    }
+
+    /// Lets the entity fall from the given position until it lands.
+    fn fall(&self, node: Node, mut pos: BlockPos) -> Option<(Node, f32)> {
+        let mut through_water = false;
+
+        loop {
+            pos = pos.neighbor(Direction::Down)?;
+
+            match self.spot(pos) {
+                Spot::Node {
+                    node: neighbor,
+                    opens_door,
+                } => return self.step(node, neighbor, opens_door, through_water),
+                Spot::Falling { in_water } => through_water |= in_water,
+                Spot::Blocked => return None,
+            }
+        }
+    }
+
+    /// Returns the cost of moving from one position to another, or `None` if the entity can't
+    /// jump that high, doesn't fit on the way, or would take too much fall damage.
+    #[allow(clippy::cast_possible_truncation)]
+    fn step(
+        &self,
+        from: Node,
+        to: Node,
+        opens_door: bool,
+        through_water: bool,
+    ) -> Option<(Node, f32)> {
+        let rise = to.floor - from.floor;
+
+        if rise > self.capabilities.jump_height.max(STEP_HEIGHT) {
+            return None;
+        }
+
+        // The entity needs room above it to jump, and room between both positions to move over,
+        // at the height of the higher one.
+        if rise > STEP_HEIGHT {
+            self.clearance(from.pos, from.pos, to.floor)?;
+        }
+
+        let opens_door = opens_door | self.clearance(from.pos, to.pos, from.floor.max(to.floor))?;
+
+        let damage = if through_water || to.in_water {
+            0.0
+        } else {
+            let ground = self.world.block(to.pos.neighbor(Direction::Down)?)?;
+            fall_damage(-rise as f32, ground)
+        };
+
+        if damage > self.capabilities.max_fall_damage {
+            return None;
+        }
+
+        let mut cost = if to.in_water {
+            SWIM_COST
+        } else {
+            let speed_factor = self.speed_factor(to);
+
+            if speed_factor <= 0.0 {
+                return None;
+            }
+
+            1.0 / speed_factor.min(1.0)
+        };
+
+        if rise > STEP_HEIGHT {
+            cost += JUMP_COST;
+        }
+
+        if opens_door {
+            cost += DOOR_COST;
+        }
+
+        Some((to, cost + damage * FALL_DAMAGE_COST))
+    }
+
+    /// Returns what the entity finds at the given position.
+    fn spot(&self, pos: BlockPos) -> Spot {
+        let Some(block) = self.world.block(pos) else {
+            return Spot::Blocked;
+        };
+        let ground = pos
+            .neighbor(Direction::Down)
+            .and_then(|pos| self.world.block(pos));
+
+        if is_harmful(block) || ground.is_some_and(is_harmful) {
+            return Spot::Blocked;
+        }
+
+        let ground_height = ground
+            .filter(|ground| ground.behavior().has_collision)
+            .and_then(|ground| ground.collision_shape().bounds())
+            .map(|bounds| bounds.max[1]);
+        let y = f64::from(pos.to_inner().y);
+        let floor = ground_height.map_or(y, |height| y - 1.0 + height);
+        let in_water = block.is_liquid();
+
+        let Some(opens_door) = self.clearance(pos, pos, floor) else {
+            return Spot::Blocked;
+        };
+
+        if ground_height.is_some() || (in_water && self.capabilities.can_swim) {
+            Spot::Node {
+                node: Node {
+                    pos,
+                    floor,
+                    in_water,
+                },
+                opens_door,
+            }
+        } else {
+            Spot::Falling { in_water }
+        }
+    }
+
+    /// Returns whether the entity fits anywhere between the middles of the columns of blocks of
+    /// the given positions with its feet at the given height, and whether it has to open a door
+    /// for that, or `None` if it doesn't fit. Checking the space between two columns catches the
+    /// shapes at the edges of blocks, like those of closed doors.
+    #[allow(clippy::cast_possible_truncation)]
+    fn clearance(&self, from: BlockPos, to: BlockPos, floor: f64) -> Option<bool> {
+        let (from, to) = (from.to_inner(), to.to_inner());
+        let radius = self.capabilities.width / 2.0;
+        let span = |a: i32, b: i32| f64::from(a.min(b)) + 0.5..f64::from(a.max(b)) + 0.5;
+        let (x, z) = (span(from.x, to.x), span(from.z, to.z));
+        let aabb = Aabb::new(
+            [x.start - radius, floor, z.start - radius],
+            [
+                x.end + radius,
+                floor + self.capabilities.height,
+                z.end + radius,
+            ],
+        );
+        let mut opens_door = false;
+
+        // Start a block lower for the blocks whose collision shapes stick out above them, like
+        // fences.
+        for x in aabb.min[0].floor() as i32..aabb.max[0].ceil() as i32 {
+            for y in aabb.min[1].floor() as i32 - 1..aabb.max[1].ceil() as i32 {
+                for z in aabb.min[2].floor() as i32..aabb.max[2].ceil() as i32 {
+                    let block = self
+                        .world
+                        .block(BlockPos::new(glam::ivec3(x, y, z)).ok()?)?;
+
+                    if !block.behavior().has_collision {
+                        continue;
+                    }
+
+                    let aabb = aabb.translate([x, y, z].map(|c| -f64::from(c)));
+
+                    if !block
+                        .collision_shape()
+                        .boxes()
+                        .iter()
+                        .any(|shape| shape.intersects(&aabb))
+                    {
+                        continue;
+                    }
+
+                    if self.capabilities.can_open_doors && block.is_in(vanilla("wooden_doors")) {
+                        opens_door = true;
+                        continue;
+                    }
+
+                    return None;
+                }
+            }
+        }
+
+        Some(opens_door)
+    }
+
+    /// Returns the speed factor of the blocks the entity stands in and on, like vanilla's
+    /// `getBlockSpeedFactor`.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::float_cmp)] // This is what vanilla does.
+    fn speed_factor(&self, node: Node) -> f32 {
+        let pos = node.pos.to_inner();
+        let block_at = |y: f64| {
+            let pos = BlockPos::new(glam::ivec3(pos.x, y.floor() as i32, pos.z)).ok()?;
+            self.world.block(pos)
+        };
+        let Some(block) = block_at(node.floor) else {
+            return 1.0;
+        };
+        let speed_factor = block.behavior().speed_factor;
+
+        if speed_factor == 1.0 && !matches!(block.id(), BlockId::Water | BlockId::BubbleColumn) {
+            block_at(node.floor - 0.500_000_1).map_or(1.0, |block| block.behavior().speed_factor)
+        } else {
+            speed_factor
+        }
+    }
+}
+
+/// Returns `true` if entities get hurt standing in or on the block, like in fire or lava or on
+/// magma blocks, which they avoid.
+fn is_harmful(block: Block) -> bool {
+    use BlockId::*;
+
+    match block.id() {
+        Fire | SoulFire | MagmaBlock | Cactus | SweetBerryBush | WitherRose | PowderSnow => true,
+        Campfire | SoulCampfire => block.get::<state::Lit>().is_some_and(|lit| lit.0),
+        _ => block.fluid_state().fluid() == Fluid::Lava,
+    }
+}
+
+/// Returns the damage an entity takes falling the given distance onto the given block, like
+/// vanilla's `calculateFallDamage` and the `fallOn` of blocks that soften falls.
+fn fall_damage(distance: f32, ground: Block) -> f32 {
+    use BlockId::*;
+
+    let (distance, multiplier) = match ground.id() {
+        HayBlock | HoneyBlock => (distance, 0.2),
+        SlimeBlock => (distance, 0.0),
+        _ if ground.is_in(vanilla("beds")) => (distance * 0.5, 1.0),
+        _ => (distance, 1.0),
+    };
+
+    ((distance - SAFE_FALL_DISTANCE) * multiplier)
+        .ceil()
+        .max(0.0)
+}
+
+/// Returns a lower bound of the cost of a path between the given positions, which is their
+/// horizontal distance as entities move a block horizontally at a cost of at least 1.
+#[allow(clippy::cast_precision_loss)]
+fn estimate(from: BlockPos, to: BlockPos) -> f32 {
+    let distance = (from.to_inner() - to.to_inner()).abs();
+
+    (distance.x + distance.z) as f32
+}
+
+/// Walks back from the goal to the start to find the path to the goal.
+fn path(visited: &FxHashMap<BlockPos, Visit>, to: BlockPos, cost: f32) -> Path {
+    let mut positions = vec![to];
+
+    while let Some(parent) = visited[positions.last().unwrap()].parent {
+        positions.push(parent);
+    }
+
+    positions.reverse();
+
+    Path { positions, cost }
+}
+
+/// The cheapest way found yet to reach a position.
+struct Visit {
+    cost: f32,
+    parent: Option<BlockPos>,
+}
+
+/// A position to visit, ordered so that the position with the lowest estimated cost of a path
+/// through it is visited first.
+struct Open {
+    estimate: f32,
+    cost: f32,
+    node: Node,
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
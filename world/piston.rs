@@ -0,0 +1,550 @@
+//! Pistons, which extend when powered and retract when not, pushing and pulling the blocks in
+//! front of them. Like in vanilla, they move through block events, which run at the end of the
+//! tick. Unlike in vanilla, moved blocks land at once instead of sliding into place over the
+//! following ticks.
+
+use strum::IntoEnumIterator;
+
+use crate::block::piston::PushReaction;
+use crate::block::state;
+use crate::redstone::sort_like_hash_set;
+use crate::update::Updates;
+use crate::{Block, BlockId, BlockPos, Direction, World};
+
+/// How many blocks a piston can push or pull at once.
+const MAX_PUSHED: usize = 12;
+
+/// A block event, which vanilla queues for blocks to act at the end of the tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct BlockEvent {
+    pos: BlockPos,
+    id: BlockId,
+    action: PistonAction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PistonAction {
+    Extend,
+    Retract,
+}
+
+/// The blocks moved by a piston, found like vanilla's `PistonStructureResolver`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PistonStructure {
+    /// The direction the blocks move in, which is the facing of the piston when it extends and
+    /// the opposite when it retracts.
+    pub push_direction: Direction,
+    /// The blocks that move one block in the push direction. Each line of blocks is listed from
+    /// back to front, and blocks are moved in reverse order.
+    pub to_push: Vec<BlockPos>,
+    /// The blocks that are destroyed because they're in the way, like torches and flowers.
+    pub to_destroy: Vec<BlockPos>,
+}
+
+impl World {
+    /// Finds the blocks the piston at the given position moves when it extends or retracts in the
+    /// given direction, or `None` if it can't move them. Extending pistons push the blocks in
+    /// front of them and sticky pistons pull the block two blocks away when they retract. Slime
+    /// and honey blocks drag the blocks stuck to them along, but not each other. Pistons can't
+    /// move more than 12 blocks, or any immovable block, like obsidian, extended pistons and
+    /// blocks with a block entity, like chests.
+    #[must_use]
+    pub fn resolve_piston_structure(
+        &self,
+        pos: BlockPos,
+        direction: Direction,
+        extending: bool,
+    ) -> Option<PistonStructure> {
+        let (push_direction, start_pos) = if extending {
+            (direction, pos.neighbor(direction)?)
+        } else {
+            (direction.opposite(), offset(pos, direction, 2)?)
+        };
+        let mut resolver = Resolver {
+            world: self,
+            piston_pos: pos,
+            push_direction,
+            to_push: Vec::new(),
+            to_destroy: Vec::new(),
+        };
+
+        resolver
+            .resolve(start_pos, direction, extending)
+            .then_some(PistonStructure {
+                push_direction,
+                to_push: resolver.to_push,
+                to_destroy: resolver.to_destroy,
+            })
+    }
+
+    /// Runs the queued block events, including those queued while they run.
+    pub(crate) fn run_block_events(&mut self) {
+        while !self.block_events.is_empty() {
+            let event = self.block_events.remove(0);
+
+            if let Some(block) = self.block(event.pos).filter(|block| block.id() == event.id) {
+                self.move_piston(event.pos, block, event.action);
+            }
+        }
+    }
+
+    /// Queues the piston at the given position to move if it's powered and retracted, or
+    /// unpowered and extended.
+    pub(crate) fn check_if_extend(&mut self, pos: BlockPos, piston: Block) {
+        let Some(facing) = piston_facing(piston) else {
+            return;
+        };
+        let powered = self.is_piston_powered(pos, facing);
+        let extended = is_extended(piston);
+
+        let action = if powered && !extended {
+            if self.resolve_piston_structure(pos, facing, true).is_none() {
+                return;
+            }
+
+            PistonAction::Extend
+        } else if !powered && extended {
+            PistonAction::Retract
+        } else {
+            return;
+        };
+
+        let event = BlockEvent {
+            pos,
+            id: piston.id(),
+            action,
+        };
+
+        if !self.block_events.contains(&event) {
+            self.block_events.push(event);
+        }
+    }
+
+    /// Returns `true` if the piston at the given position is powered, which it also is when the
+    /// block above it is, as in vanilla.
+    fn is_piston_powered(&self, pos: BlockPos, facing: Direction) -> bool {
+        let is_powered_from = |pos: BlockPos, direction: Direction| {
+            pos.neighbor(direction)
+                .is_some_and(|neighbor_pos| self.has_signal(neighbor_pos, direction))
+        };
+
+        Direction::iter()
+            .filter(|&direction| direction != facing)
+            .any(|direction| is_powered_from(pos, direction))
+            || pos.neighbor(Direction::Up).is_some_and(|above| {
+                Direction::iter()
+                    .filter(|&direction| direction != Direction::Down)
+                    .any(|direction| is_powered_from(above, direction))
+            })
+    }
+
+    /// Returns `true` if a piston facing in the given direction can move the block at the given
+    /// position in the given direction. Blocks that are destroyed when pushed only count if
+    /// `allow_destroy` is set.
+    fn is_pushable(
+        &self,
+        pos: BlockPos,
+        movement: Direction,
+        allow_destroy: bool,
+        piston_facing: Direction,
+    ) -> bool {
+        use BlockId::*;
+
+        let Some(block) = self.block(pos) else {
+            return false;
+        };
+        let id = block.id();
+
+        if block.behavior().is_air {
+            return true;
+        }
+
+        match id {
+            Obsidian | CryingObsidian | RespawnAnchor => false,
+            // Blocks can't be moved out of the build limit.
+            _ if pos.neighbor(movement).is_none() => false,
+            Piston | StickyPiston => !is_extended(block),
+            _ if block.behavior().destroy_time < 0.0 => false,
+            _ => match block.push_reaction() {
+                PushReaction::Block => false,
+                PushReaction::Destroy => allow_destroy,
+                PushReaction::PushOnly => movement == piston_facing,
+                PushReaction::Normal | PushReaction::Ignore => !block.has_block_entity(),
+            },
+        }
+    }
+
+    fn move_piston(&mut self, pos: BlockPos, mut piston: Block, action: PistonAction) {
+        let Some(facing) = piston_facing(piston) else {
+            return;
+        };
+        let powered = self.is_piston_powered(pos, facing);
+
+        match action {
+            PistonAction::Extend if !powered => {}
+            // The piston was powered again before retracting.
+            PistonAction::Retract if powered => {
+                piston.set(state::Extended(true));
+                self.replace_block(pos, piston, Updates::Shapes, 0);
+            }
+            PistonAction::Extend => {
+                let Some(landed) = self.move_blocks(pos, piston, facing, true) else {
+                    return;
+                };
+
+                piston.set(state::Extended(true));
+                self.replace_block(pos, piston, Updates::All, 0);
+                self.land_blocks(&landed);
+            }
+            PistonAction::Retract => {
+                piston.set(state::Extended(false));
+                self.replace_block(pos, piston, Updates::All, 0);
+
+                if let Some(head_pos) = pos.neighbor(facing).filter(|&head_pos| {
+                    self.block(head_pos)
+                        .is_some_and(|head| head.id() == BlockId::PistonHead)
+                }) {
+                    self.replace_block(head_pos, Block::from_id(BlockId::Air), Updates::All, 0);
+                }
+
+                let landed = if piston.id() == BlockId::StickyPiston && self.can_pull(pos, facing) {
+                    self.move_blocks(pos, piston, facing, false)
+                } else {
+                    None
+                };
+
+                self.land_blocks(&[pos]);
+                self.land_blocks(&landed.unwrap_or_default());
+            }
+        }
+    }
+
+    /// Returns `true` if the sticky piston at the given position, facing in the given direction,
+    /// pulls the block two blocks away as it retracts.
+    fn can_pull(&self, pos: BlockPos, facing: Direction) -> bool {
+        offset(pos, facing, 2).is_some_and(|pull_pos| {
+            self.block(pull_pos).is_some_and(|block| {
+                !block.behavior().is_air
+                    && self.is_pushable(pull_pos, facing.opposite(), false, facing)
+                    && (block.push_reaction() == PushReaction::Normal
+                        || matches!(block.id(), BlockId::Piston | BlockId::StickyPiston))
+            })
+        })
+    }
+
+    /// Moves the blocks in front of the piston at the given position one block, and places its
+    /// head if it extends. Returns where the blocks land, or `None` if they can't be moved.
+    fn move_blocks(
+        &mut self,
+        pos: BlockPos,
+        piston: Block,
+        facing: Direction,
+        extending: bool,
+    ) -> Option<Vec<BlockPos>> {
+        let structure = self.resolve_piston_structure(pos, facing, extending)?;
+        let moved: Vec<Block> = structure
+            .to_push
+            .iter()
+            .map(|&pos| self.block(pos).expect("the moved block should be loaded"))
+            .collect();
+        let destroyed: Vec<Block> = structure
+            .to_destroy
+            .iter()
+            .map(|&pos| {
+                self.block(pos)
+                    .expect("the destroyed block should be loaded")
+            })
+            .collect();
+        let mut vacated = structure.to_push.clone();
+        let mut landed = Vec::with_capacity(moved.len() + 1);
+
+        for &destroyed_pos in structure.to_destroy.iter().rev() {
+            self.replace_block(
+                destroyed_pos,
+                Block::from_id(BlockId::Air),
+                Updates::None,
+                0,
+            );
+        }
+
+        for (&from, &block) in structure.to_push.iter().zip(&moved).rev() {
+            let to = from
+                .neighbor(structure.push_direction)
+                .expect("moved blocks should stay in the build limit");
+
+            vacated.retain(|&pos| pos != to);
+            self.replace_block(to, block, Updates::Shapes, 0);
+            landed.push(to);
+        }
+
+        let head_pos = pos.neighbor(facing).filter(|_| extending);
+
+        if let Some(head_pos) = head_pos {
+            let mut head = Block::from_id(BlockId::PistonHead);
+            head.set(state::Facing::from(facing));
+            head.set(match piston.id() {
+                BlockId::StickyPiston => state::PistonType::Sticky,
+                _ => state::PistonType::Normal,
+            });
+
+            vacated.retain(|&pos| pos != head_pos);
+            self.replace_block(head_pos, head, Updates::Shapes, 0);
+            landed.push(head_pos);
+        }
+
+        sort_like_hash_set(&mut vacated);
+
+        for vacated_pos in vacated {
+            self.replace_block(
+                vacated_pos,
+                Block::from_id(BlockId::Air),
+                Updates::Shapes,
+                0,
+            );
+        }
+
+        for (&destroyed_pos, block) in structure.to_destroy.iter().zip(destroyed).rev() {
+            self.update_neighbors(destroyed_pos, block.id());
+        }
+
+        for (&from, block) in structure.to_push.iter().zip(moved).rev() {
+            self.update_neighbors(from, block.id());
+        }
+
+        if let Some(head_pos) = head_pos {
+            self.update_neighbors(head_pos, BlockId::PistonHead);
+        }
+
+        Some(landed)
+    }
+
+    /// Finishes moving the blocks at the given positions, which take the state matching their new
+    /// neighbors and tell them that they arrived, like vanilla's moving blocks when they stop.
+    fn land_blocks(&mut self, positions: &[BlockPos]) {
+        for &pos in positions {
+            let Some(block) = self.block(pos) else {
+                continue;
+            };
+            let shaped = self.shape_against_neighbors(pos, block);
+
+            if shaped.id() == BlockId::Air {
+                self.replace_block(pos, shaped, Updates::All, 0);
+                continue;
+            }
+
+            self.replace_block(pos, shaped, Updates::Shapes, 0);
+            self.update_neighbors(pos, shaped.id());
+            self.neighbor_changed(pos, shaped.id());
+        }
+    }
+}
+
+/// Finds the blocks moved by a piston, following vanilla's `PistonStructureResolver` to the
+/// letter so that the same blocks move in the same order.
+struct Resolver<'a> {
+    world: &'a World,
+    piston_pos: BlockPos,
+    push_direction: Direction,
+    to_push: Vec<BlockPos>,
+    to_destroy: Vec<BlockPos>,
+}
+
+impl Resolver<'_> {
+    fn resolve(&mut self, start_pos: BlockPos, piston_facing: Direction, extending: bool) -> bool {
+        if !self
+            .world
+            .is_pushable(start_pos, self.push_direction, false, piston_facing)
+        {
+            let is_destroyed = self
+                .world
+                .block(start_pos)
+                .is_some_and(|start| start.push_reaction() == PushReaction::Destroy);
+
+            if extending && is_destroyed {
+                self.to_destroy.push(start_pos);
+                return true;
+            }
+
+            return false;
+        }
+
+        if !self.add_block_line(start_pos, self.push_direction) {
+            return false;
+        }
+
+        // Sticky blocks add more blocks to the end of the list as it's walked.
+        let mut index = 0;
+
+        while let Some(&pos) = self.to_push.get(index) {
+            if is_sticky(self.world.block(pos)) && !self.add_branching_blocks(pos) {
+                return false;
+            }
+
+            index += 1;
+        }
+
+        true
+    }
+
+    /// Adds the line of blocks starting at the given position, along with the sticky blocks
+    /// dragging blocks behind it and the blocks it pushes ahead. Returns `false` if the blocks
+    /// can't be moved.
+    fn add_block_line(&mut self, origin: BlockPos, direction: Direction) -> bool {
+        let mut block = self.world.block(origin);
+
+        if is_air(block)
+            || !self
+                .world
+                .is_pushable(origin, self.push_direction, false, direction)
+            || origin == self.piston_pos
+            || self.to_push.contains(&origin)
+        {
+            return true;
+        }
+
+        let behind = self.push_direction.opposite();
+        let mut length = 1;
+
+        if length + self.to_push.len() > MAX_PUSHED {
+            return false;
+        }
+
+        while is_sticky(block) {
+            let Some(pos) = offset(origin, behind, length) else {
+                break;
+            };
+            let previous = block;
+            block = self.world.block(pos);
+
+            if is_air(block)
+                || !can_stick_to_each_other(previous, block)
+                || !self
+                    .world
+                    .is_pushable(pos, self.push_direction, false, behind)
+                || pos == self.piston_pos
+            {
+                break;
+            }
+
+            length += 1;
+
+            if length + self.to_push.len() > MAX_PUSHED {
+                return false;
+            }
+        }
+
+        let mut added = 0;
+
+        for distance in (0..length).rev() {
+            let pos = offset(origin, behind, distance).expect("the dragged block should exist");
+            self.to_push.push(pos);
+            added += 1;
+        }
+
+        let mut distance = 1;
+
+        loop {
+            let Some(pos) = offset(origin, self.push_direction, distance) else {
+                return false;
+            };
+
+            if let Some(index) = self.to_push.iter().position(|&pushed| pushed == pos) {
+                // The line runs into blocks that are already moving, which must move after it.
+                self.to_push[index..].rotate_right(added);
+
+                for index in 0..=index + added {
+                    let pos = self.to_push[index];
+
+                    if is_sticky(self.world.block(pos)) && !self.add_branching_blocks(pos) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            let block = self.world.block(pos);
+
+            if is_air(block) {
+                return true;
+            }
+
+            if !self
+                .world
+                .is_pushable(pos, self.push_direction, true, self.push_direction)
+                || pos == self.piston_pos
+            {
+                return false;
+            }
+
+            if block.is_some_and(|block| block.push_reaction() == PushReaction::Destroy) {
+                self.to_destroy.push(pos);
+                return true;
+            }
+
+            if self.to_push.len() >= MAX_PUSHED {
+                return false;
+            }
+
+            self.to_push.push(pos);
+            added += 1;
+            distance += 1;
+        }
+    }
+
+    /// Adds the lines of blocks stuck to the sides of the sticky block at the given position.
+    fn add_branching_blocks(&mut self, from: BlockPos) -> bool {
+        let block = self.world.block(from);
+
+        for direction in Direction::iter() {
+            if direction.is_parallel_to(self.push_direction) {
+                continue;
+            }
+
+            let Some(pos) = from.neighbor(direction) else {
+                continue;
+            };
+
+            if can_stick_to_each_other(self.world.block(pos), block)
+                && !self.add_block_line(pos, direction)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn offset(pos: BlockPos, direction: Direction, distance: usize) -> Option<BlockPos> {
+    (0..distance).try_fold(pos, |pos, _| pos.neighbor(direction))
+}
+
+fn is_air(block: Option<Block>) -> bool {
+    block.is_some_and(|block| block.behavior().is_air)
+}
+
+fn is_sticky(block: Option<Block>) -> bool {
+    block.is_some_and(|block| matches!(block.id(), BlockId::SlimeBlock | BlockId::HoneyBlock))
+}
+
+/// Returns `true` if the blocks stick together when one of them moves, which they do if either
+/// is sticky, except for slime and honey blocks.
+fn can_stick_to_each_other(a: Option<Block>, b: Option<Block>) -> bool {
+    use BlockId::*;
+
+    match (a.map(Block::id), b.map(Block::id)) {
+        (Some(HoneyBlock), Some(SlimeBlock)) | (Some(SlimeBlock), Some(HoneyBlock)) => false,
+        _ => is_sticky(a) || is_sticky(b),
+    }
+}
+
+fn piston_facing(piston: Block) -> Option<Direction> {
+    piston.get::<state::Facing>().map(Direction::from)
+}
+
+fn is_extended(piston: Block) -> bool {
+    piston
+        .get::<state::Extended>()
+        .is_some_and(|extended| extended.0)
+}
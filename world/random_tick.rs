@@ -0,0 +1,517 @@
+//! Random ticks, which vanilla gives to a few random blocks of each section every game tick. They
+//! make crops grow, leaves decay, grass spread and ice melt over time.
+
+use strum::IntoEnumIterator;
+
+use crate::block::random::Random;
+use crate::block::state::{self, Integer};
+use crate::block::tag::vanilla;
+use crate::tick::Priority;
+use crate::update::{growing_plant, Updates};
+use crate::{Block, BlockId, BlockPos, Chunk, ChunkPos, Direction, SectionY, World};
+
+/// The vanilla default of the `randomTickSpeed` game rule.
+const DEFAULT_RANDOM_TICK_SPEED: u32 = 3;
+
+/// The light level crops need to grow.
+const GROWTH_LIGHT: u8 = 9;
+
+/// The distance of leaves which aren't connected to a log, at which they decay.
+const DECAY_DISTANCE: u32 = 7;
+
+/// Picks the blocks that tick randomly, like vanilla's `ServerLevel::tickChunk`.
+pub(crate) struct RandomTicks {
+    speed: u32,
+    /// The state of the generator picking the positions, which vanilla keeps apart from the
+    /// random number generator of the world.
+    value: i32,
+}
+
+impl RandomTicks {
+    fn new(speed: u32, random_seed: u64) -> Self {
+        RandomTicks {
+            speed,
+            value: Random::new(random_seed).next_i32(),
+        }
+    }
+
+    /// Restarts picking positions from the given seed.
+    pub(crate) fn reseed(&mut self, seed: u64) {
+        *self = RandomTicks::new(self.speed, seed);
+    }
+
+    /// Picks a random position in the given section.
+    fn next_pos(&mut self, chunk_pos: ChunkPos, section_y: SectionY) -> BlockPos {
+        self.value = self.value.wrapping_mul(3).wrapping_add(1_013_904_223);
+        let bits = self.value >> 2;
+        let offset = glam::ivec3(bits & 15, (bits >> 16) & 15, (bits >> 8) & 15);
+        let origin = glam::ivec3(chunk_pos.0.x, section_y.0, chunk_pos.0.y) * 16;
+
+        BlockPos(origin + offset)
+    }
+}
+
+impl Default for RandomTicks {
+    fn default() -> Self {
+        RandomTicks::new(DEFAULT_RANDOM_TICK_SPEED, 0)
+    }
+}
+
+impl World {
+    /// Returns the number of blocks picked to tick randomly in each section every game tick,
+    /// which is the `randomTickSpeed` game rule of vanilla.
+    #[must_use]
+    pub fn random_tick_speed(&self) -> u32 {
+        self.random_ticks.speed
+    }
+
+    /// Sets the number of blocks picked to tick randomly in each section every game tick. Random
+    /// ticks make crops grow, leaves decay, grass spread and ice melt, and are turned off with a
+    /// speed of 0. The default is 3, like in vanilla.
+    pub fn set_random_tick_speed(&mut self, speed: u32) {
+        self.random_ticks.speed = speed;
+    }
+
+    /// Ticks random blocks in every section of the loaded chunks with blocks that tick randomly.
+    /// Chunks are visited in order of position for the simulation to be reproducible.
+    pub(crate) fn tick_random_blocks(&mut self) {
+        let speed = self.random_ticks.speed;
+
+        if speed == 0 {
+            return;
+        }
+
+        let mut chunk_positions: Vec<ChunkPos> = self.loaded_chunks.keys().copied().collect();
+        chunk_positions.sort_by_key(|chunk_pos| (chunk_pos.0.x, chunk_pos.0.y));
+
+        for chunk_pos in chunk_positions {
+            for section_index in 0..Chunk::SECTION_COUNT {
+                let section_y = SectionY::from_index(section_index);
+                let is_ticking = self
+                    .loaded_chunk(chunk_pos)
+                    .is_some_and(|chunk| chunk[section_y].random_ticking_count > 0);
+
+                if !is_ticking {
+                    continue;
+                }
+
+                for _ in 0..speed {
+                    let pos = self.random_ticks.next_pos(chunk_pos, section_y);
+
+                    if let Some(block) = self.block(pos).filter(|&block| is_randomly_ticking(block))
+                    {
+                        self.random_tick(pos, block);
+                    }
+                }
+            }
+        }
+    }
+
+    fn random_tick(&mut self, pos: BlockPos, block: Block) {
+        use BlockId::*;
+
+        match block.id() {
+            Wheat | Carrots | Potatoes => self.grow_crop(pos, block),
+            Beetroots if self.random.next_i32_below(3) != 0 => self.grow_crop(pos, block),
+            PumpkinStem | MelonStem => self.grow_stem(pos, block),
+            NetherWart if self.random.next_i32_below(10) == 0 => self.age_plant(pos, block),
+            SweetBerryBush
+                if age(block).is_some_and(|(age, max_age)| age < max_age)
+                    && self.random.next_i32_below(5) == 0
+                    && self.brightness_above(pos) >= GROWTH_LIGHT =>
+            {
+                self.age_plant(pos, block);
+            }
+            Cocoa if self.random.next_i32_below(5) == 0 => self.age_plant(pos, block),
+            SugarCane | Cactus => self.grow_column(pos, block),
+            Kelp => self.grow_plant_tip(pos, block, 0.14),
+            WeepingVines | TwistingVines => self.grow_plant_tip(pos, block, 0.1),
+            CaveVines => self.grow_plant_tip(pos, block, 0.11),
+            GrassBlock | Mycelium => self.spread_grass(pos, block),
+            Lava => self.spread_lava_fire(pos),
+            Ice if self.block_light(pos) > 11 - block.light_block() => {
+                let water = Block::from_id(Water);
+                self.replace_block(pos, water, Updates::All, 0);
+                self.neighbor_changed(pos, Water);
+            }
+            Snow if self.block_light(pos) > 11 => {
+                self.replace_block(pos, Block::from_id(Air), Updates::All, 0);
+            }
+            // Leaves that tick randomly are too far from any log.
+            id if id.is_in(vanilla("leaves")) => {
+                self.replace_block(pos, Block::from_id(Air), Updates::All, 0);
+            }
+            _ => {}
+        }
+    }
+
+    /// Lets the crop at the given position grow if it has enough light, more likely so on moist
+    /// farmland and away from crops of the same kind.
+    fn grow_crop(&mut self, pos: BlockPos, block: Block) {
+        if self.brightness(pos) < GROWTH_LIGHT {
+            return;
+        }
+
+        if age(block).is_some_and(|(age, max_age)| age < max_age) {
+            let speed = self.growth_speed(pos, block.id());
+
+            if self.random.next_i32_below((25.0 / speed) as i32 + 1) == 0 {
+                self.age_plant(pos, block);
+            }
+        }
+    }
+
+    /// Lets the pumpkin or melon stem at the given position grow, and once fully grown, grow its
+    /// fruit on a random side.
+    fn grow_stem(&mut self, pos: BlockPos, block: Block) {
+        use BlockId::*;
+
+        if self.brightness(pos) < GROWTH_LIGHT {
+            return;
+        }
+
+        let speed = self.growth_speed(pos, block.id());
+
+        if self.random.next_i32_below((25.0 / speed) as i32 + 1) != 0 {
+            return;
+        }
+
+        if age(block).is_some_and(|(age, max_age)| age < max_age) {
+            self.age_plant(pos, block);
+            return;
+        }
+
+        let direction = Direction::HORIZONTAL[self.random.next_i32_below(4) as usize];
+        let Some(fruit_pos) = pos.neighbor(direction) else {
+            return;
+        };
+        let soil = self.neighbor_block(fruit_pos, Direction::Down);
+
+        if self.block(fruit_pos).is_some_and(is_air)
+            && (soil.id() == Farmland || soil.is_in(vanilla("dirt")))
+        {
+            let (fruit, attached_stem) = match block.id() {
+                PumpkinStem => (Pumpkin, AttachedPumpkinStem),
+                _ => (Melon, AttachedMelonStem),
+            };
+            let mut attached_stem = Block::from_id(attached_stem);
+            attached_stem.set(
+                state::HorizontalFacing::try_from(direction)
+                    .expect("the direction should be horizontal"),
+            );
+
+            self.replace_block(fruit_pos, Block::from_id(fruit), Updates::All, 0);
+            self.replace_block(pos, attached_stem, Updates::All, 0);
+        }
+    }
+
+    /// Returns how fast the crop at the given position grows. Farmland under and around the crop
+    /// speeds it up, especially when moist, and crops of the same kind around it slow it down
+    /// unless they're planted in rows.
+    fn growth_speed(&self, pos: BlockPos, crop: BlockId) -> f32 {
+        let block_at = |x, y, z| {
+            BlockPos::new(pos.to_inner() + glam::ivec3(x, y, z))
+                .ok()
+                .and_then(|pos| self.block(pos))
+                .unwrap_or_default()
+        };
+        let mut speed = 1.0;
+
+        for x in -1..=1 {
+            for z in -1..=1 {
+                let soil = block_at(x, -1, z);
+                let mut soil_speed = match soil.get::<state::Moisture>() {
+                    Some(moisture) if soil.id() == BlockId::Farmland && moisture.0.get() > 0 => 3.0,
+                    _ if soil.id() == BlockId::Farmland => 1.0,
+                    _ => 0.0,
+                };
+
+                if x != 0 || z != 0 {
+                    soil_speed /= 4.0;
+                }
+
+                speed += soil_speed;
+            }
+        }
+
+        let is_crop = |x, z| block_at(x, 0, z).id() == crop;
+        let has_row_x = is_crop(-1, 0) || is_crop(1, 0);
+        let has_row_z = is_crop(0, -1) || is_crop(0, 1);
+
+        if (has_row_x && has_row_z)
+            || is_crop(-1, -1)
+            || is_crop(1, -1)
+            || is_crop(1, 1)
+            || is_crop(-1, 1)
+        {
+            speed /= 2.0;
+        }
+
+        speed
+    }
+
+    /// Returns the light level above the given position, or 0 at the top of the world.
+    fn brightness_above(&self, pos: BlockPos) -> u8 {
+        pos.neighbor(Direction::Up)
+            .map_or(0, |above| self.brightness(above))
+    }
+
+    /// Increases the age of the plant at the given position by one.
+    fn age_plant(&mut self, pos: BlockPos, block: Block) {
+        if let Some((age, _)) = age(block).filter(|(age, max_age)| age < max_age) {
+            self.replace_block(pos, with_age(block, age + 1), Updates::Shapes, 0);
+        }
+    }
+
+    /// Lets the sugar cane or cactus at the given position grow, until it's three blocks tall.
+    fn grow_column(&mut self, pos: BlockPos, block: Block) {
+        let Some(above) = pos.neighbor(Direction::Up) else {
+            return;
+        };
+
+        if !self.block(above).is_some_and(is_air) {
+            return;
+        }
+
+        let mut height = 1;
+        let mut below = pos.neighbor(Direction::Down);
+
+        while let Some(below_pos) = below.filter(|&below_pos| {
+            self.block(below_pos)
+                .is_some_and(|below| below.id() == block.id())
+        }) {
+            height += 1;
+            below = below_pos.neighbor(Direction::Down);
+        }
+
+        if height >= 3 {
+            return;
+        }
+
+        match age(block) {
+            Some((age, max_age)) if age == max_age => {
+                self.replace_block(above, Block::from_id(block.id()), Updates::All, 0);
+                self.replace_block(pos, with_age(block, 0), Updates::Shapes, 0);
+            }
+            Some((age, _)) => self.replace_block(pos, with_age(block, age + 1), Updates::Shapes, 0),
+            None => {}
+        }
+    }
+
+    /// Lets the tip of the kelp or vines at the given position grow by one block, with the given
+    /// probability.
+    fn grow_plant_tip(&mut self, pos: BlockPos, block: Block, probability: f64) {
+        let (Some((age, max_age)), Some((_, direction))) = (age(block), growing_plant(block.id()))
+        else {
+            return;
+        };
+
+        if age >= max_age || self.random.next_f64() >= probability {
+            return;
+        }
+
+        let Some(grow_pos) = pos.neighbor(direction) else {
+            return;
+        };
+        let can_grow = self.block(grow_pos).is_some_and(|target| match block.id() {
+            BlockId::Kelp => target.id() == BlockId::Water,
+            _ => is_air(target),
+        });
+
+        if can_grow {
+            let mut grown = with_age(block, age + 1);
+
+            if block.id() == BlockId::CaveVines {
+                grown.set(state::Berries(self.random.next_f32() < 0.11));
+            }
+
+            self.replace_block(grow_pos, grown, Updates::All, 0);
+        }
+    }
+
+    /// Turns the grass or mycelium at the given position back into dirt if it's covered, or else
+    /// lets it spread to the dirt around it if it has enough light.
+    fn spread_grass(&mut self, pos: BlockPos, block: Block) {
+        if !self.can_be_grass(pos) {
+            self.replace_block(pos, Block::from_id(BlockId::Dirt), Updates::All, 0);
+            return;
+        }
+
+        if self.brightness_above(pos) < GROWTH_LIGHT {
+            return;
+        }
+
+        for _ in 0..4 {
+            let x = self.random.next_i32_below(3) - 1;
+            let y = self.random.next_i32_below(5) - 3;
+            let z = self.random.next_i32_below(3) - 1;
+            let Ok(target_pos) = BlockPos::new(pos.to_inner() + glam::ivec3(x, y, z)) else {
+                continue;
+            };
+
+            if self
+                .block(target_pos)
+                .is_some_and(|target| target.id() == BlockId::Dirt)
+                && self.can_grass_spread_to(target_pos)
+            {
+                let snowy = self.neighbor_block(target_pos, Direction::Up).id() == BlockId::Snow;
+                let mut spread = Block::from_id(block.id());
+                spread.set(state::Snowy(snowy));
+
+                self.replace_block(target_pos, spread, Updates::All, 0);
+            }
+        }
+    }
+
+    /// Returns `true` if grass can stay at the given position, which it can unless the block
+    /// above it stops light or is full of fluid.
+    fn can_be_grass(&self, pos: BlockPos) -> bool {
+        let Some(above) = pos
+            .neighbor(Direction::Up)
+            .and_then(|above| self.block(above))
+        else {
+            return true;
+        };
+
+        if above.id() == BlockId::Snow
+            && above
+                .get::<state::Layers>()
+                .is_some_and(|layers| layers.0.get() == 1)
+        {
+            return true;
+        }
+
+        if above.fluid_state().amount() == 8 {
+            return false;
+        }
+
+        let covers_face = above.behavior().can_occlude
+            && above.uses_shape_for_light_occlusion()
+            && above.occlusion_shape().is_face_full(state::Facing::Down);
+
+        !covers_face && above.light_block() < 15
+    }
+
+    fn can_grass_spread_to(&self, pos: BlockPos) -> bool {
+        self.can_be_grass(pos)
+            && self
+                .neighbor_block(pos, Direction::Up)
+                .fluid_state()
+                .fluid()
+                != crate::block::fluid::Fluid::Water
+    }
+
+    /// Schedules the leaves at the given position to update their distance to the nearest log
+    /// after their neighbor changed.
+    pub(crate) fn check_leaves_distance(&mut self, pos: BlockPos, block: Block, neighbor: Block) {
+        let Some(distance) = leaves_distance(block) else {
+            return;
+        };
+        let neighbor_distance = distance_to_log(neighbor) + 1;
+
+        if neighbor_distance != 1 || distance != neighbor_distance {
+            self.schedule_block_tick(pos, block.id(), 1, Priority::Normal);
+        }
+    }
+
+    /// Returns the leaves with their distance to the nearest log, through other leaves, which is
+    /// 7 for leaves that aren't connected to any log.
+    pub(crate) fn leaves_with_distance(&self, pos: BlockPos, mut leaves: Block) -> Block {
+        let mut distance = DECAY_DISTANCE;
+
+        for direction in Direction::iter() {
+            distance = distance.min(distance_to_log(self.neighbor_block(pos, direction)) + 1);
+
+            if distance == 1 {
+                break;
+            }
+        }
+
+        leaves.set(state::Distance(
+            Integer::new(distance).expect("the distance should be in range"),
+        ));
+        leaves
+    }
+
+    /// Updates the distance of the leaves at the given position to the nearest log.
+    pub(crate) fn tick_leaves(&mut self, pos: BlockPos, leaves: Block) {
+        let leaves = self.leaves_with_distance(pos, leaves);
+        self.replace_block(pos, leaves, Updates::All, 0);
+    }
+}
+
+/// Returns `true` if the block ticks randomly, like crops and leaves too far from any log.
+pub(crate) fn is_randomly_ticking(block: Block) -> bool {
+    use BlockId::*;
+
+    let id = block.id();
+
+    match id {
+        // Plants stop ticking once fully grown, but stems keep growing fruit.
+        Wheat | Carrots | Potatoes | Beetroots | NetherWart | SweetBerryBush | Cocoa => {
+            age(block).is_some_and(|(age, max_age)| age < max_age)
+        }
+        _ if growing_plant(id).is_some() => age(block).is_some_and(|(age, max_age)| age < max_age),
+        _ if id.is_in(vanilla("leaves")) => {
+            leaves_distance(block) == Some(DECAY_DISTANCE)
+                && !block
+                    .get::<state::Persistent>()
+                    .is_some_and(|persistent| persistent.0)
+        }
+        _ => block.behavior().is_randomly_ticking,
+    }
+}
+
+fn is_air(block: Block) -> bool {
+    block.behavior().is_air
+}
+
+fn leaves_distance(block: Block) -> Option<u32> {
+    block
+        .get::<state::Distance>()
+        .filter(|_| block.is_in(vanilla("leaves")))
+        .map(|distance| distance.0.get())
+}
+
+/// Returns the distance of the block to the nearest log, as seen by the leaves next to it.
+fn distance_to_log(block: Block) -> u32 {
+    if block.is_in(vanilla("logs")) {
+        0
+    } else {
+        leaves_distance(block).unwrap_or(DECAY_DISTANCE)
+    }
+}
+
+/// Returns the age of the block and the age at which it's fully grown, for blocks that grow.
+fn age(block: Block) -> Option<(u32, u32)> {
+    [
+        block.get::<state::Age1>().map(|age| (age.0.get(), 1)),
+        block.get::<state::Age2>().map(|age| (age.0.get(), 2)),
+        block.get::<state::Age3>().map(|age| (age.0.get(), 3)),
+        block.get::<state::Age5>().map(|age| (age.0.get(), 5)),
+        block.get::<state::Age7>().map(|age| (age.0.get(), 7)),
+        block.get::<state::Age15>().map(|age| (age.0.get(), 15)),
+        block.get::<state::Age25>().map(|age| (age.0.get(), 25)),
+    ]
+    .into_iter()
+    .flatten()
+    .next()
+}
+
+/// Returns the block with the given age, which must be in the range of its age property.
+fn with_age(mut block: Block, age: u32) -> Block {
+    let expect = "the age should be in range";
+
+    match self::age(block).map(|(_, max_age)| max_age) {
+        Some(1) => block.set(state::Age1(Integer::new(age).expect(expect))),
+        Some(2) => block.set(state::Age2(Integer::new(age).expect(expect))),
+        Some(3) => block.set(state::Age3(Integer::new(age).expect(expect))),
+        Some(5) => block.set(state::Age5(Integer::new(age).expect(expect))),
+        Some(7) => block.set(state::Age7(Integer::new(age).expect(expect))),
+        Some(15) => block.set(state::Age15(Integer::new(age).expect(expect))),
+        Some(25) => block.set(state::Age25(Integer::new(age).expect(expect))),
+        _ => false,
+    };
+
+    block
+}
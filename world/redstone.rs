@@ -0,0 +1,1100 @@
+//! The redstone engine, which simulates how redstone components power each other and react to
+//! changes around them, following vanilla.
+//!
+//! Signals go from a block to its neighbors. A block emits a signal in a direction, which is the
+//! direction from the neighbor receiving it to the block. Weak signals only power the neighbor,
+//! while direct signals also power the blocks around it if it's a conductor.
+
+use anyhow::{bail, Context, Result};
+use rustc_hash::FxHashMap;
+use strum::IntoEnumIterator;
+
+use crate::block::item::ItemStack;
+use crate::block::state::{self, Integer};
+use crate::block::tag::vanilla;
+use crate::tick::Priority;
+use crate::update::{self, chest_connection, is_connected, wire_side, Updates};
+use crate::{Block, BlockId, BlockPos, Direction, World};
+
+/// The state of redstone components that isn't part of their block state, which vanilla keeps in
+/// block entities.
+#[derive(Default)]
+pub(crate) struct Redstone {
+    /// The signal output by each comparator.
+    comparator_outputs: FxHashMap<BlockPos, u8>,
+    /// The items in each container, which comparators measure.
+    containers: FxHashMap<BlockPos, Vec<ItemStack>>,
+    /// When torches recently turned off, as they burn out when toggled too often.
+    torch_toggles: Vec<(BlockPos, u64)>,
+}
+
+impl World {
+    /// Returns the strongest redstone signal the block at the given position receives from its
+    /// neighbors, from 0 to 15.
+    #[must_use]
+    pub fn neighbor_signal(&self, pos: BlockPos) -> u8 {
+        self.best_neighbor_signal(pos, true)
+    }
+
+    /// Returns the signal output by the comparator at the given position, or `None` if there is
+    /// no comparator there.
+    #[must_use]
+    pub fn comparator_output(&self, pos: BlockPos) -> Option<u8> {
+        let block = self.block(pos)?;
+
+        (block.id() == BlockId::Comparator).then(|| {
+            self.redstone
+                .comparator_outputs
+                .get(&pos)
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+
+    /// Returns the items in the container at the given position, which are empty unless set with
+    /// [`World::set_container_items`].
+    #[must_use]
+    pub fn container_items(&self, pos: BlockPos) -> &[ItemStack] {
+        self.redstone
+            .containers
+            .get(&pos)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Sets the items in the container at the given position, like chests, barrels or hoppers,
+    /// and updates the comparators measuring it. Fails if the block isn't a loaded container or if
+    /// there are more stacks than the container has slots.
+    pub fn set_container_items(&mut self, pos: BlockPos, items: Vec<ItemStack>) -> Result<()> {
+        let block = self
+            .block(pos)
+            .with_context(|| format!("block at position {pos} is not loaded"))?;
+        let location = block.id().to_location();
+        let Some(size) = container_size(block.id()) else {
+            bail!("`{location}` is not a container");
+        };
+
+        if items.len() > size {
+            bail!(
+                "too many stacks for `{location}`: expected at most `{size}`, found `{}`",
+                items.len(),
+            );
+        }
+
+        self.redstone.containers.insert(pos, items);
+        self.update_neighbors_for_output_signal(pos, block.id());
+
+        Ok(())
+    }
+
+    /// Uses the redstone component at the given position like a player would, which flips levers,
+    /// presses buttons, cycles the delay of repeaters and switches the mode of comparators.
+    /// Returns `true` if the block is such a component. Fails if the chunk of the block isn't
+    /// loaded.
+    pub fn use_component(&mut self, pos: BlockPos) -> Result<bool> {
+        use BlockId::*;
+
+        let mut block = self
+            .block(pos)
+            .with_context(|| format!("block at position {pos} is not loaded"))?;
+
+        match block.id() {
+            Lever => {
+                block.set(state::Powered(!is_powered(block)));
+                self.replace_block(pos, block, Updates::All, 0);
+                self.update_attached(pos, block);
+            }
+            id if id.is_in(vanilla("buttons")) => {
+                if !is_powered(block) {
+                    block.set(state::Powered(true));
+                    self.replace_block(pos, block, Updates::All, 0);
+                    self.update_attached(pos, block);
+
+                    let delay = if id.is_in(vanilla("wooden_buttons")) {
+                        30
+                    } else {
+                        20
+                    };
+                    self.schedule_block_tick(pos, id, delay, Priority::Normal);
+                }
+            }
+            Repeater => {
+                let delay = block.get::<state::Delay>().map_or(1, |delay| delay.0.get());
+                let delay = Integer::new(delay % 4 + 1).expect("delay should be in range");
+
+                block.set(state::Delay(delay));
+                self.replace_block(pos, block, Updates::All, 0);
+            }
+            Comparator => {
+                block.set(match block.get::<state::ComparatorMode>() {
+                    Some(state::ComparatorMode::Compare) => state::ComparatorMode::Subtract,
+                    _ => state::ComparatorMode::Compare,
+                });
+                self.replace_block(pos, block, Updates::Shapes, 0);
+                self.refresh_comparator(pos, block);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// Returns the signal the block at the given position emits in the given direction, or gets
+    /// from its neighbors if it's a conductor. Redstone wire only counts if `wires` is set, which
+    /// wire computing its own power turns off so that it doesn't power itself back.
+    fn signal(&self, pos: BlockPos, direction: Direction, wires: bool) -> u8 {
+        let Some(block) = self.block(pos) else {
+            return 0;
+        };
+        let signal = self.emitted_signal(pos, block, direction, wires);
+
+        if block.is_redstone_conductor() {
+            signal.max(self.direct_signal_to(pos, wires))
+        } else {
+            signal
+        }
+    }
+
+    pub(crate) fn has_signal(&self, pos: BlockPos, direction: Direction) -> bool {
+        self.signal(pos, direction, true) > 0
+    }
+
+    /// Returns the strongest direct signal the block at the given position receives.
+    fn direct_signal_to(&self, pos: BlockPos, wires: bool) -> u8 {
+        let mut signal = 0;
+
+        for direction in Direction::iter() {
+            let Some(neighbor_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+            let Some(neighbor) = self.block(neighbor_pos) else {
+                continue;
+            };
+
+            signal =
+                signal.max(self.emitted_direct_signal(neighbor_pos, neighbor, direction, wires));
+
+            if signal >= 15 {
+                break;
+            }
+        }
+
+        signal
+    }
+
+    fn best_neighbor_signal(&self, pos: BlockPos, wires: bool) -> u8 {
+        let mut signal = 0;
+
+        for direction in Direction::iter() {
+            if let Some(neighbor_pos) = pos.neighbor(direction) {
+                signal = signal.max(self.signal(neighbor_pos, direction, wires));
+            }
+
+            if signal >= 15 {
+                break;
+            }
+        }
+
+        signal
+    }
+
+    pub(crate) fn has_neighbor_signal(&self, pos: BlockPos) -> bool {
+        Direction::iter().any(|direction| {
+            pos.neighbor(direction)
+                .is_some_and(|neighbor_pos| self.has_signal(neighbor_pos, direction))
+        })
+    }
+
+    /// Returns the weak signal the block emits in the given direction.
+    fn emitted_signal(&self, pos: BlockPos, block: Block, direction: Direction, wires: bool) -> u8 {
+        use BlockId::*;
+
+        match block.id() {
+            RedstoneWire if wires => self.wire_signal(pos, block, direction),
+            RedstoneTorch => lit_signal(block, direction != Direction::Up),
+            RedstoneWallTorch => lit_signal(block, horizontal_facing(block) != Some(direction)),
+            RedstoneBlock => 15,
+            Repeater | Observer if facing(block) == Some(direction) => power_level(block),
+            Comparator if is_powered(block) && facing(block) == Some(direction) => self
+                .redstone
+                .comparator_outputs
+                .get(&pos)
+                .copied()
+                .unwrap_or_default(),
+            Lever | Lectern | DetectorRail | TripwireHook | LightningRod | Target
+            | DaylightDetector | SculkSensor => power_level(block),
+            id if id.is_in(vanilla("buttons")) || id.is_in(vanilla("pressure_plates")) => {
+                power_level(block)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Returns the direct signal the block emits in the given direction, which also powers the
+    /// blocks around the conductor receiving it.
+    fn emitted_direct_signal(
+        &self,
+        pos: BlockPos,
+        block: Block,
+        direction: Direction,
+        wires: bool,
+    ) -> u8 {
+        use BlockId::*;
+
+        let emits = match block.id() {
+            RedstoneWire | Repeater | Comparator | Observer => true,
+            RedstoneTorch | RedstoneWallTorch => direction == Direction::Down,
+            Lectern | DetectorRail | SculkSensor => direction == Direction::Up,
+            TripwireHook | LightningRod => facing(block) == Some(direction),
+            Lever => attached_direction(block) == Some(direction),
+            id if id.is_in(vanilla("buttons")) => attached_direction(block) == Some(direction),
+            id if id.is_in(vanilla("pressure_plates")) => direction == Direction::Up,
+            _ => false,
+        };
+
+        if emits {
+            self.emitted_signal(pos, block, direction, wires)
+        } else {
+            0
+        }
+    }
+
+    fn wire_signal(&self, pos: BlockPos, wire: Block, direction: Direction) -> u8 {
+        let power = power_level(wire);
+
+        if direction == Direction::Down || power == 0 {
+            return 0;
+        }
+
+        if direction == Direction::Up
+            || is_connected(wire_side(
+                self.wire_connections(pos, wire),
+                direction.opposite(),
+            ))
+        {
+            power
+        } else {
+            0
+        }
+    }
+
+    /// Runs the hook of the block placed at the given position, replacing `old`.
+    pub(crate) fn on_place(&mut self, pos: BlockPos, block: Block, old: Block) {
+        use BlockId::*;
+
+        match block.id() {
+            RedstoneWire if old.id() != RedstoneWire => {
+                self.update_power_strength(pos, block);
+
+                for direction in [Direction::Up, Direction::Down] {
+                    self.update_neighbors_around(pos, direction, RedstoneWire);
+                }
+
+                self.update_neighbors_of_neighboring_wires(pos);
+            }
+            RedstoneTorch | RedstoneWallTorch => {
+                for direction in Direction::iter() {
+                    self.update_neighbors_around(pos, direction, block.id());
+                }
+            }
+            Repeater | Comparator => self.update_neighbors_in_front(pos, block),
+            Observer
+                if old.id() != Observer
+                    && is_powered(block)
+                    && !self.has_scheduled_block_tick(pos, Observer) =>
+            {
+                let mut unpowered = block;
+                unpowered.set(state::Powered(false));
+                self.replace_block(pos, unpowered, Updates::None, 0);
+                self.update_neighbors_in_front(pos, unpowered);
+            }
+            Piston | StickyPiston if old.id() != block.id() => self.check_if_extend(pos, block),
+            Fire => self.schedule_fire_tick(pos),
+            _ => {}
+        }
+    }
+
+    /// Runs the hook of the block removed from the given position, replaced by `block`.
+    pub(crate) fn on_remove(&mut self, pos: BlockPos, old: Block, block: Block) {
+        use BlockId::*;
+
+        let replaced = old.id() != block.id();
+
+        match old.id() {
+            RedstoneWire if replaced => {
+                for direction in Direction::iter() {
+                    self.update_neighbors_around(pos, direction, RedstoneWire);
+                }
+
+                self.update_power_strength(pos, old);
+                self.update_neighbors_of_neighboring_wires(pos);
+            }
+            RedstoneTorch | RedstoneWallTorch => {
+                for direction in Direction::iter() {
+                    self.update_neighbors_around(pos, direction, old.id());
+                }
+            }
+            Repeater | Comparator if replaced => {
+                self.redstone.comparator_outputs.remove(&pos);
+                self.update_neighbors_in_front(pos, old);
+            }
+            Observer
+                if replaced && is_powered(old) && self.has_scheduled_block_tick(pos, Observer) =>
+            {
+                let mut unpowered = old;
+                unpowered.set(state::Powered(false));
+                self.update_neighbors_in_front(pos, unpowered);
+            }
+            Lever if replaced && is_powered(old) => self.update_attached(pos, old),
+            id if replaced && is_powered(old) && id.is_in(vanilla("buttons")) => {
+                self.update_attached(pos, old);
+            }
+            id if replaced && container_size(id).is_some() => {
+                self.redstone.containers.remove(&pos);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the state of a block about to be placed at the given position, which depends on
+    /// the signals around it for some components, and on the logs around it for leaves.
+    pub(crate) fn placement_state(&self, pos: BlockPos, mut block: Block) -> Block {
+        match block.id() {
+            BlockId::RedstoneLamp => {
+                block.set(state::Lit(self.has_neighbor_signal(pos)));
+            }
+            BlockId::Repeater => {
+                block.set(state::Locked(self.is_locked(pos, block)));
+            }
+            id if id.is_in(vanilla("leaves")) => block = self.leaves_with_distance(pos, block),
+            _ => {}
+        }
+
+        block
+    }
+
+    /// Runs the hook of a block that was just placed at the given position.
+    pub(crate) fn set_placed(&mut self, pos: BlockPos, block: Block) {
+        if is_diode(block) && self.should_turn_on(pos, block) {
+            self.schedule_block_tick(pos, block.id(), 1, Priority::Normal);
+        }
+    }
+
+    /// Lets the block at the given position react to a change of its neighbor `source`.
+    pub(crate) fn neighbor_changed(&mut self, pos: BlockPos, source: BlockId) {
+        use BlockId::*;
+
+        let Some(block) = self.block(pos) else {
+            return;
+        };
+
+        match block.id() {
+            RedstoneWire => {
+                let below = self.neighbor_block(pos, Direction::Down);
+
+                if update::is_face_sturdy(below, Direction::Up) || below.id() == Hopper {
+                    self.update_power_strength(pos, block);
+                } else {
+                    self.replace_block(pos, Block::from_id(Air), Updates::All, 0);
+                }
+            }
+            RedstoneTorch | RedstoneWallTorch
+                if is_lit(block) == self.is_torch_powered(pos, block)
+                    && !self.will_tick_this_tick(pos, block.id()) =>
+            {
+                self.schedule_block_tick(pos, block.id(), 2, Priority::Normal);
+            }
+            Repeater | Comparator => {
+                let below = self.neighbor_block(pos, Direction::Down);
+
+                if update::is_face_sturdy(below, Direction::Up) {
+                    self.check_diode_tick(pos, block);
+                } else {
+                    self.replace_block(pos, Block::from_id(Air), Updates::All, 0);
+
+                    for direction in Direction::iter() {
+                        self.update_neighbors_around(pos, direction, block.id());
+                    }
+                }
+            }
+            RedstoneLamp => {
+                let lit = is_lit(block);
+
+                if lit != self.has_neighbor_signal(pos) {
+                    if lit {
+                        self.schedule_block_tick(pos, RedstoneLamp, 4, Priority::Normal);
+                    } else {
+                        let mut lamp = block;
+                        lamp.set(state::Lit(true));
+                        self.replace_block(pos, lamp, Updates::Shapes, 0);
+                    }
+                }
+            }
+            Piston | StickyPiston => self.check_if_extend(pos, block),
+            id if id.is_in(vanilla("doors")) => {
+                let other_half = match block.get::<state::DoubleBlockHalf>() {
+                    Some(state::DoubleBlockHalf::Lower) => Direction::Up,
+                    _ => Direction::Down,
+                };
+                let powered = self.has_neighbor_signal(pos)
+                    || pos
+                        .neighbor(other_half)
+                        .is_some_and(|pos| self.has_neighbor_signal(pos));
+
+                if source != id && powered != is_powered(block) {
+                    self.set_powered_and_open(pos, block, powered);
+                }
+            }
+            id if id.is_in(vanilla("trapdoors")) || id.is_in(vanilla("fence_gates")) => {
+                let powered = self.has_neighbor_signal(pos);
+
+                if powered != is_powered(block) {
+                    self.set_powered_and_open(pos, block, powered);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn set_powered_and_open(&mut self, pos: BlockPos, mut block: Block, powered: bool) {
+        block.set(state::Powered(powered));
+        block.set(state::Open(powered));
+        self.replace_block(pos, block, Updates::Shapes, 0);
+    }
+
+    /// Runs the scheduled tick of the block at the given position.
+    pub(crate) fn tick_block(&mut self, pos: BlockPos, block: Block) {
+        use BlockId::*;
+
+        match block.id() {
+            RedstoneTorch | RedstoneWallTorch => self.tick_torch(pos, block),
+            Repeater => self.tick_repeater(pos, block),
+            Comparator => self.refresh_comparator(pos, block),
+            RedstoneLamp if is_lit(block) && !self.has_neighbor_signal(pos) => {
+                let mut lamp = block;
+                lamp.set(state::Lit(false));
+                self.replace_block(pos, lamp, Updates::Shapes, 0);
+            }
+            Observer => {
+                let powered = is_powered(block);
+                let mut observer = block;
+                observer.set(state::Powered(!powered));
+                self.replace_block(pos, observer, Updates::Shapes, 0);
+
+                if !powered {
+                    self.schedule_block_tick(pos, Observer, 2, Priority::Normal);
+                }
+
+                self.update_neighbors_in_front(pos, block);
+            }
+            id if id.is_in(vanilla("buttons")) && is_powered(block) => {
+                let mut button = block;
+                button.set(state::Powered(false));
+                self.replace_block(pos, button, Updates::All, 0);
+                self.update_attached(pos, button);
+            }
+            Fire => self.tick_fire(pos, block),
+            id if id.is_in(vanilla("leaves")) => self.tick_leaves(pos, block),
+            _ => {}
+        }
+    }
+
+    /// Schedules the observer at the given position to pulse if it faces the changed neighbor in
+    /// the given direction.
+    pub(crate) fn observe(&mut self, pos: BlockPos, block: Block, direction: Direction) {
+        if block.id() == BlockId::Observer
+            && facing(block) == Some(direction)
+            && !is_powered(block)
+            && !self.has_scheduled_block_tick(pos, BlockId::Observer)
+        {
+            self.schedule_block_tick(pos, BlockId::Observer, 2, Priority::Normal);
+        }
+    }
+
+    /// Updates the comparators measuring the block at the given position, directly or through a
+    /// conductor.
+    pub(crate) fn update_neighbors_for_output_signal(&mut self, pos: BlockPos, source: BlockId) {
+        for direction in Direction::HORIZONTAL {
+            let Some(neighbor_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+            let Some(neighbor) = self.block(neighbor_pos) else {
+                continue;
+            };
+
+            if neighbor.id() == BlockId::Comparator {
+                self.neighbor_changed(neighbor_pos, source);
+            } else if neighbor.is_redstone_conductor() {
+                let Some(beyond_pos) = neighbor_pos.neighbor(direction) else {
+                    continue;
+                };
+
+                if self
+                    .block(beyond_pos)
+                    .is_some_and(|beyond| beyond.id() == BlockId::Comparator)
+                {
+                    self.neighbor_changed(beyond_pos, source);
+                }
+            }
+        }
+    }
+
+    /// Returns the signal comparators read from the block at the given position, or `None` if
+    /// they don't measure it.
+    pub(crate) fn analog_output(&self, pos: BlockPos, block: Block) -> Option<u8> {
+        use BlockId::*;
+
+        let level = |value: u32| Some(u8::try_from(value).expect("level should fit in a byte"));
+
+        match block.id() {
+            Cake => level(2 * (7 - block.get::<state::Bites>()?.0.get())),
+            id if id.is_in(vanilla("candle_cakes")) => Some(14),
+            Composter => level(block.get::<state::LevelComposter>()?.0.get()),
+            Cauldron => Some(0),
+            WaterCauldron | PowderSnowCauldron => {
+                level(block.get::<state::LevelCauldron>()?.0.get())
+            }
+            LavaCauldron => Some(3),
+            EndPortalFrame => Some(if block.get::<state::Eye>()?.0 { 15 } else { 0 }),
+            RespawnAnchor => level(block.get::<state::RespawnAnchorCharges>()?.0.get() * 15 / 4),
+            Beehive | BeeNest => level(block.get::<state::LevelHoney>()?.0.get()),
+            Chest | TrappedChest => Some(self.chest_signal(pos, block)),
+            id => {
+                let size = container_size(id)?;
+
+                Some(container_signal(self.container_items(pos), size))
+            }
+        }
+    }
+
+    /// Returns the signal measured from a chest, which covers both halves of double chests.
+    /// Chests that can't be opened because of a conductor above them read as empty.
+    fn chest_signal(&self, pos: BlockPos, chest: Block) -> u8 {
+        let is_blocked = |pos| {
+            self.neighbor_block(pos, Direction::Up)
+                .is_redstone_conductor()
+        };
+        let other_half = chest_connection(chest)
+            .filter(|_| chest.get::<state::ChestType>() != Some(state::ChestType::Single))
+            .and_then(|direction| pos.neighbor(direction))
+            .filter(|&other_pos| {
+                self.block(other_pos)
+                    .is_some_and(|other| other.id() == chest.id())
+            });
+
+        if is_blocked(pos) || other_half.is_some_and(is_blocked) {
+            return 0;
+        }
+
+        match other_half {
+            Some(other_pos) => {
+                let items = self.container_items(pos);
+                let other_items = self.container_items(other_pos);
+
+                container_signal(items.iter().chain(other_items), 2 * 27)
+            }
+            None => container_signal(self.container_items(pos), 27),
+        }
+    }
+
+    /// Tells the neighbors of the block next to the given position about a change.
+    fn update_neighbors_around(&mut self, pos: BlockPos, direction: Direction, source: BlockId) {
+        if let Some(neighbor_pos) = pos.neighbor(direction) {
+            self.update_neighbors(neighbor_pos, source);
+        }
+    }
+
+    /// Tells the neighbors of the block a lever or a button is attached to about a change.
+    fn update_attached(&mut self, pos: BlockPos, block: Block) {
+        self.update_neighbors(pos, block.id());
+
+        if let Some(direction) = attached_direction(block) {
+            self.update_neighbors_around(pos, direction.opposite(), block.id());
+        }
+    }
+
+    /// Tells the block in front of the repeater, comparator or observer and the neighbors of that
+    /// block about a change of its output.
+    fn update_neighbors_in_front(&mut self, pos: BlockPos, block: Block) {
+        let Some(facing) = facing(block) else {
+            return;
+        };
+        let Some(front_pos) = pos.neighbor(facing.opposite()) else {
+            return;
+        };
+
+        self.neighbor_changed(front_pos, block.id());
+        self.update_neighbors_except(front_pos, block.id(), facing);
+    }
+
+    fn update_power_strength(&mut self, pos: BlockPos, wire: Block) {
+        let strength = self.wire_target_strength(pos);
+
+        if power_level(wire) == strength {
+            return;
+        }
+
+        if self.block(pos) == Some(wire) {
+            let mut powered = wire;
+            powered.set(state::Power(
+                Integer::new(u32::from(strength)).expect("power should be in range"),
+            ));
+            self.replace_block(pos, powered, Updates::Shapes, 0);
+        }
+
+        let mut positions = vec![pos];
+        positions.extend(Direction::iter().filter_map(|direction| pos.neighbor(direction)));
+        sort_like_hash_set(&mut positions);
+
+        for pos in positions {
+            self.update_neighbors(pos, BlockId::RedstoneWire);
+        }
+    }
+
+    /// Returns the power the wire at the given position should have, which is the strongest
+    /// signal it gets from other components or the power of the wire next to it minus one.
+    fn wire_target_strength(&self, pos: BlockPos) -> u8 {
+        let signal = self.best_neighbor_signal(pos, false);
+
+        if signal >= 15 {
+            return signal;
+        }
+
+        let above_conducts = self
+            .neighbor_block(pos, Direction::Up)
+            .is_redstone_conductor();
+        let mut wire = 0;
+
+        for direction in Direction::HORIZONTAL {
+            let Some(side_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+            let side = self.block(side_pos).unwrap_or_default();
+            wire = wire.max(wire_power(side));
+
+            let diagonal = if side.is_redstone_conductor() {
+                if above_conducts {
+                    continue;
+                }
+
+                Direction::Up
+            } else {
+                Direction::Down
+            };
+
+            wire = wire.max(wire_power(self.neighbor_block(side_pos, diagonal)));
+        }
+
+        signal.max(wire.saturating_sub(1))
+    }
+
+    fn update_neighbors_of_neighboring_wires(&mut self, pos: BlockPos) {
+        for direction in Direction::HORIZONTAL {
+            if let Some(side_pos) = pos.neighbor(direction) {
+                self.check_corner_change_at(Some(side_pos));
+            }
+        }
+
+        for direction in Direction::HORIZONTAL {
+            let Some(side_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+            let diagonal = if self.neighbor_block(pos, direction).is_redstone_conductor() {
+                Direction::Up
+            } else {
+                Direction::Down
+            };
+
+            self.check_corner_change_at(side_pos.neighbor(diagonal));
+        }
+    }
+
+    fn check_corner_change_at(&mut self, pos: Option<BlockPos>) {
+        let Some(pos) = pos.filter(|&pos| self.is_wire_at(pos)) else {
+            return;
+        };
+
+        self.update_neighbors(pos, BlockId::RedstoneWire);
+
+        for direction in Direction::iter() {
+            self.update_neighbors_around(pos, direction, BlockId::RedstoneWire);
+        }
+    }
+
+    fn is_wire_at(&self, pos: BlockPos) -> bool {
+        self.block(pos)
+            .is_some_and(|block| block.id() == BlockId::RedstoneWire)
+    }
+
+    /// Returns `true` if the block the torch is attached to is powered.
+    fn is_torch_powered(&self, pos: BlockPos, torch: Block) -> bool {
+        let direction = match torch.id() {
+            BlockId::RedstoneWallTorch => match horizontal_facing(torch) {
+                Some(facing) => facing.opposite(),
+                None => return false,
+            },
+            _ => Direction::Down,
+        };
+
+        pos.neighbor(direction)
+            .is_some_and(|attached_pos| self.has_signal(attached_pos, direction))
+    }
+
+    fn tick_torch(&mut self, pos: BlockPos, torch: Block) {
+        let powered = self.is_torch_powered(pos, torch);
+        let time = self.time;
+        self.redstone
+            .torch_toggles
+            .retain(|&(_, toggled)| time - toggled <= 60);
+
+        if is_lit(torch) {
+            if powered {
+                let mut unlit = torch;
+                unlit.set(state::Lit(false));
+                self.replace_block(pos, unlit, Updates::All, 0);
+
+                if self.is_torch_burnt_out(pos, true) {
+                    let id = self.block(pos).unwrap_or_default().id();
+                    self.schedule_block_tick(pos, id, 160, Priority::Normal);
+                }
+            }
+        } else if !powered && !self.is_torch_burnt_out(pos, false) {
+            let mut lit = torch;
+            lit.set(state::Lit(true));
+            self.replace_block(pos, lit, Updates::All, 0);
+        }
+    }
+
+    /// Returns `true` if the torch at the given position turned off 8 times within the last 60
+    /// ticks, after recording that it just turned off if `toggled` is set.
+    fn is_torch_burnt_out(&mut self, pos: BlockPos, toggled: bool) -> bool {
+        if toggled {
+            self.redstone.torch_toggles.push((pos, self.time));
+        }
+
+        self.redstone
+            .torch_toggles
+            .iter()
+            .filter(|&&(toggled_pos, _)| toggled_pos == pos)
+            .count()
+            >= 8
+    }
+
+    /// Returns the signal going into the back of the repeater or comparator.
+    fn diode_input(&self, pos: BlockPos, diode: Block) -> u8 {
+        let Some(facing) = facing(diode) else {
+            return 0;
+        };
+        let Some(input_pos) = pos.neighbor(facing) else {
+            return 0;
+        };
+        let input = self.block(input_pos).unwrap_or_default();
+        let mut signal = self.signal(input_pos, facing, true);
+
+        if signal < 15 {
+            signal = signal.max(wire_power(input));
+        }
+
+        if diode.id() == BlockId::Comparator {
+            if let Some(output) = self.analog_output(input_pos, input) {
+                signal = output;
+            } else if signal < 15 && input.is_redstone_conductor() {
+                let measured = input_pos.neighbor(facing).and_then(|measured_pos| {
+                    self.analog_output(measured_pos, self.block(measured_pos)?)
+                });
+
+                if let Some(output) = measured {
+                    signal = output;
+                }
+            }
+        }
+
+        signal
+    }
+
+    /// Returns the strongest signal going into the sides of the repeater or comparator.
+    /// Repeaters are only locked by other repeaters and comparators.
+    fn diode_side_input(&self, pos: BlockPos, diode: Block) -> u8 {
+        let Some(facing) = facing(diode) else {
+            return 0;
+        };
+        let diodes_only = diode.id() == BlockId::Repeater;
+
+        [facing.clockwise(), facing.counter_clockwise()]
+            .into_iter()
+            .filter_map(|direction| {
+                let side_pos = pos.neighbor(direction)?;
+                let side = self.block(side_pos)?;
+
+                Some(match side.id() {
+                    _ if diodes_only && !is_diode(side) => 0,
+                    _ if diodes_only => self.emitted_direct_signal(side_pos, side, direction, true),
+                    BlockId::RedstoneBlock => 15,
+                    BlockId::RedstoneWire => power_level(side),
+                    _ if side.is_signal_source() => {
+                        self.emitted_direct_signal(side_pos, side, direction, true)
+                    }
+                    _ => 0,
+                })
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the repeater at the given position is locked by a powered repeater or
+    /// comparator on its side.
+    pub(crate) fn is_locked(&self, pos: BlockPos, repeater: Block) -> bool {
+        self.diode_side_input(pos, repeater) > 0
+    }
+
+    fn should_turn_on(&self, pos: BlockPos, diode: Block) -> bool {
+        let input = self.diode_input(pos, diode);
+
+        if diode.id() != BlockId::Comparator {
+            return input > 0;
+        }
+
+        let side_input = self.diode_side_input(pos, diode);
+
+        input > 0
+            && (input > side_input
+                || (input == side_input
+                    && diode.get::<state::ComparatorMode>()
+                        == Some(state::ComparatorMode::Compare)))
+    }
+
+    /// Returns the signal the comparator at the given position should output.
+    fn comparator_signal(&self, pos: BlockPos, comparator: Block) -> u8 {
+        let input = self.diode_input(pos, comparator);
+        let side_input = self.diode_side_input(pos, comparator);
+
+        if side_input > input {
+            0
+        } else if comparator.get::<state::ComparatorMode>() == Some(state::ComparatorMode::Subtract)
+        {
+            input - side_input
+        } else {
+            input
+        }
+    }
+
+    /// Returns `true` if the repeater or comparator at the given position outputs into the side of
+    /// another one, which then updates first.
+    fn should_prioritize(&self, pos: BlockPos, diode: Block) -> bool {
+        let Some(output) = facing(diode).map(Direction::opposite) else {
+            return false;
+        };
+        let front = self.neighbor_block(pos, output);
+
+        is_diode(front) && facing(front) != Some(output)
+    }
+
+    fn delay(diode: Block) -> u64 {
+        match diode.get::<state::Delay>() {
+            Some(delay) => 2 * u64::from(delay.0.get()),
+            None => 2,
+        }
+    }
+
+    fn check_diode_tick(&mut self, pos: BlockPos, diode: Block) {
+        if self.will_tick_this_tick(pos, diode.id()) {
+            return;
+        }
+
+        if diode.id() == BlockId::Comparator {
+            let output = self
+                .redstone
+                .comparator_outputs
+                .get(&pos)
+                .copied()
+                .unwrap_or_default();
+
+            if self.comparator_signal(pos, diode) != output
+                || is_powered(diode) != self.should_turn_on(pos, diode)
+            {
+                let priority = if self.should_prioritize(pos, diode) {
+                    Priority::High
+                } else {
+                    Priority::Normal
+                };
+                self.schedule_block_tick(pos, diode.id(), 2, priority);
+            }
+
+            return;
+        }
+
+        let powered = is_powered(diode);
+
+        if self.is_locked(pos, diode) || powered == self.should_turn_on(pos, diode) {
+            return;
+        }
+
+        let priority = if self.should_prioritize(pos, diode) {
+            Priority::ExtremelyHigh
+        } else if powered {
+            Priority::VeryHigh
+        } else {
+            Priority::High
+        };
+        self.schedule_block_tick(pos, diode.id(), Self::delay(diode), priority);
+    }
+
+    fn tick_repeater(&mut self, pos: BlockPos, repeater: Block) {
+        if self.is_locked(pos, repeater) {
+            return;
+        }
+
+        let powered = is_powered(repeater);
+        let should_turn_on = self.should_turn_on(pos, repeater);
+        let mut updated = repeater;
+
+        if powered && !should_turn_on {
+            updated.set(state::Powered(false));
+            self.replace_block(pos, updated, Updates::Shapes, 0);
+        } else if !powered {
+            updated.set(state::Powered(true));
+            self.replace_block(pos, updated, Updates::Shapes, 0);
+
+            // Pulses shorter than the delay are extended to it.
+            if !should_turn_on {
+                let delay = Self::delay(repeater);
+                self.schedule_block_tick(pos, BlockId::Repeater, delay, Priority::VeryHigh);
+            }
+        }
+    }
+
+    fn refresh_comparator(&mut self, pos: BlockPos, comparator: Block) {
+        let output = self.comparator_signal(pos, comparator);
+        let old_output = self
+            .redstone
+            .comparator_outputs
+            .insert(pos, output)
+            .unwrap_or_default();
+
+        if old_output == output
+            && comparator.get::<state::ComparatorMode>() != Some(state::ComparatorMode::Compare)
+        {
+            return;
+        }
+
+        let should_turn_on = self.should_turn_on(pos, comparator);
+
+        if is_powered(comparator) != should_turn_on {
+            let mut updated = comparator;
+            updated.set(state::Powered(should_turn_on));
+            self.replace_block(pos, updated, Updates::Shapes, 0);
+        }
+
+        self.update_neighbors_in_front(pos, comparator);
+    }
+}
+
+/// Returns the number of slots of the container, or `None` if the block isn't a container
+/// measured by comparators.
+fn container_size(id: BlockId) -> Option<usize> {
+    use BlockId::*;
+
+    match id {
+        Chest | TrappedChest | Barrel => Some(27),
+        Dispenser | Dropper => Some(9),
+        Hopper | BrewingStand => Some(5),
+        Furnace | BlastFurnace | Smoker => Some(3),
+        id if id.is_in(vanilla("shulker_boxes")) => Some(27),
+        _ => None,
+    }
+}
+
+/// Returns the signal measured from a container with the given number of slots, which grows
+/// with how full it is and is at least 1 if it holds anything.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn container_signal<'a>(items: impl IntoIterator<Item = &'a ItemStack>, size: usize) -> u8 {
+    let mut fullness = 0.0;
+    let mut has_items = false;
+
+    for stack in items {
+        if !stack.is_empty() {
+            fullness += f32::from(stack.count) / f32::from(stack.id.max_stack_size().min(64));
+            has_items = true;
+        }
+    }
+
+    fullness /= size as f32;
+
+    (fullness * 14.0).floor() as u8 + u8::from(has_items)
+}
+
+/// Sorts the positions in the order in which vanilla iterates a small `HashSet` holding them,
+/// which decides the order in which redstone wire updates the blocks around it.
+pub(crate) fn sort_like_hash_set(positions: &mut [BlockPos]) {
+    positions.sort_by_key(|pos| {
+        let pos = pos.to_inner();
+        let hash = pos
+            .y
+            .wrapping_add(pos.z.wrapping_mul(31))
+            .wrapping_mul(31)
+            .wrapping_add(pos.x)
+            .cast_unsigned();
+
+        (hash ^ (hash >> 16)) & 15
+    });
+}
+
+fn is_diode(block: Block) -> bool {
+    matches!(block.id(), BlockId::Repeater | BlockId::Comparator)
+}
+
+fn is_powered(block: Block) -> bool {
+    block
+        .get::<state::Powered>()
+        .is_some_and(|powered| powered.0)
+}
+
+fn is_lit(block: Block) -> bool {
+    block.get::<state::Lit>().is_some_and(|lit| lit.0)
+}
+
+fn lit_signal(block: Block, emits: bool) -> u8 {
+    if is_lit(block) && emits {
+        15
+    } else {
+        0
+    }
+}
+
+/// Returns the signal of a component with either a power level or a powered state.
+fn power_level(block: Block) -> u8 {
+    match block.get::<state::Power>() {
+        Some(power) => u8::try_from(power.0.get()).expect("power should be in range"),
+        None if is_powered(block) => 15,
+        None => 0,
+    }
+}
+
+fn wire_power(block: Block) -> u8 {
+    if block.id() == BlockId::RedstoneWire {
+        power_level(block)
+    } else {
+        0
+    }
+}
+
+/// Returns the direction the component faces, which is the direction of its input for
+/// repeaters and comparators and of what it watches for observers.
+fn facing(block: Block) -> Option<Direction> {
+    block
+        .get::<state::Facing>()
+        .map(Direction::from)
+        .or_else(|| horizontal_facing(block))
+}
+
+fn horizontal_facing(block: Block) -> Option<Direction> {
+    block.get::<state::HorizontalFacing>().map(Direction::from)
+}
+
+/// Returns the direction from the block a lever or a button is attached to towards it.
+fn attached_direction(block: Block) -> Option<Direction> {
+    match block.get::<state::AttachFace>()? {
+        state::AttachFace::Floor => Some(Direction::Up),
+        state::AttachFace::Ceiling => Some(Direction::Down),
+        state::AttachFace::Wall => horizontal_facing(block),
+    }
+}
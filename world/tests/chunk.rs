@@ -0,0 +1,256 @@
+//! Checks reading chunks from and writing them to their NBT representation.
+
+use quartz_nbt::{compound, NbtCompound, NbtList, NbtTag};
+use world::block::version::Version;
+use world::{Block, BlockId, BlockPos, Chunk, ChunkPos, World};
+
+/// Returns the NBT representation of a section with the given palette and, if there is more than
+/// one block in it, the packed indices into the palette.
+fn section(y: i8, palette: &[NbtCompound], data: Option<Vec<i64>>) -> NbtTag {
+    let mut block_states = compound! { "palette": NbtList::from(palette.to_vec()) };
+
+    if let Some(data) = data {
+        block_states.insert("data", data);
+    }
+
+    NbtTag::Compound(compound! { "Y": y, "block_states": block_states })
+}
+
+/// Returns the NBT representation of a chunk at (2, -3) saved by the given version, whose bottom
+/// section has the given palette with the block at index 1 at (0, -64, 0) and the block at index
+/// 2 at (1, -64, 0), and that is otherwise empty.
+fn chunk_tag(version: Version, palette: &[NbtCompound]) -> NbtCompound {
+    // 3 blocks take 4 bits each, so 16 fit into each long.
+    let mut data = vec![0; 4096 / 16];
+    data[0] = 1 | 2 << 4;
+
+    let sections = (-4..20)
+        .map(|y| {
+            if y == -4 {
+                section(y, palette, Some(data.clone()))
+            } else {
+                section(y, &[compound! { "Name": "minecraft:air" }], None)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    compound! {
+        "xPos": 2,
+        "zPos": -3,
+        "DataVersion": version.data_version(),
+        "sections": NbtList::from(sections),
+    }
+}
+
+fn pos(x: i32, y: i32, z: i32) -> BlockPos {
+    BlockPos::new(glam::ivec3(x, y, z)).unwrap()
+}
+
+fn block_at(chunk: &Chunk, pos: BlockPos) -> Block {
+    chunk.blocks().find(|&(p, _)| p == pos).unwrap().1
+}
+
+/// Returns the blocks of the chunk followed by its unknown blocks, in a comparable form.
+fn contents(chunk: &Chunk) -> Vec<String> {
+    let mut unknown_blocks = chunk
+        .unknown_blocks()
+        .map(|(pos, unknown_block)| format!("{pos}: {unknown_block}"))
+        .collect::<Vec<_>>();
+    unknown_blocks.sort();
+
+    chunk
+        .blocks()
+        .map(|(pos, block)| format!("{pos}: {block}"))
+        .chain(unknown_blocks)
+        .collect()
+}
+
+#[test]
+fn modded_blocks_round_trip() {
+    let ruby_ore = compound! {
+        "Name": "examplemod:ruby_ore",
+        "Properties": { "lit": "true", "depth": "deep" },
+    };
+    let palette = vec![
+        compound! { "Name": "minecraft:air" },
+        compound! { "Name": "minecraft:oak_log", "Properties": { "axis": "x" } },
+        ruby_ore.clone(),
+    ];
+    let chunk = Chunk::from_nbt(&chunk_tag(Version::V1_18_2, &palette)).unwrap();
+
+    let (log_pos, ruby_pos) = (pos(32, -64, -48), pos(33, -64, -48));
+    let oak_log: Block = "minecraft:oak_log[axis=x]".parse().unwrap();
+    assert_eq!(block_at(&chunk, log_pos), oak_log);
+    assert_eq!(
+        block_at(&chunk, ruby_pos).id(),
+        Chunk::UNKNOWN_BLOCK_PLACEHOLDER
+    );
+    assert_eq!(block_at(&chunk, pos(34, -64, -48)).id(), BlockId::Air);
+    assert_eq!(
+        chunk.unknown_block(ruby_pos).unwrap().to_string(),
+        "examplemod:ruby_ore[depth=deep,lit=true]",
+    );
+
+    let saved = chunk.to_nbt(Version::V1_18_2);
+    let sections = saved.get::<_, &NbtList>("sections").unwrap();
+    let bottom = <&NbtCompound>::try_from(&sections[0]).unwrap();
+    let palette = bottom
+        .get::<_, &NbtCompound>("block_states")
+        .unwrap()
+        .get::<_, &NbtList>("palette")
+        .unwrap();
+    assert!(palette
+        .iter()
+        .any(|tag| *tag == NbtTag::Compound(ruby_ore.clone())));
+
+    let reloaded = Chunk::from_nbt(&saved).unwrap();
+    assert_eq!(contents(&reloaded), contents(&chunk));
+    assert_eq!(reloaded.to_nbt(Version::V1_18_2), saved);
+}
+
+#[test]
+fn unknown_blocks_are_forgotten_once_replaced() {
+    let palette = vec![
+        compound! { "Name": "minecraft:air" },
+        compound! { "Name": "examplemod:ruby_ore" },
+        compound! { "Name": "examplemod:ruby_ore" },
+    ];
+    let chunk = Chunk::from_nbt(&chunk_tag(Version::V1_18_2, &palette)).unwrap();
+    let mut world = World::default();
+    world.insert_chunk(chunk);
+
+    let ruby_pos = pos(32, -64, -48);
+    assert_eq!(
+        world.unknown_block_summary().get("examplemod:ruby_ore"),
+        Some(&2)
+    );
+
+    world
+        .set_block(ruby_pos, Block::from_id(BlockId::Stone))
+        .unwrap();
+    assert!(world.unknown_block(ruby_pos).is_none());
+    assert_eq!(
+        world.unknown_block_summary().get("examplemod:ruby_ore"),
+        Some(&1)
+    );
+
+    let chunk = world
+        .loaded_chunk(ChunkPos::new(glam::ivec2(2, -3)))
+        .unwrap();
+    let reloaded = Chunk::from_nbt(&chunk.to_nbt(Version::V1_18_2)).unwrap();
+    assert_eq!(block_at(&reloaded, ruby_pos).id(), BlockId::Stone);
+    assert_eq!(reloaded.unknown_blocks().count(), 1);
+}
+
+#[test]
+fn blocks_added_since_round_trip_and_others_are_converted() {
+    let palette = vec![
+        compound! { "Name": "minecraft:air" },
+        compound! { "Name": "minecraft:short_grass" },
+        compound! { "Name": "minecraft:cherry_log", "Properties": { "axis": "y" } },
+    ];
+    let chunk = Chunk::from_nbt(&chunk_tag(Version::V1_20_4, &palette)).unwrap();
+
+    assert_eq!(block_at(&chunk, pos(32, -64, -48)).id(), BlockId::Grass);
+    assert_eq!(
+        chunk.unknown_block(pos(33, -64, -48)).unwrap().name(),
+        "minecraft:cherry_log"
+    );
+
+    let saved = chunk.to_nbt(Version::V1_18_2);
+    assert_eq!(
+        saved.get::<_, i32>("DataVersion").unwrap(),
+        Version::V1_18_2.data_version()
+    );
+
+    let sections = saved.get::<_, &NbtList>("sections").unwrap();
+    let bottom = <&NbtCompound>::try_from(&sections[0]).unwrap();
+    let names = bottom
+        .get::<_, &NbtCompound>("block_states")
+        .unwrap()
+        .get::<_, &NbtList>("palette")
+        .unwrap()
+        .iter()
+        .map(|tag| {
+            <&NbtCompound>::try_from(tag)
+                .unwrap()
+                .get::<_, &str>("Name")
+                .unwrap()
+                .to_owned()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(names.contains(&"minecraft:grass".to_owned()));
+    assert!(names.contains(&"minecraft:cherry_log".to_owned()));
+}
+
+#[test]
+fn blocks_with_properties_added_since_round_trip() {
+    let waterlogged = compound! {
+        "Name": "minecraft:oak_leaves",
+        "Properties": { "distance": "7", "persistent": "true", "waterlogged": "true" },
+    };
+    let palette = vec![
+        compound! { "Name": "minecraft:air" },
+        compound! {
+            "Name": "minecraft:oak_leaves",
+            "Properties": { "distance": "7", "persistent": "true", "waterlogged": "false" },
+        },
+        waterlogged.clone(),
+    ];
+    let chunk = Chunk::from_nbt(&chunk_tag(Version::V1_20_4, &palette)).unwrap();
+
+    // Leaves of 1.18.2 can't be waterlogged, so waterlogged leaves are kept as unknown blocks
+    // rather than dried.
+    let leaves: Block = "minecraft:oak_leaves[distance=7,persistent=true]"
+        .parse()
+        .unwrap();
+    assert_eq!(block_at(&chunk, pos(32, -64, -48)), leaves);
+    assert_eq!(
+        block_at(&chunk, pos(33, -64, -48)).id(),
+        Chunk::UNKNOWN_BLOCK_PLACEHOLDER
+    );
+    assert_eq!(
+        chunk.unknown_block(pos(33, -64, -48)).unwrap().to_string(),
+        "minecraft:oak_leaves[distance=7,persistent=true,waterlogged=true]",
+    );
+
+    let saved = chunk.to_nbt(Version::V1_20_4);
+    let sections = saved.get::<_, &NbtList>("sections").unwrap();
+    let bottom = <&NbtCompound>::try_from(&sections[0]).unwrap();
+    let palette = bottom
+        .get::<_, &NbtCompound>("block_states")
+        .unwrap()
+        .get::<_, &NbtList>("palette")
+        .unwrap();
+    assert!(palette
+        .iter()
+        .any(|tag| *tag == NbtTag::Compound(waterlogged.clone())));
+
+    let reloaded = Chunk::from_nbt(&saved).unwrap();
+    assert_eq!(contents(&reloaded), contents(&chunk));
+}
+
+#[test]
+fn sections_of_one_block_have_no_data() {
+    let chunk = Chunk::from_block(
+        ChunkPos::new(glam::ivec2(0, 0)),
+        Block::from_id(BlockId::Stone),
+    );
+    let saved = chunk.to_nbt(Version::V1_18_2);
+
+    for section in saved.get::<_, &NbtList>("sections").unwrap() {
+        let block_states = <&NbtCompound>::try_from(section)
+            .unwrap()
+            .get::<_, &NbtCompound>("block_states")
+            .unwrap();
+
+        assert!(!block_states.contains_key("data"));
+        assert_eq!(block_states.get::<_, &NbtList>("palette").unwrap().len(), 1);
+    }
+
+    let reloaded = Chunk::from_nbt(&saved).unwrap();
+    assert!(reloaded
+        .blocks()
+        .all(|(_, block)| block.id() == BlockId::Stone));
+}
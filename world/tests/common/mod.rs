@@ -0,0 +1,64 @@
+//! Fixture worlds shared by the integration tests.
+
+#![allow(dead_code)] // Not every test uses every helper.
+
+use world::{Block, BlockId, BlockPos, Chunk, ChunkPos, World};
+
+/// Returns a world with the 3×3 chunks around the origin loaded and a stone floor at Y 0 from
+/// -16 to 31 on both horizontal axes. Everything else is air.
+pub fn flat_world() -> World {
+    let mut world = World::default();
+
+    for x in -1..=1 {
+        for z in -1..=1 {
+            let pos = ChunkPos::new(glam::ivec2(x, z));
+
+            world.insert_chunk(Chunk::from_block(pos, Block::from_id(BlockId::Air)));
+        }
+    }
+
+    fill(
+        &mut world,
+        pos(-16, 0, -16),
+        pos(31, 0, 31),
+        "minecraft:stone",
+    );
+
+    world
+}
+
+/// Shorthand for creating a block position out of its coordinates.
+pub fn pos(x: i32, y: i32, z: i32) -> BlockPos {
+    BlockPos::new(glam::ivec3(x, y, z)).unwrap()
+}
+
+/// Shorthand for parsing a block, like `minecraft:oak_stairs[facing=east]`.
+pub fn block(s: &str) -> Block {
+    s.parse().unwrap()
+}
+
+/// Sets the blocks from `from` to `to` inclusive without updating them or their neighbors.
+pub fn fill(world: &mut World, from: BlockPos, to: BlockPos, s: &str) {
+    let (from, to) = (from.to_inner(), to.to_inner());
+
+    for x in from.x..=to.x {
+        for y in from.y..=to.y {
+            for z in from.z..=to.z {
+                world.set_block(pos(x, y, z), block(s)).unwrap();
+            }
+        }
+    }
+}
+
+/// Sets the block and updates it and its neighbors, like vanilla does when a player places it.
+pub fn place(world: &mut World, pos: BlockPos, s: &str) {
+    world.set_block_and_update(pos, block(s)).unwrap();
+}
+
+/// Asserts that the block at the given position is the given block, like
+/// `minecraft:oak_fence[east=true]`. Properties left out take their default values.
+pub fn assert_block(world: &World, pos: BlockPos, expected: &str) {
+    let actual = world.block(pos).unwrap();
+
+    assert_eq!(actual.to_string(), block(expected).to_string(), "at {pos}");
+}
@@ -0,0 +1,242 @@
+//! Lights fires in fixture worlds and checks that they spread, burn blocks away and go out, the
+//! same way every time for the same seed.
+
+mod common;
+
+use common::{assert_block, fill, flat_world, place, pos};
+use world::{BlockId, BlockPos, World};
+
+/// Returns a world with a 5×2×5 pile of oak planks from (0, 1, 0) to (4, 2, 4), with fire on top
+/// of its middle.
+fn wood_pile(seed: u64) -> World {
+    let mut world = flat_world();
+    world.set_seed(seed);
+
+    fill(
+        &mut world,
+        pos(0, 1, 0),
+        pos(4, 2, 4),
+        "minecraft:oak_planks",
+    );
+    place(&mut world, pos(2, 3, 2), "minecraft:fire");
+
+    world
+}
+
+fn tick(world: &mut World, ticks: u32) {
+    for _ in 0..ticks {
+        world.tick();
+    }
+}
+
+/// Returns the number of blocks of the given kind from (-4, 0, -4) to (8, 8, 8).
+fn count(world: &World, id: BlockId) -> usize {
+    let mut count = 0;
+
+    for x in -4..=8 {
+        for y in 0..=8 {
+            for z in -4..=8 {
+                if world.block(pos(x, y, z)).unwrap().id() == id {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+/// Asserts that both worlds have the same blocks from (-4, 0, -4) to (8, 8, 8).
+fn assert_same_blocks(a: &World, b: &World) {
+    for x in -4..=8 {
+        for y in 0..=8 {
+            for z in -4..=8 {
+                let pos = pos(x, y, z);
+                assert_eq!(a.block(pos), b.block(pos), "at {pos}");
+            }
+        }
+    }
+}
+
+/// Returns seeds spread out enough for their first random numbers to differ.
+fn seeds() -> impl Iterator<Item = u64> {
+    (0..8).map(|i| i * 0x9e37_79b9)
+}
+
+#[test]
+fn fire_spreads_and_burns_wood_away() {
+    for seed in seeds() {
+        let mut world = wood_pile(seed);
+        let mut most_fire = 0;
+
+        // The fire spreads over the pile while it burns, until it burns out.
+        for _ in 0..200 {
+            tick(&mut world, 100);
+            let fire = count(&world, BlockId::Fire);
+            most_fire = most_fire.max(fire);
+
+            if fire == 0 {
+                break;
+            }
+        }
+
+        assert!(most_fire > 1, "{seed}");
+        assert_eq!(count(&world, BlockId::Fire), 0, "{seed}");
+        assert!(count(&world, BlockId::OakPlanks) < 50, "{seed}");
+    }
+}
+
+#[test]
+fn fire_plays_out_the_same_for_the_same_seed() {
+    let mut a = wood_pile(7);
+    let mut b = wood_pile(7);
+    tick(&mut a, 600);
+    tick(&mut b, 600);
+
+    assert_same_blocks(&a, &b);
+}
+
+#[test]
+fn fire_stands_still_without_fire_ticks() {
+    let mut world = wood_pile(0);
+    world.set_do_fire_tick(false);
+    assert!(!world.do_fire_tick());
+
+    tick(&mut world, 2000);
+    assert_eq!(count(&world, BlockId::OakPlanks), 50);
+    assert_eq!(count(&world, BlockId::Fire), 1);
+    assert_block(&world, pos(2, 3, 2), "minecraft:fire");
+
+    // Fire picks up where it left off once enabled again.
+    world.set_do_fire_tick(true);
+    tick(&mut world, 400);
+    assert!(count(&world, BlockId::OakPlanks) < 50);
+}
+
+/// Returns a world with a 5×1×5 floor of oak planks from (0, 1, 0) to (4, 1, 4), with fire on
+/// top of its middle. Unlike in a pile, fire burning through a floor is always under the sky.
+fn wood_floor(seed: u64, raining: bool) -> World {
+    let mut world = flat_world();
+    world.set_seed(seed);
+    world.set_raining(raining);
+
+    fill(
+        &mut world,
+        pos(0, 1, 0),
+        pos(4, 1, 4),
+        "minecraft:oak_planks",
+    );
+    place(&mut world, pos(2, 2, 2), "minecraft:fire");
+
+    world
+}
+
+#[test]
+fn rain_puts_out_fire_under_the_sky() {
+    let mut wet_planks = 0;
+    let mut dry_planks = 0;
+
+    for seed in seeds() {
+        let mut wet = wood_floor(seed, true);
+        let mut dry = wood_floor(seed, false);
+        assert!(wet.is_raining());
+        tick(&mut wet, 1000);
+        tick(&mut dry, 1000);
+
+        assert_eq!(count(&wet, BlockId::Fire), 0, "{seed}");
+        wet_planks += count(&wet, BlockId::OakPlanks);
+        dry_planks += count(&dry, BlockId::OakPlanks);
+    }
+
+    assert!(wet_planks > dry_planks + 40, "{wet_planks} {dry_planks}");
+
+    // Under a roof, fire burns the same as when it's dry.
+    let mut wet = wood_pile(7);
+    let mut dry = wood_pile(7);
+    wet.set_raining(true);
+
+    for world in [&mut wet, &mut dry] {
+        fill(world, pos(-2, 6, -2), pos(6, 6, 6), "minecraft:stone");
+        tick(world, 1000);
+    }
+
+    assert!(count(&dry, BlockId::OakPlanks) < 50);
+
+    assert_same_blocks(&wet, &dry);
+}
+
+#[test]
+fn fire_on_netherrack_burns_forever() {
+    for seed in seeds() {
+        let mut world = flat_world();
+        world.set_seed(seed);
+        fill(
+            &mut world,
+            pos(0, 0, 0),
+            pos(0, 0, 0),
+            "minecraft:netherrack",
+        );
+        place(&mut world, pos(0, 1, 0), "minecraft:fire");
+
+        // Fire on stone ages and goes out after a while.
+        place(&mut world, pos(4, 1, 0), "minecraft:fire");
+
+        tick(&mut world, 4000);
+        assert_eq!(world.block(pos(0, 1, 0)).unwrap().id(), BlockId::Fire);
+        assert_block(&world, pos(4, 1, 0), "minecraft:air");
+
+        // Even in the rain.
+        world.set_raining(true);
+        tick(&mut world, 4000);
+        assert_eq!(world.block(pos(0, 1, 0)).unwrap().id(), BlockId::Fire);
+    }
+}
+
+/// Returns a world with a lava source in a stone basin at (0, 1, 0), with oak planks on the rim
+/// next to it.
+fn lava_pool(seed: u64) -> World {
+    let mut world = flat_world();
+    world.set_seed(seed);
+    world.set_random_tick_speed(4096);
+
+    fill(&mut world, pos(-1, 1, -1), pos(1, 1, 1), "minecraft:stone");
+    fill(
+        &mut world,
+        pos(1, 2, 0),
+        pos(1, 2, 0),
+        "minecraft:oak_planks",
+    );
+    fill(&mut world, pos(0, 1, 0), pos(0, 1, 0), "minecraft:lava");
+
+    world
+}
+
+/// Returns `true` if fire burns next to the planks of the lava pool.
+fn is_lit(world: &World) -> bool {
+    [
+        pos(0, 2, 0),
+        pos(1, 3, 0),
+        pos(2, 2, 0),
+        pos(1, 2, 1),
+        pos(1, 2, -1),
+    ]
+    .into_iter()
+    .any(|pos: BlockPos| world.block(pos).unwrap().id() == BlockId::Fire)
+}
+
+#[test]
+fn lava_sets_fire_to_flammable_blocks_nearby() {
+    for seed in seeds() {
+        let mut world = lava_pool(seed);
+        world.set_do_fire_tick(false);
+        tick(&mut world, 100);
+        assert!(!is_lit(&world), "{seed}");
+
+        world.set_do_fire_tick(true);
+        let lit = (0..100).any(|_| {
+            world.tick();
+            is_lit(&world)
+        });
+        assert!(lit, "{seed}");
+    }
+}
@@ -0,0 +1,202 @@
+//! Checks how fluids spread and interact in small fixture worlds.
+
+mod common;
+
+use common::{block, fill, flat_world, place, pos};
+use world::block::fluid::Fluid;
+use world::{BlockId, BlockPos, World};
+
+/// Returns the fluid and amount at the given position, with `None` for blocks without fluid.
+fn fluid(world: &World, pos: BlockPos) -> Option<(Fluid, u8)> {
+    let fluid_state = world.fluid_state(pos);
+
+    (!fluid_state.is_empty()).then_some((fluid_state.fluid(), fluid_state.amount()))
+}
+
+/// Returns a world with a raised stone platform at Y 1 that has a single hole at the given
+/// distance east of the origin, where a fluid source will be placed on top of the platform.
+fn platform_with_hole(distance: i32) -> World {
+    let mut world = flat_world();
+
+    fill(
+        &mut world,
+        pos(-12, 1, -12),
+        pos(12, 1, 12),
+        "minecraft:stone",
+    );
+    world
+        .set_block(pos(distance, 1, 0), block("minecraft:air"))
+        .unwrap();
+
+    world
+}
+
+// Like in vanilla, the slope find distance counts from the neighbors of the source, so water
+// finds holes up to 5 blocks away and lava up to 3 blocks away.
+
+#[test]
+fn water_flows_towards_holes_within_its_slope_find_distance() {
+    let mut world = platform_with_hole(5);
+
+    place(&mut world, pos(0, 2, 0), "minecraft:water");
+    world.settle(1000);
+
+    for (x, amount) in (1..=4).zip([7, 6, 5, 4]) {
+        assert_eq!(fluid(&world, pos(x, 2, 0)), Some((Fluid::Water, amount)));
+    }
+    assert_eq!(fluid(&world, pos(5, 1, 0)), Some((Fluid::Water, 8)));
+    assert_eq!(fluid(&world, pos(-1, 2, 0)), None);
+    assert_eq!(fluid(&world, pos(0, 2, 1)), None);
+    assert_eq!(fluid(&world, pos(0, 2, -1)), None);
+}
+
+#[test]
+fn water_spreads_evenly_without_holes_within_its_slope_find_distance() {
+    let mut world = platform_with_hole(6);
+
+    place(&mut world, pos(0, 2, 0), "minecraft:water");
+    world.settle(1000);
+
+    assert_eq!(fluid(&world, pos(-1, 2, 0)), Some((Fluid::Water, 7)));
+    assert_eq!(fluid(&world, pos(0, 2, 1)), Some((Fluid::Water, 7)));
+    assert_eq!(fluid(&world, pos(0, 2, -1)), Some((Fluid::Water, 7)));
+    assert_eq!(fluid(&world, pos(-7, 2, 0)), Some((Fluid::Water, 1)));
+    assert_eq!(fluid(&world, pos(-8, 2, 0)), None);
+}
+
+#[test]
+fn lava_flows_towards_holes_within_its_slope_find_distance() {
+    let mut world = platform_with_hole(3);
+
+    place(&mut world, pos(0, 2, 0), "minecraft:lava");
+    world.settle(1000);
+
+    assert_eq!(fluid(&world, pos(1, 2, 0)), Some((Fluid::Lava, 6)));
+    assert_eq!(fluid(&world, pos(2, 2, 0)), Some((Fluid::Lava, 4)));
+    assert_eq!(fluid(&world, pos(3, 1, 0)), Some((Fluid::Lava, 8)));
+    assert_eq!(fluid(&world, pos(-1, 2, 0)), None);
+    assert_eq!(fluid(&world, pos(0, 2, 1)), None);
+}
+
+#[test]
+fn lava_spreads_evenly_without_holes_within_its_slope_find_distance() {
+    let mut world = platform_with_hole(4);
+
+    place(&mut world, pos(0, 2, 0), "minecraft:lava");
+    world.settle(1000);
+
+    assert_eq!(fluid(&world, pos(-1, 2, 0)), Some((Fluid::Lava, 6)));
+    assert_eq!(fluid(&world, pos(0, 2, 1)), Some((Fluid::Lava, 6)));
+    assert_eq!(fluid(&world, pos(-3, 2, 0)), Some((Fluid::Lava, 2)));
+    assert_eq!(fluid(&world, pos(-4, 2, 0)), None);
+}
+
+#[test]
+fn two_water_sources_create_a_source_between_them() {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:water");
+    place(&mut world, pos(2, 1, 0), "minecraft:water");
+    world.settle(1000);
+
+    assert!(world.fluid_state(pos(1, 1, 0)).is_source());
+    assert!(!world.fluid_state(pos(1, 1, 1)).is_source());
+}
+
+#[test]
+fn lava_sources_are_not_infinite() {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:lava");
+    place(&mut world, pos(2, 1, 0), "minecraft:lava");
+    world.settle(1000);
+
+    assert!(!world.fluid_state(pos(1, 1, 0)).is_source());
+}
+
+#[test]
+fn water_next_to_a_lava_source_makes_obsidian() {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:lava");
+    place(&mut world, pos(1, 1, 0), "minecraft:water");
+    world.settle(1000);
+
+    assert_eq!(world.block(pos(0, 1, 0)).unwrap().id(), BlockId::Obsidian);
+}
+
+#[test]
+fn water_next_to_flowing_lava_makes_cobblestone() {
+    let mut world = flat_world();
+
+    // A channel running east, so that the lava and water only meet in one place.
+    fill(&mut world, pos(-1, 1, -1), pos(8, 1, -1), "minecraft:stone");
+    fill(&mut world, pos(-1, 1, 1), pos(8, 1, 1), "minecraft:stone");
+    world
+        .set_block(pos(-1, 1, 0), block("minecraft:stone"))
+        .unwrap();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:lava");
+    world.settle(1000);
+    assert_eq!(fluid(&world, pos(2, 1, 0)), Some((Fluid::Lava, 4)));
+
+    place(&mut world, pos(3, 1, 0), "minecraft:water");
+    world.settle(1000);
+
+    assert_eq!(
+        world.block(pos(2, 1, 0)).unwrap().id(),
+        BlockId::Cobblestone
+    );
+    assert_eq!(fluid(&world, pos(0, 1, 0)), Some((Fluid::Lava, 8)));
+}
+
+#[test]
+fn lava_flowing_down_into_water_makes_stone() {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:water");
+    place(&mut world, pos(0, 3, 0), "minecraft:lava");
+    world.settle(1000);
+
+    assert_eq!(world.block(pos(0, 1, 0)).unwrap().id(), BlockId::Stone);
+}
+
+#[test]
+fn lava_above_soul_soil_next_to_blue_ice_makes_basalt() {
+    let mut world = flat_world();
+
+    world
+        .set_block(pos(0, 0, 0), block("minecraft:soul_soil"))
+        .unwrap();
+    world
+        .set_block(pos(1, 1, 0), block("minecraft:blue_ice"))
+        .unwrap();
+    place(&mut world, pos(0, 1, 0), "minecraft:lava");
+    world.settle(1000);
+
+    assert_eq!(world.block(pos(0, 1, 0)).unwrap().id(), BlockId::Basalt);
+}
+
+#[test]
+fn fluids_do_not_flow_into_portals_signs_or_doors() {
+    let mut world = flat_world();
+
+    let neighbors = [
+        (pos(-1, 1, 0), "minecraft:end_portal"),
+        (pos(1, 1, 0), "minecraft:oak_sign"),
+        (pos(0, 1, 1), "minecraft:oak_door[half=lower]"),
+        (pos(0, 1, -1), "minecraft:grass"),
+    ];
+
+    for (pos, s) in neighbors {
+        world.set_block(pos, block(s)).unwrap();
+    }
+
+    place(&mut world, pos(0, 1, 0), "minecraft:lava");
+    world.settle(1000);
+
+    assert_eq!(world.block(pos(-1, 1, 0)).unwrap().id(), BlockId::EndPortal);
+    assert_eq!(world.block(pos(1, 1, 0)).unwrap().id(), BlockId::OakSign);
+    assert_eq!(world.block(pos(0, 1, 1)).unwrap().id(), BlockId::OakDoor);
+    assert_eq!(fluid(&world, pos(0, 1, -1)), Some((Fluid::Lava, 6)));
+}
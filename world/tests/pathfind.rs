@@ -0,0 +1,227 @@
+//! Finds paths through small fixture worlds and checks where they lead and what they cost.
+
+#![allow(clippy::float_cmp)] // Costs are sums of halves, which floats represent exactly.
+
+mod common;
+
+use common::{fill, flat_world, pos};
+use world::pathfind::{Capabilities, Path};
+use world::{BlockPos, World};
+
+/// Returns a world with a corridor from X -1 to 6 along Z 0, walled in by stone 3 blocks high.
+fn corridor() -> World {
+    let mut world = flat_world();
+
+    fill(&mut world, pos(-2, 1, -1), pos(7, 3, -1), "minecraft:stone");
+    fill(&mut world, pos(-2, 1, 1), pos(7, 3, 1), "minecraft:stone");
+    fill(&mut world, pos(-2, 1, 0), pos(-2, 3, 0), "minecraft:stone");
+    fill(&mut world, pos(7, 1, 0), pos(7, 3, 0), "minecraft:stone");
+
+    world
+}
+
+/// Finds a path with the default capabilities, changed by `f`.
+fn find_path(
+    world: &World,
+    from: BlockPos,
+    to: BlockPos,
+    f: impl FnOnce(&mut Capabilities),
+) -> Option<Path> {
+    let mut capabilities = Capabilities::default();
+    f(&mut capabilities);
+
+    world.find_path(from, to, &capabilities)
+}
+
+/// Asserts that each position of the path is next to the previous one, ignoring height.
+fn assert_connected(path: &Path) {
+    for pair in path.positions.windows(2) {
+        let offset = pair[1].to_inner() - pair[0].to_inner();
+
+        assert_eq!(offset.x.abs() + offset.z.abs(), 1, "{:?}", path.positions);
+    }
+}
+
+#[test]
+fn walks_on_flat_ground() {
+    let world = flat_world();
+
+    let path = find_path(&world, pos(0, 1, 0), pos(3, 1, 2), |_| {}).unwrap();
+
+    assert_eq!(path.cost, 5.0);
+    assert_eq!(path.positions.len(), 6);
+    assert_eq!(path.positions.first(), Some(&pos(0, 1, 0)));
+    assert_eq!(path.positions.last(), Some(&pos(3, 1, 2)));
+    assert!(path.positions.iter().all(|pos| pos.to_inner().y == 1));
+    assert_connected(&path);
+}
+
+#[test]
+fn steps_up_slabs_without_jumping() {
+    let mut world = corridor();
+    fill(&mut world, pos(3, 1, 0), pos(6, 1, 0), "minecraft:stone");
+
+    // Jumping onto the block costs extra.
+    let path = find_path(&world, pos(0, 1, 0), pos(4, 2, 0), |_| {}).unwrap();
+    assert_eq!(path.cost, 4.5);
+
+    // A slab in front of it is stepped onto, and the block from there. Positions are those of the
+    // blocks above the ground the entity stands on.
+    fill(
+        &mut world,
+        pos(2, 1, 0),
+        pos(2, 1, 0),
+        "minecraft:oak_slab[type=bottom]",
+    );
+    let path = find_path(&world, pos(0, 1, 0), pos(4, 2, 0), |_| {}).unwrap();
+    assert_eq!(path.cost, 4.0);
+    assert_eq!(
+        path.positions,
+        [
+            pos(0, 1, 0),
+            pos(1, 1, 0),
+            pos(2, 2, 0),
+            pos(3, 2, 0),
+            pos(4, 2, 0)
+        ],
+    );
+
+    // Entities that can't jump still step up slabs.
+    let path = find_path(&world, pos(0, 1, 0), pos(4, 2, 0), |capabilities| {
+        capabilities.jump_height = 0.0;
+    });
+    assert_eq!(path.map(|path| path.cost), Some(4.0));
+}
+
+#[test]
+fn jumps_up_to_the_jump_height() {
+    let mut world = corridor();
+    fill(&mut world, pos(3, 1, 0), pos(3, 1, 0), "minecraft:stone");
+
+    let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |_| {}).unwrap();
+    assert_eq!(path.cost, 6.5);
+    assert!(path.positions.contains(&pos(3, 2, 0)));
+
+    let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |capabilities| {
+        capabilities.jump_height = 0.5;
+    });
+    assert_eq!(path, None);
+
+    // Two blocks are too high for the default jump height, but not for a higher one.
+    fill(&mut world, pos(3, 2, 0), pos(3, 2, 0), "minecraft:stone");
+    let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |_| {});
+    assert_eq!(path, None);
+
+    let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |capabilities| {
+        capabilities.jump_height = 2.5;
+    });
+    assert!(path.unwrap().positions.contains(&pos(3, 3, 0)));
+}
+
+/// Returns a world with a stone tower 10 blocks high at the origin, next to the given block on
+/// the ground at (1, 1, 0).
+fn tower(landing: &str) -> World {
+    let mut world = flat_world();
+
+    fill(&mut world, pos(0, 1, 0), pos(0, 10, 0), "minecraft:stone");
+    fill(&mut world, pos(1, 1, 0), pos(1, 1, 0), landing);
+
+    world
+}
+
+#[test]
+fn falls_only_as_far_as_the_damage_allows() {
+    let world = tower("minecraft:air");
+
+    // Falling 10 blocks does 7 damage.
+    let path = find_path(&world, pos(0, 11, 0), pos(1, 1, 0), |_| {});
+    assert_eq!(path, None);
+
+    let path = find_path(&world, pos(0, 11, 0), pos(1, 1, 0), |capabilities| {
+        capabilities.max_fall_damage = 6.0;
+    });
+    assert_eq!(path, None);
+
+    let path = find_path(&world, pos(0, 11, 0), pos(1, 1, 0), |capabilities| {
+        capabilities.max_fall_damage = 7.0;
+    })
+    .unwrap();
+    assert_eq!(path.cost, 15.0);
+    assert_eq!(path.positions, [pos(0, 11, 0), pos(1, 1, 0)]);
+}
+
+#[test]
+fn falls_onto_hay_or_into_water() {
+    // Landing on hay bales does a fifth of the damage, rounded up.
+    let world = tower("minecraft:hay_block");
+    let path = find_path(&world, pos(0, 11, 0), pos(1, 2, 0), |_| {});
+    assert_eq!(path, None);
+
+    let path = find_path(&world, pos(0, 11, 0), pos(1, 2, 0), |capabilities| {
+        capabilities.max_fall_damage = 2.0;
+    })
+    .unwrap();
+    assert_eq!(path.cost, 5.0);
+
+    // Falling into water does no damage.
+    let world = tower("minecraft:water");
+    let path = find_path(&world, pos(0, 11, 0), pos(1, 1, 0), |_| {}).unwrap();
+    assert_eq!(path.cost, 2.0);
+}
+
+#[test]
+fn opens_wooden_doors_but_not_iron_doors() {
+    let mut world = corridor();
+    fill(
+        &mut world,
+        pos(3, 1, 0),
+        pos(3, 1, 0),
+        "minecraft:oak_door[facing=east,half=lower]",
+    );
+    fill(
+        &mut world,
+        pos(3, 2, 0),
+        pos(3, 2, 0),
+        "minecraft:oak_door[facing=east,half=upper]",
+    );
+
+    let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |_| {}).unwrap();
+    assert_eq!(path.cost, 7.0);
+    assert!(path.positions.contains(&pos(3, 1, 0)));
+
+    let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |capabilities| {
+        capabilities.can_open_doors = false;
+    });
+    assert_eq!(path, None);
+
+    fill(
+        &mut world,
+        pos(3, 1, 0),
+        pos(3, 1, 0),
+        "minecraft:iron_door[facing=east,half=lower]",
+    );
+    fill(
+        &mut world,
+        pos(3, 2, 0),
+        pos(3, 2, 0),
+        "minecraft:iron_door[facing=east,half=upper]",
+    );
+
+    let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |_| {});
+    assert_eq!(path, None);
+}
+
+#[test]
+fn slow_blocks_cost_more() {
+    let mut world = corridor();
+    let walk = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |_| {}).unwrap();
+    assert_eq!(walk.cost, 6.0);
+
+    for slow_block in ["minecraft:soul_sand", "minecraft:honey_block"] {
+        fill(&mut world, pos(2, 0, 0), pos(4, 0, 0), slow_block);
+
+        let path = find_path(&world, pos(0, 1, 0), pos(6, 1, 0), |_| {}).unwrap();
+        assert_eq!(path.positions, walk.positions, "{slow_block}");
+        assert!(path.cost > walk.cost, "{slow_block}");
+    }
+}
@@ -0,0 +1,221 @@
+//! Powers pistons in fixture worlds and checks which blocks they move, break or leave in place.
+
+mod common;
+
+use common::{assert_block, fill, flat_world, place, pos};
+use world::{BlockPos, Direction, World};
+
+/// Places a piston facing east at the given position, with the given blocks in front of it.
+fn piston(world: &mut World, piston: &str, at: BlockPos, blocks: &[&str]) {
+    let at = at.to_inner();
+
+    for (x, block) in (at.x + 1..).zip(blocks) {
+        place(world, pos(x, at.y, at.z), block);
+    }
+
+    place(
+        world,
+        pos(at.x, at.y, at.z),
+        &format!("minecraft:{piston}[facing=east]"),
+    );
+}
+
+/// Powers or unpowers the piston at the given position with a redstone block behind it, and lets
+/// it move.
+fn power(world: &mut World, at: BlockPos, powered: bool) {
+    let at = at.to_inner();
+    let block = if powered {
+        "minecraft:redstone_block"
+    } else {
+        "minecraft:air"
+    };
+
+    place(world, pos(at.x - 1, at.y, at.z), block);
+    world.settle(10);
+}
+
+#[test]
+fn pushes_at_most_12_blocks() {
+    let mut world = flat_world();
+    piston(&mut world, "piston", pos(0, 1, 0), &["minecraft:stone"; 12]);
+    power(&mut world, pos(0, 1, 0), true);
+
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:piston[facing=east,extended=true]",
+    );
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:piston_head[facing=east,type=normal]",
+    );
+    for x in 2..=13 {
+        assert_block(&world, pos(x, 1, 0), "minecraft:stone");
+    }
+
+    // With a 13th block, the piston doesn't move at all.
+    let mut world = flat_world();
+    piston(&mut world, "piston", pos(0, 1, 0), &["minecraft:stone"; 13]);
+    assert!(world
+        .resolve_piston_structure(pos(0, 1, 0), Direction::East, true)
+        .is_none());
+    power(&mut world, pos(0, 1, 0), true);
+
+    assert_block(&world, pos(0, 1, 0), "minecraft:piston[facing=east]");
+    for x in 1..=13 {
+        assert_block(&world, pos(x, 1, 0), "minecraft:stone");
+    }
+    assert_block(&world, pos(14, 1, 0), "minecraft:air");
+}
+
+#[test]
+fn slime_and_honey_drag_blocks_but_not_each_other() {
+    // Raised above the floor, which would stick to the slime block.
+    let mut world = flat_world();
+    piston(
+        &mut world,
+        "sticky_piston",
+        pos(0, 3, 0),
+        &["minecraft:slime_block"],
+    );
+    place(&mut world, pos(1, 3, -1), "minecraft:stone");
+    place(&mut world, pos(1, 3, 1), "minecraft:honey_block");
+    place(&mut world, pos(1, 4, 1), "minecraft:stone");
+
+    let structure = world
+        .resolve_piston_structure(pos(0, 3, 0), Direction::East, true)
+        .unwrap();
+    assert_eq!(structure.to_push.len(), 2);
+
+    power(&mut world, pos(0, 3, 0), true);
+    assert_block(&world, pos(2, 3, 0), "minecraft:slime_block");
+    assert_block(&world, pos(2, 3, -1), "minecraft:stone");
+    assert_block(&world, pos(1, 3, -1), "minecraft:air");
+    assert_block(&world, pos(1, 3, 1), "minecraft:honey_block");
+    assert_block(&world, pos(1, 4, 1), "minecraft:stone");
+
+    // Retracting pulls the slime block back, along with the stone stuck to it.
+    power(&mut world, pos(0, 3, 0), false);
+    assert_block(&world, pos(1, 3, 0), "minecraft:slime_block");
+    assert_block(&world, pos(1, 3, -1), "minecraft:stone");
+    assert_block(&world, pos(2, 3, 0), "minecraft:air");
+    assert_block(&world, pos(2, 3, -1), "minecraft:air");
+}
+
+#[test]
+fn obsidian_and_chests_block_pistons() {
+    for blocker in ["minecraft:obsidian", "minecraft:chest[facing=north]"] {
+        let mut world = flat_world();
+        piston(
+            &mut world,
+            "piston",
+            pos(0, 1, 0),
+            &["minecraft:stone", blocker],
+        );
+        power(&mut world, pos(0, 1, 0), true);
+
+        assert_block(&world, pos(0, 1, 0), "minecraft:piston[facing=east]");
+        assert_block(&world, pos(1, 1, 0), "minecraft:stone");
+        assert_block(&world, pos(2, 1, 0), blocker);
+    }
+}
+
+#[test]
+fn glazed_terracotta_can_only_be_pushed() {
+    let mut world = flat_world();
+    piston(
+        &mut world,
+        "sticky_piston",
+        pos(0, 1, 0),
+        &["minecraft:white_glazed_terracotta"],
+    );
+
+    power(&mut world, pos(0, 1, 0), true);
+    assert_block(&world, pos(2, 1, 0), "minecraft:white_glazed_terracotta");
+
+    power(&mut world, pos(0, 1, 0), false);
+    assert_block(&world, pos(0, 1, 0), "minecraft:sticky_piston[facing=east]");
+    assert_block(&world, pos(1, 1, 0), "minecraft:air");
+    assert_block(&world, pos(2, 1, 0), "minecraft:white_glazed_terracotta");
+
+    // Glazed terracotta doesn't stick to slime blocks either.
+    fill(
+        &mut world,
+        pos(2, 2, 0),
+        pos(2, 2, 0),
+        "minecraft:slime_block",
+    );
+    fill(
+        &mut world,
+        pos(3, 2, 0),
+        pos(3, 2, 0),
+        "minecraft:sticky_piston[facing=west]",
+    );
+    let structure = world
+        .resolve_piston_structure(pos(3, 2, 0), Direction::West, true)
+        .unwrap();
+    assert_eq!(structure.to_push, [pos(2, 2, 0)]);
+}
+
+#[test]
+fn destroys_fragile_blocks_in_the_way() {
+    let mut world = flat_world();
+    piston(
+        &mut world,
+        "piston",
+        pos(0, 1, 0),
+        &["minecraft:stone", "minecraft:dandelion"],
+    );
+
+    let structure = world
+        .resolve_piston_structure(pos(0, 1, 0), Direction::East, true)
+        .unwrap();
+    assert_eq!(structure.to_push, [pos(1, 1, 0)]);
+    assert_eq!(structure.to_destroy, [pos(2, 1, 0)]);
+
+    power(&mut world, pos(0, 1, 0), true);
+    assert_block(&world, pos(2, 1, 0), "minecraft:stone");
+    assert_block(&world, pos(3, 1, 0), "minecraft:air");
+
+    // Fragile blocks are also broken when the piston head extends into them.
+    let mut world = flat_world();
+    piston(
+        &mut world,
+        "piston",
+        pos(0, 1, 0),
+        &["minecraft:oak_sapling"],
+    );
+    power(&mut world, pos(0, 1, 0), true);
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:piston_head[facing=east,type=normal]",
+    );
+}
+
+#[test]
+fn sticky_pistons_pull_blocks_back() {
+    for (piston_name, pulled) in [("sticky_piston", true), ("piston", false)] {
+        let mut world = flat_world();
+        piston(&mut world, piston_name, pos(0, 1, 0), &["minecraft:stone"]);
+
+        power(&mut world, pos(0, 1, 0), true);
+        assert_block(&world, pos(2, 1, 0), "minecraft:stone");
+
+        power(&mut world, pos(0, 1, 0), false);
+        assert_block(
+            &world,
+            pos(0, 1, 0),
+            &format!("minecraft:{piston_name}[facing=east]"),
+        );
+
+        if pulled {
+            assert_block(&world, pos(1, 1, 0), "minecraft:stone");
+            assert_block(&world, pos(2, 1, 0), "minecraft:air");
+        } else {
+            assert_block(&world, pos(1, 1, 0), "minecraft:air");
+            assert_block(&world, pos(2, 1, 0), "minecraft:stone");
+        }
+    }
+}
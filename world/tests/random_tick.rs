@@ -0,0 +1,145 @@
+//! Lets random ticks play out in fixture worlds and checks that crops grow, leaves decay, grass
+//! spreads and ice melts, the same way every time for the same seed.
+
+mod common;
+
+use common::{assert_block, fill, flat_world, place, pos};
+use world::{BlockId, World};
+
+/// Returns a world with wheat on moist farmland, a leaf away from any log, a grass block next to
+/// dirt and ice next to a torch, with random ticks fast enough for each block to tick about once
+/// per game tick.
+fn garden() -> World {
+    let mut world = flat_world();
+    world.set_random_tick_speed(4096);
+
+    fill(
+        &mut world,
+        pos(0, 0, 0),
+        pos(0, 0, 0),
+        "minecraft:farmland[moisture=7]",
+    );
+    place(&mut world, pos(0, 1, 0), "minecraft:wheat");
+
+    place(&mut world, pos(4, 1, 0), "minecraft:oak_leaves");
+
+    fill(
+        &mut world,
+        pos(-4, 0, 0),
+        pos(-4, 0, 0),
+        "minecraft:grass_block",
+    );
+    fill(&mut world, pos(-5, 0, 0), pos(-5, 0, 0), "minecraft:dirt");
+
+    place(&mut world, pos(0, 1, 12), "minecraft:ice");
+    place(&mut world, pos(1, 1, 12), "minecraft:torch");
+
+    world
+}
+
+fn tick(world: &mut World, ticks: u32) {
+    for _ in 0..ticks {
+        world.tick();
+    }
+}
+
+/// Returns the blocks around the origin.
+fn snapshot(world: &World) -> Vec<String> {
+    let mut blocks = Vec::new();
+
+    for x in -6..=6 {
+        for y in 0..=2 {
+            for z in -12..=12 {
+                blocks.push(world.block(pos(x, y, z)).unwrap().to_string());
+            }
+        }
+    }
+
+    blocks
+}
+
+#[test]
+fn the_same_seed_plays_out_the_same_way() {
+    let run = |seed| {
+        let mut world = garden();
+        world.set_seed(seed);
+        world.set_random_tick_speed(64);
+        tick(&mut world, 200);
+
+        snapshot(&world)
+    };
+
+    assert_eq!(run(1), run(1));
+    assert_ne!(run(1), run(2));
+}
+
+#[test]
+fn crops_age_in_the_light() {
+    let mut world = garden();
+    tick(&mut world, 500);
+    assert_block(&world, pos(0, 1, 0), "minecraft:wheat[age=7]");
+
+    // Without light, they don't grow.
+    let mut world = garden();
+    fill(&mut world, pos(-1, 3, -1), pos(1, 3, 1), "minecraft:stone");
+    tick(&mut world, 500);
+    assert_block(&world, pos(0, 1, 0), "minecraft:wheat[age=0]");
+}
+
+#[test]
+fn leaves_away_from_logs_decay() {
+    let mut world = garden();
+    assert_block(&world, pos(4, 1, 0), "minecraft:oak_leaves[distance=7]");
+    place(
+        &mut world,
+        pos(4, 1, 1),
+        "minecraft:oak_leaves[persistent=true]",
+    );
+    place(&mut world, pos(6, 1, 0), "minecraft:oak_log");
+    place(&mut world, pos(6, 2, 0), "minecraft:oak_leaves");
+
+    tick(&mut world, 100);
+    assert_block(&world, pos(4, 1, 0), "minecraft:air");
+    assert_block(
+        &world,
+        pos(4, 1, 1),
+        "minecraft:oak_leaves[distance=7,persistent=true]",
+    );
+    assert_block(&world, pos(6, 2, 0), "minecraft:oak_leaves[distance=1]");
+}
+
+#[test]
+fn grass_spreads_to_lit_dirt() {
+    let mut world = garden();
+    fill(&mut world, pos(-4, 0, 1), pos(-4, 0, 1), "minecraft:dirt");
+    fill(&mut world, pos(-4, 1, 1), pos(-4, 1, 1), "minecraft:stone");
+
+    tick(&mut world, 200);
+    assert_block(&world, pos(-5, 0, 0), "minecraft:grass_block");
+    assert_block(&world, pos(-4, 0, 1), "minecraft:dirt");
+
+    // Covered grass turns back into dirt.
+    place(&mut world, pos(-4, 1, 0), "minecraft:stone");
+    tick(&mut world, 100);
+    assert_block(&world, pos(-4, 0, 0), "minecraft:dirt");
+}
+
+#[test]
+fn ice_melts_next_to_light() {
+    let mut world = garden();
+    place(&mut world, pos(0, 1, -12), "minecraft:ice");
+
+    tick(&mut world, 100);
+    assert_eq!(world.block(pos(0, 1, 12)).unwrap().id(), BlockId::Water);
+    assert_block(&world, pos(0, 1, -12), "minecraft:ice");
+}
+
+#[test]
+fn a_speed_of_zero_stops_random_ticks() {
+    let mut world = garden();
+    world.set_random_tick_speed(0);
+    let before = snapshot(&world);
+
+    tick(&mut world, 500);
+    assert_eq!(snapshot(&world), before);
+}
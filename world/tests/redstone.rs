@@ -0,0 +1,292 @@
+//! Runs reference redstone circuits in fixture worlds and checks the block states they settle in.
+
+mod common;
+
+use common::{flat_world, place, pos};
+use world::block::item::{ItemId, ItemStack};
+use world::block::state;
+use world::{BlockPos, World};
+
+fn power(world: &World, pos: BlockPos) -> u32 {
+    let state::Power(power) = world.block(pos).unwrap().get().unwrap();
+
+    power.get()
+}
+
+fn is_lit(world: &World, pos: BlockPos) -> bool {
+    let state::Lit(lit) = world.block(pos).unwrap().get().unwrap();
+
+    lit
+}
+
+fn is_powered(world: &World, pos: BlockPos) -> bool {
+    let state::Powered(powered) = world.block(pos).unwrap().get().unwrap();
+
+    powered
+}
+
+/// Ticks the world the given number of times, collecting the value returned after each tick.
+fn trace<T>(world: &mut World, ticks: u32, mut f: impl FnMut(&World) -> T) -> Vec<T> {
+    (0..ticks)
+        .map(|_| {
+            world.tick();
+            f(world)
+        })
+        .collect()
+}
+
+#[test]
+fn dust_decays_over_distance() {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:redstone_block");
+    for x in 1..=17 {
+        place(&mut world, pos(x, 1, 0), "minecraft:redstone_wire");
+    }
+    world.settle(100);
+
+    let powers = (1..=17).map(|x| power(&world, pos(x, 1, 0)));
+    assert!(powers.eq([15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0]));
+
+    place(&mut world, pos(0, 1, 0), "minecraft:air");
+    world.settle(100);
+
+    assert!((1..=17).all(|x| power(&world, pos(x, 1, 0)) == 0));
+}
+
+#[test]
+fn torch_inverter() {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:stone");
+    place(
+        &mut world,
+        pos(0, 2, 0),
+        "minecraft:lever[face=floor,facing=north]",
+    );
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:redstone_wall_torch[facing=east]",
+    );
+    place(&mut world, pos(2, 1, 0), "minecraft:redstone_lamp");
+    world.settle(100);
+    assert!(is_lit(&world, pos(1, 1, 0)));
+    assert!(is_lit(&world, pos(2, 1, 0)));
+
+    // The torch turns off after 2 ticks, and the lamp after 4 more.
+    world.use_component(pos(0, 2, 0)).unwrap();
+    let states = trace(&mut world, 6, |world| {
+        (is_lit(world, pos(1, 1, 0)), is_lit(world, pos(2, 1, 0)))
+    });
+    assert_eq!(
+        states,
+        [
+            (true, true),
+            (false, true),
+            (false, true),
+            (false, true),
+            (false, true),
+            (false, false),
+        ],
+    );
+
+    // Lamps turn on right away.
+    world.use_component(pos(0, 2, 0)).unwrap();
+    let states = trace(&mut world, 2, |world| {
+        (is_lit(world, pos(1, 1, 0)), is_lit(world, pos(2, 1, 0)))
+    });
+    assert_eq!(states, [(false, false), (true, true)]);
+}
+
+#[test]
+fn torch_burns_out() {
+    let mut world = flat_world();
+
+    // The torch powers the block above it, which powers the dust on top, which leads down to the
+    // dust on the block the torch is attached to.
+    place(&mut world, pos(0, 1, 0), "minecraft:stone");
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:redstone_wall_torch[facing=east]",
+    );
+    place(&mut world, pos(1, 2, 0), "minecraft:stone");
+    place(&mut world, pos(1, 3, 0), "minecraft:redstone_wire");
+    place(&mut world, pos(0, 2, 0), "minecraft:redstone_wire");
+
+    let lit = trace(&mut world, 100, |world| is_lit(world, pos(1, 1, 0)));
+    let turned_off = lit.windows(2).filter(|pair| pair[0] && !pair[1]).count();
+
+    // It burns out when it turned off 8 times within 60 ticks.
+    assert_eq!(turned_off, 8);
+    assert!(lit[40..].iter().all(|&lit| !lit));
+
+    // Like in vanilla, it stays off until it's updated again, even though it's no longer powered.
+    world.settle(1000);
+    assert!(!is_lit(&world, pos(1, 1, 0)));
+    assert_eq!(power(&world, pos(0, 2, 0)), 0);
+}
+
+#[test]
+fn repeater_delays_signals() {
+    let mut world = flat_world();
+
+    place(
+        &mut world,
+        pos(0, 1, 0),
+        "minecraft:lever[face=floor,facing=east]",
+    );
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:repeater[facing=west,delay=4]",
+    );
+    place(&mut world, pos(2, 1, 0), "minecraft:redstone_wire");
+    world.settle(100);
+
+    // A delay of 4 redstone ticks is 8 game ticks, both when turning on and off.
+    world.use_component(pos(0, 1, 0)).unwrap();
+    let powers = trace(&mut world, 8, |world| power(world, pos(2, 1, 0)));
+    assert_eq!(powers, [0, 0, 0, 0, 0, 0, 0, 15]);
+
+    world.use_component(pos(0, 1, 0)).unwrap();
+    let powers = trace(&mut world, 8, |world| power(world, pos(2, 1, 0)));
+    assert_eq!(powers, [15, 15, 15, 15, 15, 15, 15, 0]);
+}
+
+#[test]
+fn repeater_locks() {
+    let mut world = flat_world();
+
+    // A repeater powered from the side locks the one it points into.
+    place(&mut world, pos(1, 1, 0), "minecraft:repeater[facing=west]");
+    place(&mut world, pos(2, 1, 0), "minecraft:redstone_wire");
+    place(&mut world, pos(1, 1, 1), "minecraft:repeater[facing=south]");
+    place(&mut world, pos(1, 1, 2), "minecraft:redstone_block");
+    world.settle(100);
+
+    place(&mut world, pos(0, 1, 0), "minecraft:redstone_block");
+    world.settle(100);
+
+    let state::Locked(locked) = world.block(pos(1, 1, 0)).unwrap().get().unwrap();
+    assert!(locked);
+    assert!(!is_powered(&world, pos(1, 1, 0)));
+    assert_eq!(power(&world, pos(2, 1, 0)), 0);
+
+    place(&mut world, pos(1, 1, 2), "minecraft:air");
+    world.settle(100);
+
+    assert!(is_powered(&world, pos(1, 1, 0)));
+    assert_eq!(power(&world, pos(2, 1, 0)), 15);
+}
+
+/// Returns a world with a comparator in the given mode at (1, 1, 0) whose back is powered by a
+/// redstone block and whose side is powered at 12 by dust leading to another redstone block. The
+/// comparator outputs into dust at (2, 1, 0).
+fn comparator(mode: &str) -> World {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:redstone_block");
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        &format!("minecraft:comparator[facing=west,mode={mode}]"),
+    );
+    place(&mut world, pos(2, 1, 0), "minecraft:redstone_wire");
+    for z in 1..=4 {
+        place(&mut world, pos(1, 1, z), "minecraft:redstone_wire");
+    }
+    place(&mut world, pos(1, 1, 5), "minecraft:redstone_block");
+    world.settle(100);
+
+    world
+}
+
+#[test]
+fn comparator_compares() {
+    let mut world = comparator("compare");
+
+    assert_eq!(power(&world, pos(1, 1, 1)), 12);
+    assert_eq!(world.comparator_output(pos(1, 1, 0)), Some(15));
+    assert_eq!(power(&world, pos(2, 1, 0)), 15);
+
+    // The side is stronger than the back once the back is powered at 11 by dust instead.
+    place(&mut world, pos(-5, 1, 0), "minecraft:redstone_block");
+    for x in -4..=0 {
+        place(&mut world, pos(x, 1, 0), "minecraft:redstone_wire");
+    }
+    world.settle(100);
+    assert_eq!(power(&world, pos(0, 1, 0)), 11);
+
+    assert_eq!(world.comparator_output(pos(1, 1, 0)), Some(0));
+    assert_eq!(power(&world, pos(2, 1, 0)), 0);
+}
+
+#[test]
+fn comparator_subtracts() {
+    let world = comparator("subtract");
+
+    assert_eq!(world.comparator_output(pos(1, 1, 0)), Some(3));
+    assert_eq!(power(&world, pos(2, 1, 0)), 3);
+}
+
+#[test]
+fn comparator_reads_containers() {
+    let mut world = flat_world();
+
+    place(&mut world, pos(0, 1, 0), "minecraft:chest[facing=north]");
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:comparator[facing=west]",
+    );
+    place(&mut world, pos(2, 1, 0), "minecraft:redstone_wire");
+    world.settle(100);
+    assert_eq!(world.comparator_output(pos(1, 1, 0)), Some(0));
+
+    // 10 of the 27 slots full gives 1 + 10 / 27 * 14, rounded down.
+    let items = vec![ItemStack::new(ItemId::Stone, 64); 10];
+    world.set_container_items(pos(0, 1, 0), items).unwrap();
+    world.settle(100);
+
+    assert_eq!(world.comparator_output(pos(1, 1, 0)), Some(6));
+    assert_eq!(power(&world, pos(2, 1, 0)), 6);
+
+    world.set_container_items(pos(0, 1, 0), Vec::new()).unwrap();
+    world.settle(100);
+
+    assert_eq!(power(&world, pos(2, 1, 0)), 0);
+}
+
+#[test]
+fn observer_clock() {
+    let mut world = flat_world();
+
+    // Placing the second observer triggers the first, and from then on each one triggers the
+    // other when it turns on. The one that turns off doesn't, as the other has a tick scheduled.
+    place(&mut world, pos(0, 1, 0), "minecraft:observer[facing=east]");
+    place(&mut world, pos(1, 1, 0), "minecraft:observer[facing=west]");
+
+    let powered = trace(&mut world, 13, |world| {
+        (
+            is_powered(world, pos(0, 1, 0)),
+            is_powered(world, pos(1, 1, 0)),
+        )
+    });
+    let period = [
+        (true, false),
+        (true, false),
+        (false, true),
+        (false, true),
+        (false, false),
+        (false, false),
+    ];
+    assert_eq!(powered[0], (false, false));
+    assert_eq!(powered[1..7], period);
+    assert_eq!(powered[7..13], period);
+
+    // The clock never stops.
+    assert_eq!(world.settle(100), 100);
+    assert!(world.has_scheduled_ticks());
+}
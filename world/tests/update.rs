@@ -0,0 +1,367 @@
+//! Places blocks next to each other in fixture worlds and checks the states they take, like the
+//! connections of fences or the shape of stairs.
+
+mod common;
+
+use common::{assert_block, fill, flat_world, place, pos};
+
+#[test]
+fn fences_connect_to_fences_of_their_kind_and_full_faces() {
+    let mut world = flat_world();
+    place(&mut world, pos(0, 1, 0), "minecraft:oak_fence");
+    place(&mut world, pos(1, 1, 0), "minecraft:spruce_fence");
+    place(&mut world, pos(2, 1, 0), "minecraft:nether_brick_fence");
+    place(&mut world, pos(0, 1, 1), "minecraft:stone");
+    place(&mut world, pos(0, 1, -1), "minecraft:pumpkin");
+    place(
+        &mut world,
+        pos(-1, 1, 0),
+        "minecraft:oak_fence_gate[facing=north]",
+    );
+
+    // Wooden fences connect to each other, to full faces and to gates facing across them, but not
+    // to pumpkins or nether brick fences.
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:oak_fence[east=true,south=true,west=true]",
+    );
+    assert_block(&world, pos(1, 1, 0), "minecraft:spruce_fence[west=true]");
+    assert_block(&world, pos(2, 1, 0), "minecraft:nether_brick_fence");
+
+    // Gates facing along a fence don't connect.
+    place(
+        &mut world,
+        pos(-1, 1, 0),
+        "minecraft:oak_fence_gate[facing=east]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:oak_fence[east=true,south=true]",
+    );
+
+    place(&mut world, pos(1, 1, 0), "minecraft:air");
+    assert_block(&world, pos(0, 1, 0), "minecraft:oak_fence[south=true]");
+}
+
+#[test]
+fn panes_connect_to_panes_walls_and_full_faces() {
+    let mut world = flat_world();
+    place(&mut world, pos(0, 1, 0), "minecraft:glass_pane");
+    place(&mut world, pos(1, 1, 0), "minecraft:iron_bars");
+    place(&mut world, pos(-1, 1, 0), "minecraft:cobblestone_wall");
+    place(&mut world, pos(0, 1, 1), "minecraft:glass");
+    place(&mut world, pos(0, 1, -1), "minecraft:oak_leaves");
+
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:glass_pane[east=true,south=true,west=true]",
+    );
+    assert_block(&world, pos(1, 1, 0), "minecraft:iron_bars[west=true]");
+    assert_block(
+        &world,
+        pos(-1, 1, 0),
+        "minecraft:cobblestone_wall[east=low,up=true]",
+    );
+}
+
+#[test]
+fn walls_are_low_or_tall_and_raise_posts() {
+    let mut world = flat_world();
+    for x in 0..3 {
+        place(&mut world, pos(x, 1, 0), "minecraft:cobblestone_wall");
+    }
+
+    // The ends of a straight wall have posts, but not its middle.
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:cobblestone_wall[east=low,up=true]",
+    );
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:cobblestone_wall[east=low,west=low,up=false]",
+    );
+    assert_block(
+        &world,
+        pos(2, 1, 0),
+        "minecraft:cobblestone_wall[west=low,up=true]",
+    );
+
+    // Sides under a full block are tall.
+    place(&mut world, pos(1, 2, 0), "minecraft:stone");
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:cobblestone_wall[east=tall,west=tall,up=false]",
+    );
+
+    // So are sides under a bottom slab, whose bottom face is full, but not under a top slab.
+    place(
+        &mut world,
+        pos(1, 2, 0),
+        "minecraft:stone_slab[type=bottom]",
+    );
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:cobblestone_wall[east=tall,west=tall,up=false]",
+    );
+    place(&mut world, pos(1, 2, 0), "minecraft:stone_slab[type=top]");
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:cobblestone_wall[east=low,west=low,up=false]",
+    );
+
+    // Torches on top raise a post.
+    place(&mut world, pos(1, 2, 0), "minecraft:torch");
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:cobblestone_wall[east=low,west=low,up=true]",
+    );
+
+    // Corners have posts.
+    place(&mut world, pos(2, 1, 1), "minecraft:cobblestone_wall");
+    assert_block(
+        &world,
+        pos(2, 1, 0),
+        "minecraft:cobblestone_wall[south=low,west=low,up=true]",
+    );
+}
+
+#[test]
+fn stairs_form_corners() {
+    let mut world = flat_world();
+    place(
+        &mut world,
+        pos(0, 1, 0),
+        "minecraft:oak_stairs[facing=north]",
+    );
+
+    // Stairs in front of other stairs, facing across them, make an outer corner.
+    place(
+        &mut world,
+        pos(0, 1, -1),
+        "minecraft:oak_stairs[facing=east]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:oak_stairs[facing=north,shape=outer_right]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, -1),
+        "minecraft:oak_stairs[facing=east,shape=straight]",
+    );
+
+    // Behind them, they make an inner corner.
+    place(&mut world, pos(0, 1, -1), "minecraft:air");
+    place(
+        &mut world,
+        pos(0, 1, 1),
+        "minecraft:oak_stairs[facing=west]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:oak_stairs[facing=north,shape=inner_left]",
+    );
+
+    // Stairs of another half don't count.
+    place(
+        &mut world,
+        pos(0, 1, 1),
+        "minecraft:oak_stairs[facing=west,half=top]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:oak_stairs[facing=north,shape=straight]",
+    );
+
+    // Stairs in a straight line with their neighbor keep it straight.
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:oak_stairs[facing=north]",
+    );
+    place(
+        &mut world,
+        pos(0, 1, 1),
+        "minecraft:oak_stairs[facing=east]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:oak_stairs[facing=north,shape=straight]",
+    );
+}
+
+#[test]
+fn door_halves_stay_in_sync_and_break_together() {
+    let mut world = flat_world();
+    place(
+        &mut world,
+        pos(0, 1, 0),
+        "minecraft:oak_door[facing=east,half=lower]",
+    );
+    place(
+        &mut world,
+        pos(0, 2, 0),
+        "minecraft:oak_door[facing=east,half=upper]",
+    );
+
+    // Opening either half opens the other one.
+    place(
+        &mut world,
+        pos(0, 1, 0),
+        "minecraft:oak_door[facing=east,half=lower,open=true]",
+    );
+    assert_block(
+        &world,
+        pos(0, 2, 0),
+        "minecraft:oak_door[facing=east,half=upper,open=true]",
+    );
+    place(
+        &mut world,
+        pos(0, 2, 0),
+        "minecraft:oak_door[facing=east,half=upper,open=false,hinge=right]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:oak_door[facing=east,half=lower,open=false,hinge=right]",
+    );
+
+    // Breaking either half breaks the other one.
+    place(&mut world, pos(0, 2, 0), "minecraft:air");
+    assert_block(&world, pos(0, 1, 0), "minecraft:air");
+
+    place(
+        &mut world,
+        pos(0, 1, 0),
+        "minecraft:oak_door[facing=east,half=lower]",
+    );
+    place(
+        &mut world,
+        pos(0, 2, 0),
+        "minecraft:oak_door[facing=east,half=upper]",
+    );
+    place(&mut world, pos(0, 1, 0), "minecraft:air");
+    assert_block(&world, pos(0, 2, 0), "minecraft:air");
+
+    // So does removing the block below.
+    place(
+        &mut world,
+        pos(0, 1, 0),
+        "minecraft:oak_door[facing=east,half=lower]",
+    );
+    place(
+        &mut world,
+        pos(0, 2, 0),
+        "minecraft:oak_door[facing=east,half=upper]",
+    );
+    place(&mut world, pos(0, 0, 0), "minecraft:air");
+    assert_block(&world, pos(0, 1, 0), "minecraft:air");
+    assert_block(&world, pos(0, 2, 0), "minecraft:air");
+}
+
+#[test]
+fn redstone_wire_dots_and_crosses() {
+    let mut world = flat_world();
+
+    // A dot stays a dot on its own, and points towards the wire placed next to it.
+    place(&mut world, pos(0, 1, 0), "minecraft:redstone_wire");
+    assert_block(&world, pos(0, 1, 0), "minecraft:redstone_wire");
+    place(&mut world, pos(1, 1, 0), "minecraft:redstone_wire");
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:redstone_wire[east=side,west=side]",
+    );
+
+    // Without anything to connect to, it becomes a cross.
+    place(&mut world, pos(1, 1, 0), "minecraft:air");
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:redstone_wire[north=side,east=side,south=side,west=side]",
+    );
+
+    // With wire on three sides, it points to them only.
+    place(&mut world, pos(-1, 1, 0), "minecraft:redstone_wire");
+    place(&mut world, pos(1, 1, 0), "minecraft:redstone_wire");
+    place(&mut world, pos(0, 1, -1), "minecraft:redstone_wire");
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:redstone_wire[north=side,east=side,west=side]",
+    );
+
+    // Wire climbs the side of a block to the wire on top.
+    fill(&mut world, pos(0, 1, 1), pos(0, 1, 1), "minecraft:stone");
+    place(&mut world, pos(0, 2, 1), "minecraft:redstone_wire");
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:redstone_wire[north=side,east=side,south=up,west=side]",
+    );
+}
+
+#[test]
+fn chests_pair_with_chests_facing_the_same_way() {
+    let mut world = flat_world();
+    place(&mut world, pos(0, 1, 0), "minecraft:chest[facing=north]");
+
+    // The right half of a chest facing north is on the east side, next to the left half.
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:chest[facing=north,type=right]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:chest[facing=north,type=left]",
+    );
+    assert_block(
+        &world,
+        pos(1, 1, 0),
+        "minecraft:chest[facing=north,type=right]",
+    );
+
+    place(&mut world, pos(1, 1, 0), "minecraft:air");
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:chest[facing=north,type=single]",
+    );
+
+    // Chests facing another way, and trapped chests, don't pair.
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:chest[facing=south,type=left]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:chest[facing=north,type=single]",
+    );
+    place(
+        &mut world,
+        pos(1, 1, 0),
+        "minecraft:trapped_chest[facing=north,type=right]",
+    );
+    assert_block(
+        &world,
+        pos(0, 1, 0),
+        "minecraft:chest[facing=north,type=single]",
+    );
+}
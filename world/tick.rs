@@ -0,0 +1,167 @@
+//! Scheduled ticks, which drive the simulation of fluids and redstone components one game tick at
+//! a time.
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+use rustc_hash::FxHashSet;
+
+use crate::block::fluid::Fluid;
+use crate::block::random::Random;
+use crate::{BlockId, BlockPos, World};
+
+impl World {
+    /// Returns the number of game ticks the world was simulated for.
+    #[must_use]
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    /// Advances the simulation by one game tick, running the block ticks and then the fluid ticks
+    /// that are due, then the random ticks, followed by the block events they triggered, like
+    /// pistons moving.
+    pub fn tick(&mut self) {
+        self.time += 1;
+
+        let mut block_ticks = self.block_ticks.take_due(self.time);
+
+        while let Some((pos, id)) = block_ticks.pop() {
+            self.block_ticks.running.remove(&(pos, id));
+
+            if let Some(block) = self.block(pos).filter(|block| block.id() == id) {
+                self.tick_block(pos, block);
+            }
+        }
+
+        let mut fluid_ticks = self.fluid_ticks.take_due(self.time);
+
+        while let Some((pos, fluid)) = fluid_ticks.pop() {
+            self.fluid_ticks.running.remove(&(pos, fluid));
+
+            if self.fluid_state(pos).fluid() == fluid {
+                self.tick_fluid(pos);
+            }
+        }
+
+        self.tick_random_blocks();
+        self.run_block_events();
+    }
+
+    /// Reseeds the randomness of the simulation, like random ticks and fire, so that it plays out
+    /// the same way every time from the same world.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = Random::new(seed);
+        self.random_ticks.reseed(seed);
+    }
+
+    /// Runs the simulation until no tick is scheduled anymore or until `max_ticks` game ticks
+    /// have passed, returning the number of ticks that passed. Clocks never settle.
+    pub fn settle(&mut self, max_ticks: u32) -> u32 {
+        let mut ticks = 0;
+
+        while ticks < max_ticks && self.has_scheduled_ticks() {
+            self.tick();
+            ticks += 1;
+        }
+
+        ticks
+    }
+
+    /// Returns `true` if any block or fluid tick is scheduled, or any block event is queued, like
+    /// a piston about to move.
+    #[must_use]
+    pub fn has_scheduled_ticks(&self) -> bool {
+        !self.block_ticks.queue.is_empty()
+            || !self.fluid_ticks.queue.is_empty()
+            || !self.block_events.is_empty()
+    }
+
+    /// Schedules the block at the given position to tick after the given number of game ticks,
+    /// unless such a tick is already scheduled. Ticks due at the same time run by priority. Ticks
+    /// scheduled between two calls to [`World::tick`] with a delay of 1 run during the next one.
+    pub(crate) fn schedule_block_tick(
+        &mut self,
+        pos: BlockPos,
+        id: BlockId,
+        delay: u64,
+        priority: Priority,
+    ) {
+        self.block_ticks
+            .schedule(pos, id, self.time + delay, priority);
+    }
+
+    pub(crate) fn schedule_fluid_tick(&mut self, pos: BlockPos, fluid: Fluid, delay: u64) {
+        self.fluid_ticks
+            .schedule(pos, fluid, self.time + delay, Priority::Normal);
+    }
+
+    /// Returns `true` if the block at the given position has a tick scheduled, including one that
+    /// is about to run during the current game tick.
+    pub(crate) fn has_scheduled_block_tick(&self, pos: BlockPos, id: BlockId) -> bool {
+        self.block_ticks.scheduled.contains(&(pos, id)) || self.will_tick_this_tick(pos, id)
+    }
+
+    /// Returns `true` if the block at the given position has a tick about to run during the
+    /// current game tick.
+    pub(crate) fn will_tick_this_tick(&self, pos: BlockPos, id: BlockId) -> bool {
+        self.block_ticks.running.contains(&(pos, id))
+    }
+}
+
+/// The priority of a scheduled tick, which decides the order of ticks due at the same time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Priority {
+    ExtremelyHigh,
+    VeryHigh,
+    High,
+    Normal,
+}
+
+/// The ticks scheduled for blocks or fluids of type `T`, run in the order they are due, then by
+/// priority and then in the order they were scheduled.
+pub(crate) struct Ticks<T> {
+    next_order: u64,
+    queue: BTreeMap<(u64, Priority, u64), (BlockPos, T)>,
+    scheduled: FxHashSet<(BlockPos, T)>,
+    running: FxHashSet<(BlockPos, T)>,
+}
+
+impl<T: Copy + Eq + Hash> Ticks<T> {
+    fn schedule(&mut self, pos: BlockPos, ty: T, time: u64, priority: Priority) {
+        if self.scheduled.insert((pos, ty)) {
+            self.queue
+                .insert((time, priority, self.next_order), (pos, ty));
+            self.next_order += 1;
+        }
+    }
+
+    /// Removes the ticks due at the given time from the queue, returning them in reverse order.
+    fn take_due(&mut self, time: u64) -> Vec<(BlockPos, T)> {
+        let mut due = Vec::new();
+
+        while let Some(entry) = self.queue.first_entry() {
+            if entry.key().0 > time {
+                break;
+            }
+
+            let tick = entry.remove();
+            self.scheduled.remove(&tick);
+            self.running.insert(tick);
+            due.push(tick);
+        }
+
+        due.reverse();
+        due
+    }
+}
+
+impl<T> Default for Ticks<T> {
+    fn default() -> Self {
+        Ticks {
+            next_order: 0,
+            queue: BTreeMap::new(),
+            scheduled: FxHashSet::default(),
+            running: FxHashSet::default(),
+        }
+    }
+}
@@ -0,0 +1,841 @@
+//! Shape and neighbor updates, which keep the state of blocks consistent with their neighbors
+//! when a block changes, like the connections of fences or the power of redstone wire.
+
+use anyhow::{Context, Result};
+
+use crate::block::shape::{Aabb, VoxelShape};
+use crate::block::state::{self, Facing};
+use crate::block::tag::vanilla;
+use crate::fire::fire_age;
+use crate::{Block, BlockId, BlockPos, Direction, World};
+
+/// The order in which vanilla updates the shape of the neighbors of a changed block.
+const SHAPE_UPDATE_ORDER: [Direction; 6] = [
+    Direction::West,
+    Direction::East,
+    Direction::North,
+    Direction::South,
+    Direction::Down,
+    Direction::Up,
+];
+
+/// The order in which vanilla tells the neighbors of a block about a change.
+const NEIGHBOR_UPDATE_ORDER: [Direction; 6] = [
+    Direction::West,
+    Direction::East,
+    Direction::Down,
+    Direction::Up,
+    Direction::North,
+    Direction::South,
+];
+
+/// How deep changes can cascade from neighbor to neighbor through shape updates, like in
+/// vanilla.
+const MAX_UPDATE_DEPTH: u32 = 512;
+
+/// Which updates a block change triggers, like the flags of vanilla's `Level::setBlock`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Updates {
+    /// Neighbors are told about the change and update their shape.
+    All,
+    /// Neighbors only update their shape.
+    Shapes,
+    /// Neighbors aren't updated.
+    None,
+}
+
+impl World {
+    /// Sets the block at the given position and updates the state of its neighbors, like vanilla
+    /// does when a block is placed or broken. The block itself takes the state matching its
+    /// neighbors, unless that would remove it. Fences, panes and walls connect to each other,
+    /// stairs change shape, redstone wire is rerouted and blocks that can't stay without the
+    /// block, like the other half of a door, are removed. Redstone components react to the change
+    /// and the fluids around the block are scheduled to tick. Fails if the chunk of the block
+    /// isn't loaded.
+    pub fn set_block_and_update(&mut self, pos: BlockPos, block: Block) -> Result<()> {
+        let old = self
+            .block(pos)
+            .with_context(|| format!("block at position {pos} is not loaded"))?;
+        let mut placed = match self.shape_against_neighbors(pos, block) {
+            // Blocks are placed even without what they need to stay, like the other half of a
+            // door, so that builds can be pasted block by block.
+            placed if placed.id() == BlockId::Air => block,
+            // Doors are opened and closed by setting either half, which the other half follows.
+            _ if block.is_in(vanilla("doors")) => block,
+            placed => placed,
+        };
+        let is_new = placed.id() != old.id();
+
+        if is_new {
+            placed = self.placement_state(pos, placed);
+        }
+
+        self.replace_block(pos, placed, Updates::All, 0);
+
+        if is_new && self.block(pos) == Some(placed) {
+            self.set_placed(pos, placed);
+        }
+
+        Ok(())
+    }
+
+    /// Updates the state of the block at the given position against each of its neighbors, as if
+    /// they had all just changed, and then updates the neighbors in turn. This fixes blocks whose
+    /// state doesn't match their surroundings, and removes those that can't stay, like half
+    /// doors. Fails if the chunk of the block isn't loaded.
+    pub fn update_shape(&mut self, pos: BlockPos) -> Result<()> {
+        let old = self
+            .block(pos)
+            .with_context(|| format!("block at position {pos} is not loaded"))?;
+        let block = self.shape_against_neighbors(pos, old);
+
+        self.replace_block(pos, block, Updates::All, 0);
+
+        Ok(())
+    }
+
+    pub(crate) fn shape_against_neighbors(&self, pos: BlockPos, mut block: Block) -> Block {
+        for direction in SHAPE_UPDATE_ORDER {
+            let Some(neighbor) = pos.neighbor(direction).and_then(|pos| self.block(pos)) else {
+                continue;
+            };
+
+            block = self.updated_shape(pos, block, direction, neighbor);
+        }
+
+        block
+    }
+
+    /// Sets the block at the given position, which must be loaded, and runs the hooks of the old
+    /// and new blocks before triggering the given updates, like vanilla's `Level::setBlock`.
+    pub(crate) fn replace_block(
+        &mut self,
+        pos: BlockPos,
+        block: Block,
+        updates: Updates,
+        depth: u32,
+    ) {
+        let old = self.block(pos).expect("the block should be loaded");
+
+        if old == block {
+            return;
+        }
+
+        self.set_block(pos, block)
+            .expect("the block should be loaded");
+        self.on_remove(pos, old, block);
+
+        // The hooks may change the block again, which then takes over the updates.
+        if self.block(pos).map(Block::id) != Some(block.id()) {
+            return;
+        }
+
+        self.on_place(pos, block, old);
+
+        if self.block(pos) != Some(block) {
+            return;
+        }
+
+        if updates == Updates::All {
+            self.update_neighbors(pos, block.id());
+
+            if self.analog_output(pos, block).is_some() {
+                self.update_neighbors_for_output_signal(pos, block.id());
+            }
+        }
+
+        if updates != Updates::None && depth < MAX_UPDATE_DEPTH {
+            self.update_diagonal_wires(pos, old, depth);
+            self.update_neighbor_shapes(pos, block, depth);
+            self.update_diagonal_wires(pos, block, depth);
+        }
+
+        self.update_fluids_around(pos);
+    }
+
+    /// Tells the neighbors of the given position about a change of the `source` block there.
+    pub(crate) fn update_neighbors(&mut self, pos: BlockPos, source: BlockId) {
+        for direction in NEIGHBOR_UPDATE_ORDER {
+            self.update_neighbor_in(pos, direction, source);
+        }
+    }
+
+    /// Tells the neighbors of the given position about a change of the `source` block, except the
+    /// one in the given direction.
+    pub(crate) fn update_neighbors_except(
+        &mut self,
+        pos: BlockPos,
+        source: BlockId,
+        skip: Direction,
+    ) {
+        for direction in NEIGHBOR_UPDATE_ORDER {
+            if direction != skip {
+                self.update_neighbor_in(pos, direction, source);
+            }
+        }
+    }
+
+    fn update_neighbor_in(&mut self, pos: BlockPos, direction: Direction, source: BlockId) {
+        if let Some(neighbor_pos) = pos.neighbor(direction) {
+            self.neighbor_changed(neighbor_pos, source);
+        }
+    }
+
+    fn update_neighbor_shapes(&mut self, pos: BlockPos, block: Block, depth: u32) {
+        for direction in SHAPE_UPDATE_ORDER {
+            if let Some(neighbor_pos) = pos.neighbor(direction) {
+                self.update_shape_from(neighbor_pos, direction.opposite(), block, depth + 1);
+            }
+        }
+    }
+
+    /// Updates the shape of the block at the given position after its neighbor in the given
+    /// direction changed, removing it if it can't stay.
+    fn update_shape_from(
+        &mut self,
+        pos: BlockPos,
+        direction: Direction,
+        neighbor: Block,
+        depth: u32,
+    ) {
+        let Some(block) = self.block(pos) else {
+            return;
+        };
+
+        self.observe(pos, block, direction);
+        self.check_leaves_distance(pos, block, neighbor);
+
+        let updated = self.updated_shape(pos, block, direction, neighbor);
+
+        if updated.id() == BlockId::Air && block.id() != BlockId::Air {
+            self.replace_block(pos, updated, Updates::All, depth);
+        } else {
+            self.replace_block(pos, updated, Updates::Shapes, depth);
+        }
+    }
+
+    /// Updates the redstone wire one block up or down diagonally from the given wire, which may
+    /// connect to it as it climbs blocks.
+    fn update_diagonal_wires(&mut self, pos: BlockPos, block: Block, depth: u32) {
+        if block.id() != BlockId::RedstoneWire {
+            return;
+        }
+
+        for direction in Direction::HORIZONTAL {
+            let Some(side_pos) = pos.neighbor(direction) else {
+                continue;
+            };
+
+            if wire_side(block, direction) == state::RedstoneSide::None
+                || self
+                    .block(side_pos)
+                    .is_some_and(|side| side.id() == BlockId::RedstoneWire)
+            {
+                continue;
+            }
+
+            for vertical in [Direction::Down, Direction::Up] {
+                let Some(wire_pos) = side_pos.neighbor(vertical) else {
+                    continue;
+                };
+                let Some(neighbor) = pos.neighbor(vertical).and_then(|pos| self.block(pos)) else {
+                    continue;
+                };
+
+                if self
+                    .block(wire_pos)
+                    .is_some_and(|wire| wire.id() == BlockId::RedstoneWire)
+                {
+                    self.update_shape_from(wire_pos, direction.opposite(), neighbor, depth + 1);
+                }
+            }
+        }
+    }
+
+    /// Returns the state the block at the given position should have after its neighbor in the
+    /// given direction changed, which is air if the block can't stay.
+    fn updated_shape(
+        &self,
+        pos: BlockPos,
+        mut block: Block,
+        direction: Direction,
+        neighbor: Block,
+    ) -> Block {
+        use BlockId::*;
+
+        let id = block.id();
+
+        match id {
+            RedstoneWire => self.updated_wire(pos, block, direction),
+            Chest | TrappedChest => updated_chest(block, direction, neighbor),
+            Repeater
+                if block
+                    .get::<state::HorizontalFacing>()
+                    .is_some_and(|facing| !direction.is_parallel_to(facing.into())) =>
+            {
+                block.set(state::Locked(self.is_locked(pos, block)));
+                block
+            }
+            Fire if self.can_fire_survive(pos) => self.fire_state(pos, fire_age(block)),
+            Fire => Block::from_id(Air),
+            SoulFire
+                if !self
+                    .neighbor_block(pos, Direction::Down)
+                    .is_in(vanilla("soul_fire_base_blocks")) =>
+            {
+                Block::from_id(Air)
+            }
+            // The tip of kelp and vines turns into their stem as they grow.
+            _ if growing_plant(id).is_some_and(|(stem, growth_direction)| {
+                direction == growth_direction && (neighbor.id() == id || neighbor.id() == stem)
+            }) =>
+            {
+                let (stem, _) = growing_plant(id).expect("the block should be a growing plant");
+                let mut stem = Block::from_id(stem);
+
+                if let Some(berries) = block.get::<state::Berries>() {
+                    stem.set(berries);
+                }
+
+                stem
+            }
+            GrassBlock | Podzol | Mycelium if direction == Direction::Up => {
+                block.set(state::Snowy(neighbor.id().is_in(vanilla("snow"))));
+                block
+            }
+            TallGrass | LargeFern | Sunflower | Lilac | RoseBush | Peony | TallSeagrass
+            | SmallDripleaf => updated_double_plant(block, direction, neighbor),
+            _ if direction.is_horizontal() && id.is_in(vanilla("fences")) => {
+                set_side(
+                    &mut block,
+                    direction,
+                    fence_connects(id, neighbor, direction),
+                );
+                block
+            }
+            _ if direction.is_horizontal() && is_pane(id) => {
+                set_side(&mut block, direction, pane_connects(neighbor, direction));
+                block
+            }
+            _ if id.is_in(vanilla("walls")) => self.updated_wall(pos, block, direction, neighbor),
+            _ if direction.is_horizontal() && id.is_in(vanilla("stairs")) => {
+                block.set(self.stairs_shape(pos, block));
+                block
+            }
+            _ if id.is_in(vanilla("fence_gates")) => {
+                self.updated_fence_gate(pos, block, direction, neighbor)
+            }
+            _ if id.is_in(vanilla("doors")) => updated_door(block, direction, neighbor),
+            _ => block,
+        }
+    }
+
+    pub(crate) fn neighbor_block(&self, pos: BlockPos, direction: Direction) -> Block {
+        pos.neighbor(direction)
+            .and_then(|pos| self.block(pos))
+            .unwrap_or_default()
+    }
+
+    fn updated_wall(
+        &self,
+        pos: BlockPos,
+        mut block: Block,
+        direction: Direction,
+        neighbor: Block,
+    ) -> Block {
+        if direction == Direction::Down {
+            return block;
+        }
+
+        let above = if direction == Direction::Up {
+            neighbor
+        } else {
+            self.neighbor_block(pos, Direction::Up)
+        };
+        let above_face = above.collision_shape().face(Facing::Down);
+
+        for side in Direction::HORIZONTAL {
+            let connected = if side == direction {
+                wall_connects(neighbor, direction)
+            } else {
+                block.property_named(side_name(side)) != Some(state::WallSide::None)
+            };
+            let wall_side = if !connected {
+                state::WallSide::None
+            } else if is_covered(&above_face, &wall_test_shape(Some(side))) {
+                state::WallSide::Tall
+            } else {
+                state::WallSide::Low
+            };
+
+            block.set_property_named(side_name(side), wall_side);
+        }
+
+        block.set(state::Up(raises_wall_post(block, above, &above_face)));
+        block
+    }
+
+    fn stairs_shape(&self, pos: BlockPos, block: Block) -> state::StairsShape {
+        let Some((facing, half)) = stairs(block) else {
+            return state::StairsShape::Straight;
+        };
+        let neighbor_stairs = |direction| stairs(self.neighbor_block(pos, direction));
+        // Stairs don't take a shape that would break a straight line of stairs next to them.
+        let can_take_shape = |direction| neighbor_stairs(direction) != Some((facing, half));
+
+        if let Some((front_facing, front_half)) = neighbor_stairs(facing) {
+            if front_half == half
+                && !front_facing.is_parallel_to(facing)
+                && can_take_shape(front_facing.opposite())
+            {
+                return if front_facing == facing.counter_clockwise() {
+                    state::StairsShape::OuterLeft
+                } else {
+                    state::StairsShape::OuterRight
+                };
+            }
+        }
+
+        if let Some((back_facing, back_half)) = neighbor_stairs(facing.opposite()) {
+            if back_half == half
+                && !back_facing.is_parallel_to(facing)
+                && can_take_shape(back_facing)
+            {
+                return if back_facing == facing.counter_clockwise() {
+                    state::StairsShape::InnerLeft
+                } else {
+                    state::StairsShape::InnerRight
+                };
+            }
+        }
+
+        state::StairsShape::Straight
+    }
+
+    fn updated_fence_gate(
+        &self,
+        pos: BlockPos,
+        mut block: Block,
+        direction: Direction,
+        neighbor: Block,
+    ) -> Block {
+        let Some(facing) = block.get::<state::HorizontalFacing>() else {
+            return block;
+        };
+
+        // Gates sink into walls on both of their sides.
+        if direction.is_parallel_to(Direction::from(facing).clockwise()) {
+            let walls = vanilla("walls");
+            let in_wall = neighbor.is_in(walls)
+                || self.neighbor_block(pos, direction.opposite()).is_in(walls);
+
+            block.set(state::InWall(in_wall));
+        }
+
+        block
+    }
+
+    fn updated_wire(&self, pos: BlockPos, mut block: Block, direction: Direction) -> Block {
+        match direction {
+            Direction::Down => block,
+            Direction::Up => self.wire_connections(pos, block),
+            _ => {
+                let above_conducts = self
+                    .neighbor_block(pos, Direction::Up)
+                    .is_redstone_conductor();
+                let side = self.wire_side_towards(pos, direction, !above_conducts);
+
+                if is_connected(side) == is_connected(wire_side(block, direction))
+                    && !is_cross(block)
+                {
+                    block.set_property_named(side_name(direction), side);
+                    block
+                } else {
+                    let mut cross = block;
+
+                    for direction in Direction::HORIZONTAL {
+                        cross.set_property_named(side_name(direction), state::RedstoneSide::Side);
+                    }
+
+                    cross.set_property_named(side_name(direction), side);
+                    self.wire_connections(pos, cross)
+                }
+            }
+        }
+    }
+
+    /// Returns the wire with its sides connected to the redstone components around it. Wire that
+    /// doesn't connect to anything on one axis points in both directions along the other one.
+    pub(crate) fn wire_connections(&self, pos: BlockPos, block: Block) -> Block {
+        let was_dot = is_dot(block);
+        let mut wire = Block::from_id(BlockId::RedstoneWire);
+
+        if let Some(power) = block.get::<state::Power>() {
+            wire.set(power);
+        }
+
+        let above_conducts = self
+            .neighbor_block(pos, Direction::Up)
+            .is_redstone_conductor();
+
+        for direction in Direction::HORIZONTAL {
+            let side = self.wire_side_towards(pos, direction, !above_conducts);
+            wire.set_property_named(side_name(direction), side);
+        }
+
+        if was_dot && is_dot(wire) {
+            return wire;
+        }
+
+        let [north, east, south, west] =
+            Direction::HORIZONTAL.map(|direction| is_connected(wire_side(wire, direction)));
+
+        for (direction, connected, other_axis_connected) in [
+            (Direction::West, west, north || south),
+            (Direction::East, east, north || south),
+            (Direction::North, north, east || west),
+            (Direction::South, south, east || west),
+        ] {
+            if !connected && !other_axis_connected {
+                wire.set_property_named(side_name(direction), state::RedstoneSide::Side);
+            }
+        }
+
+        wire
+    }
+
+    /// Returns how the wire at the given position connects in the given direction, which is up
+    /// when climbing the neighboring block.
+    fn wire_side_towards(
+        &self,
+        pos: BlockPos,
+        direction: Direction,
+        can_climb: bool,
+    ) -> state::RedstoneSide {
+        let Some(neighbor_pos) = pos.neighbor(direction) else {
+            return state::RedstoneSide::None;
+        };
+        let neighbor = self.block(neighbor_pos).unwrap_or_default();
+
+        if can_climb
+            && (is_face_sturdy(neighbor, Direction::Up) || neighbor.id() == BlockId::Hopper)
+            && connects_to_wire(self.neighbor_block(neighbor_pos, Direction::Up), None)
+        {
+            return if is_face_sturdy(neighbor, direction.opposite()) {
+                state::RedstoneSide::Up
+            } else {
+                state::RedstoneSide::Side
+            };
+        }
+
+        if connects_to_wire(neighbor, Some(direction))
+            || (!neighbor.is_redstone_conductor()
+                && connects_to_wire(self.neighbor_block(neighbor_pos, Direction::Down), None))
+        {
+            state::RedstoneSide::Side
+        } else {
+            state::RedstoneSide::None
+        }
+    }
+}
+
+/// Returns the stem of kelp or vines growing from the given tip, and the direction they grow in.
+pub(crate) fn growing_plant(tip: BlockId) -> Option<(BlockId, Direction)> {
+    use BlockId::*;
+
+    match tip {
+        Kelp => Some((KelpPlant, Direction::Up)),
+        WeepingVines => Some((WeepingVinesPlant, Direction::Down)),
+        TwistingVines => Some((TwistingVinesPlant, Direction::Up)),
+        CaveVines => Some((CaveVinesPlant, Direction::Down)),
+        _ => None,
+    }
+}
+
+pub(crate) fn side_name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::North => "north",
+        Direction::East => "east",
+        Direction::South => "south",
+        Direction::West => "west",
+        _ => unreachable!("expected a horizontal direction"),
+    }
+}
+
+/// Sets the side of a fence-like block with a boolean property for each side.
+fn set_side(block: &mut Block, direction: Direction, connected: bool) {
+    match direction {
+        Direction::North => block.set(state::North(connected)),
+        Direction::East => block.set(state::East(connected)),
+        Direction::South => block.set(state::South(connected)),
+        Direction::West => block.set(state::West(connected)),
+        _ => false,
+    };
+}
+
+/// Returns `true` if the face of the block in the given direction can fully support other
+/// blocks.
+pub(crate) fn is_face_sturdy(block: Block, direction: Direction) -> bool {
+    block.collision_shape().is_face_full(direction.into())
+}
+
+/// Returns `true` if fences, panes and walls don't connect to the block, despite its full faces.
+fn is_connection_exception(id: BlockId) -> bool {
+    use BlockId::*;
+
+    id.is_in(vanilla("leaves"))
+        || id.is_in(vanilla("shulker_boxes"))
+        || matches!(id, Barrier | CarvedPumpkin | JackOLantern | Melon | Pumpkin)
+}
+
+fn is_pane(id: BlockId) -> bool {
+    use BlockId::*;
+
+    matches!(
+        id,
+        IronBars
+            | GlassPane
+            | WhiteStainedGlassPane
+            | OrangeStainedGlassPane
+            | MagentaStainedGlassPane
+            | LightBlueStainedGlassPane
+            | YellowStainedGlassPane
+            | LimeStainedGlassPane
+            | PinkStainedGlassPane
+            | GrayStainedGlassPane
+            | LightGrayStainedGlassPane
+            | CyanStainedGlassPane
+            | PurpleStainedGlassPane
+            | BlueStainedGlassPane
+            | BrownStainedGlassPane
+            | GreenStainedGlassPane
+            | RedStainedGlassPane
+            | BlackStainedGlassPane
+    )
+}
+
+/// Returns `true` if the neighbor in the given direction is a fence gate facing across that
+/// direction.
+fn is_gate_across(neighbor: Block, direction: Direction) -> bool {
+    neighbor.is_in(vanilla("fence_gates"))
+        && neighbor
+            .get::<state::HorizontalFacing>()
+            .is_some_and(|facing| !Direction::from(facing).is_parallel_to(direction))
+}
+
+/// Returns `true` if the fence connects to the neighbor in the given direction. Wooden fences
+/// and nether brick fences don't connect to each other.
+fn fence_connects(fence: BlockId, neighbor: Block, direction: Direction) -> bool {
+    let id = neighbor.id();
+    let wooden_fences = vanilla("wooden_fences");
+
+    (!is_connection_exception(id) && is_face_sturdy(neighbor, direction.opposite()))
+        || (id.is_in(vanilla("fences")) && id.is_in(wooden_fences) == fence.is_in(wooden_fences))
+        || is_gate_across(neighbor, direction)
+}
+
+fn pane_connects(neighbor: Block, direction: Direction) -> bool {
+    let id = neighbor.id();
+
+    (!is_connection_exception(id) && is_face_sturdy(neighbor, direction.opposite()))
+        || is_pane(id)
+        || id.is_in(vanilla("walls"))
+}
+
+fn wall_connects(neighbor: Block, direction: Direction) -> bool {
+    let id = neighbor.id();
+
+    id.is_in(vanilla("walls"))
+        || (!is_connection_exception(id) && is_face_sturdy(neighbor, direction.opposite()))
+        || is_pane(id)
+        || is_gate_across(neighbor, direction)
+}
+
+/// Returns the shape the block above a wall must cover for the post, or the side in the given
+/// direction, to be tall.
+fn wall_test_shape(side: Option<Direction>) -> VoxelShape {
+    let aabb = match side {
+        None => Aabb::from_pixels(7.0, 0.0, 7.0, 9.0, 16.0, 9.0),
+        Some(Direction::North) => Aabb::from_pixels(7.0, 0.0, 0.0, 9.0, 16.0, 9.0),
+        Some(Direction::South) => Aabb::from_pixels(7.0, 0.0, 7.0, 9.0, 16.0, 16.0),
+        Some(Direction::West) => Aabb::from_pixels(0.0, 0.0, 7.0, 9.0, 16.0, 9.0),
+        Some(Direction::East) => Aabb::from_pixels(7.0, 0.0, 7.0, 16.0, 16.0, 9.0),
+        Some(_) => unreachable!("expected a horizontal direction"),
+    };
+
+    VoxelShape::new([aabb])
+}
+
+fn is_covered(shape: &VoxelShape, test: &VoxelShape) -> bool {
+    test.subtract(shape).is_empty()
+}
+
+/// Returns `true` if the wall has a post, which is the case when it doesn't go straight through
+/// or when something sits on top of it.
+fn raises_wall_post(wall: Block, above: Block, above_face: &VoxelShape) -> bool {
+    if above.is_in(vanilla("walls")) && above.get::<state::Up>() == Some(state::Up(true)) {
+        return true;
+    }
+
+    let [north, east, south, west] = Direction::HORIZONTAL.map(|direction| {
+        wall.property_named(side_name(direction))
+            .unwrap_or(state::WallSide::None)
+    });
+    let none = |side| side == state::WallSide::None;
+
+    if (none(north) && none(east) && none(south) && none(west))
+        || none(north) != none(south)
+        || none(east) != none(west)
+    {
+        return true;
+    }
+
+    if (north == state::WallSide::Tall && south == state::WallSide::Tall)
+        || (east == state::WallSide::Tall && west == state::WallSide::Tall)
+    {
+        return false;
+    }
+
+    above.is_in(vanilla("wall_post_override")) || is_covered(above_face, &wall_test_shape(None))
+}
+
+/// Returns the facing and half of the block if it's stairs.
+fn stairs(block: Block) -> Option<(Direction, state::Half)> {
+    if !block.is_in(vanilla("stairs")) {
+        return None;
+    }
+
+    Some((
+        block.get::<state::HorizontalFacing>()?.into(),
+        block.get::<state::Half>()?,
+    ))
+}
+
+pub(crate) fn wire_side(block: Block, direction: Direction) -> state::RedstoneSide {
+    block
+        .property_named(side_name(direction))
+        .unwrap_or(state::RedstoneSide::None)
+}
+
+pub(crate) fn is_connected(side: state::RedstoneSide) -> bool {
+    side != state::RedstoneSide::None
+}
+
+fn is_dot(wire: Block) -> bool {
+    Direction::HORIZONTAL
+        .into_iter()
+        .all(|direction| !is_connected(wire_side(wire, direction)))
+}
+
+fn is_cross(wire: Block) -> bool {
+    Direction::HORIZONTAL
+        .into_iter()
+        .all(|direction| is_connected(wire_side(wire, direction)))
+}
+
+/// Returns `true` if redstone wire connects to the block, coming from the given direction if
+/// it's next to the wire rather than above or below it.
+fn connects_to_wire(block: Block, direction: Option<Direction>) -> bool {
+    match block.id() {
+        BlockId::RedstoneWire => true,
+        BlockId::Repeater => block
+            .get::<state::HorizontalFacing>()
+            .zip(direction)
+            .is_some_and(|(facing, direction)| Direction::from(facing).is_parallel_to(direction)),
+        BlockId::Observer => block
+            .get::<state::Facing>()
+            .is_some_and(|facing| direction == Some(facing.into())),
+        _ => block.is_signal_source() && direction.is_some(),
+    }
+}
+
+/// Returns the half of a double chest joined with the neighboring chest, or split from it.
+fn updated_chest(mut chest: Block, direction: Direction, neighbor: Block) -> Block {
+    let Some(ty) = chest.get::<state::ChestType>() else {
+        return chest;
+    };
+
+    if neighbor.id() == chest.id() && direction.is_horizontal() {
+        let neighbor_ty = neighbor.get::<state::ChestType>();
+
+        if ty == state::ChestType::Single
+            && neighbor_ty != Some(state::ChestType::Single)
+            && chest.get::<state::HorizontalFacing>() == neighbor.get::<state::HorizontalFacing>()
+            && chest_connection(neighbor) == Some(direction.opposite())
+        {
+            chest.set(match neighbor_ty {
+                Some(state::ChestType::Left) => state::ChestType::Right,
+                _ => state::ChestType::Left,
+            });
+        }
+    } else if chest_connection(chest) == Some(direction) {
+        chest.set(state::ChestType::Single);
+    }
+
+    chest
+}
+
+/// Returns the direction of the other half of a double chest.
+pub(crate) fn chest_connection(chest: Block) -> Option<Direction> {
+    let facing = Direction::from(chest.get::<state::HorizontalFacing>()?);
+
+    Some(match chest.get::<state::ChestType>()? {
+        state::ChestType::Left => facing.clockwise(),
+        _ => facing.counter_clockwise(),
+    })
+}
+
+/// Returns the door with the state of its other half, or air if that half is missing or if the
+/// block below can't support it.
+fn updated_door(mut door: Block, direction: Direction, neighbor: Block) -> Block {
+    let Some(half) = door.get::<state::DoubleBlockHalf>() else {
+        return door;
+    };
+
+    if !direction.is_horizontal()
+        && (half == state::DoubleBlockHalf::Lower) == (direction == Direction::Up)
+    {
+        if neighbor.id() != door.id() || neighbor.get::<state::DoubleBlockHalf>() == Some(half) {
+            return Block::from_id(BlockId::Air);
+        }
+
+        if let Some(facing) = neighbor.get::<state::HorizontalFacing>() {
+            door.set(facing);
+        }
+
+        if let Some(open) = neighbor.get::<state::Open>() {
+            door.set(open);
+        }
+
+        if let Some(hinge) = neighbor.get::<state::DoorHingeSide>() {
+            door.set(hinge);
+        }
+
+        if let Some(powered) = neighbor.get::<state::Powered>() {
+            door.set(powered);
+        }
+    } else if half == state::DoubleBlockHalf::Lower
+        && direction == Direction::Down
+        && !is_face_sturdy(neighbor, Direction::Up)
+    {
+        return Block::from_id(BlockId::Air);
+    }
+
+    door
+}
+
+/// Returns the double plant, or air if its other half is missing.
+fn updated_double_plant(plant: Block, direction: Direction, neighbor: Block) -> Block {
+    let Some(half) = plant.get::<state::DoubleBlockHalf>() else {
+        return plant;
+    };
+
+    if !direction.is_horizontal()
+        && (half == state::DoubleBlockHalf::Lower) == (direction == Direction::Up)
+        && (neighbor.id() != plant.id() || neighbor.get::<state::DoubleBlockHalf>() == Some(half))
+    {
+        Block::from_id(BlockId::Air)
+    } else {
+        plant
+    }
+}